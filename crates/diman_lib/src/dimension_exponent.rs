@@ -26,7 +26,7 @@ impl DimensionExponent for i64 {
     }
 
     fn float_pow(num: Magnitude, exponent: Self) -> Magnitude {
-        Magnitude::from_f64(num.into_f64().powi(exponent as i32))
+        num.powi(exponent as i32)
     }
 
     fn from_int(i: i32) -> Self {
@@ -0,0 +1,304 @@
+//! Dual numbers for forward-mode automatic differentiation.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::magnitude::Magnitude;
+use crate::storage::{
+    first_order_float_methods, DualStorage, FloatStorage, QuantityStorage, RealStorage,
+};
+
+/// A value together with its derivative with respect to a single
+/// variable.
+///
+/// `X` is the type of the variable, such as `Length<f64>`. It does not
+/// affect the representation, but keeps dual numbers of different
+/// variables apart and tells quantities the dimension of their
+/// derivative.
+///
+/// Dual numbers are ordered by their value first and by their
+/// derivative second.
+pub struct Dual<F, X> {
+    pub value: F,
+    pub derivative: F,
+    variable: PhantomData<fn() -> X>,
+}
+
+impl<F, X> Dual<F, X> {
+    pub const fn new(value: F, derivative: F) -> Self {
+        Self {
+            value,
+            derivative,
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<F: Clone, X> Clone for Dual<F, X> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone(), self.derivative.clone())
+    }
+}
+
+impl<F: Copy, X> Copy for Dual<F, X> {}
+
+impl<F: fmt::Debug, X> fmt::Debug for Dual<F, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dual")
+            .field("value", &self.value)
+            .field("derivative", &self.derivative)
+            .finish()
+    }
+}
+
+/// Formats the dual number as `value + derivative ε`, applying the
+/// formatting options to the value and the derivative alike.
+impl<F: fmt::Display, X> fmt::Display for Dual<F, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)?;
+        f.write_str(" + ")?;
+        self.derivative.fmt(f)?;
+        f.write_str("ε")
+    }
+}
+
+impl<F: PartialEq, X> PartialEq for Dual<F, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.derivative == other.derivative
+    }
+}
+
+impl<F: PartialOrd, X> PartialOrd for Dual<F, X> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.value.partial_cmp(&other.value) {
+            Some(Ordering::Equal) => self.derivative.partial_cmp(&other.derivative),
+            ordering => ordering,
+        }
+    }
+}
+
+macro_rules! impl_dual {
+    ($($real: ident: $conversion_method: ident),*) => {
+        $(
+            impl<X> Dual<$real, X> {
+                /// A value which does not depend on the variable.
+                pub const fn constant(value: $real) -> Self {
+                    Self::new(value, 0.0)
+                }
+
+                /// The variable itself, whose derivative is one.
+                pub const fn variable(value: $real) -> Self {
+                    Self::new(value, 1.0)
+                }
+
+                /// Applies the chain rule to a function with the given
+                /// value and derivative at `self.value`.
+                fn chain(self, value: $real, derivative: $real) -> Self {
+                    Self::new(value, derivative * self.derivative)
+                }
+            }
+
+            impl<X> QuantityStorage for Dual<$real, X> {}
+
+            impl<X> Add for Dual<$real, X> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+                }
+            }
+
+            impl<X> Sub for Dual<$real, X> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+                }
+            }
+
+            impl<X> Mul for Dual<$real, X> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    Self::new(
+                        self.value * rhs.value,
+                        self.derivative * rhs.value + self.value * rhs.derivative,
+                    )
+                }
+            }
+
+            impl<X> Div for Dual<$real, X> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self {
+                    Self::new(
+                        self.value / rhs.value,
+                        (self.derivative * rhs.value - self.value * rhs.derivative)
+                            / (rhs.value * rhs.value),
+                    )
+                }
+            }
+
+            impl<X> Neg for Dual<$real, X> {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    Self::new(-self.value, -self.derivative)
+                }
+            }
+
+            impl<X> AddAssign for Dual<$real, X> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<X> SubAssign for Dual<$real, X> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<X> MulAssign for Dual<$real, X> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<X> DivAssign for Dual<$real, X> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+
+            impl<X> Mul<$real> for Dual<$real, X> {
+                type Output = Self;
+
+                fn mul(self, rhs: $real) -> Self {
+                    Self::new(self.value * rhs, self.derivative * rhs)
+                }
+            }
+
+            impl<X> Div<$real> for Dual<$real, X> {
+                type Output = Self;
+
+                fn div(self, rhs: $real) -> Self {
+                    Self::new(self.value / rhs, self.derivative / rhs)
+                }
+            }
+
+            impl<X> Mul<Dual<$real, X>> for $real {
+                type Output = Dual<$real, X>;
+
+                fn mul(self, rhs: Dual<$real, X>) -> Dual<$real, X> {
+                    rhs * self
+                }
+            }
+
+            impl<X> Div<Dual<$real, X>> for $real {
+                type Output = Dual<$real, X>;
+
+                fn div(self, rhs: Dual<$real, X>) -> Dual<$real, X> {
+                    rhs.chain(self / rhs.value, -self / (rhs.value * rhs.value))
+                }
+            }
+
+            impl<X> Mul<Magnitude> for Dual<$real, X> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl<X> Div<Magnitude> for Dual<$real, X> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / rhs.$conversion_method()
+                }
+            }
+
+            /// The minimum and maximum ignore the derivatives.
+            impl<X> RealStorage for Dual<$real, X> {
+                fn zero() -> Self {
+                    Self::constant(0.0)
+                }
+
+                fn min(self, other: Self) -> Self {
+                    if other.value < self.value {
+                        other
+                    } else {
+                        self
+                    }
+                }
+
+                fn max(self, other: Self) -> Self {
+                    if other.value > self.value {
+                        other
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            impl<X> FloatStorage for Dual<$real, X> {
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    RealStorage::max(RealStorage::min(self, max), min)
+                }
+
+                fn is_nan(self) -> bool {
+                    self.value.is_nan() || self.derivative.is_nan()
+                }
+
+                first_order_float_methods!($real, chain);
+            }
+
+            impl<X> DualStorage for Dual<$real, X> {
+                type Value = $real;
+
+                fn constant(value: $real) -> Self {
+                    Self::constant(value)
+                }
+
+                fn variable(value: $real) -> Self {
+                    Self::variable(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_dual!(f32: into_f32, f64: into_f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::dual::Dual;
+    use crate::storage::FloatStorage;
+
+    type X = Dual<f64, ()>;
+
+    #[test]
+    fn dual_arithmetic() {
+        let x = X::variable(3.0);
+        let c = X::constant(2.0);
+        assert_eq!(x * x + c * x, X::new(15.0, 8.0));
+        assert_eq!(c / x, X::new(2.0 / 3.0, -2.0 / 9.0));
+        assert_eq!(1.0 / x, X::new(1.0 / 3.0, -1.0 / 9.0));
+        assert_eq!(x.powi(3), X::new(27.0, 27.0));
+        assert_eq!(x.powi(0), X::constant(1.0));
+    }
+
+    #[test]
+    fn dual_float_functions() {
+        let x = X::variable(4.0);
+        assert_eq!(x.sqrt(), X::new(2.0, 0.25));
+        assert_eq!(x.ln(), X::new(4.0f64.ln(), 0.25));
+        assert_eq!(X::variable(0.0).sin(), X::new(0.0, 1.0));
+        assert_eq!(X::variable(0.0).exp(), X::new(1.0, 1.0));
+    }
+}
@@ -0,0 +1,625 @@
+//! Closed intervals of floats with outward-rounded arithmetic.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::magnitude::Magnitude;
+use crate::storage::{FloatStorage, IntervalStorage, QuantityStorage, RealStorage};
+
+/// A closed interval `[lower, upper]` which is guaranteed to contain
+/// the exact result of the operations that produced it.
+///
+/// Arithmetic, `sqrt` and unit conversions round the lower bound
+/// down and the upper bound up, so that the bounds stay as tight as
+/// possible. The bounds of transcendental functions are widened by
+/// one unit in the last place, which assumes that the math library of
+/// the platform is accurate to within one unit in the last place.
+///
+/// Intervals are only ordered if they do not overlap, see
+/// [`Interval::compare`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Interval<F> {
+    pub lower: F,
+    pub upper: F,
+}
+
+/// The result of comparing two intervals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalOrdering {
+    /// Every value of the first interval is less than every value of
+    /// the second one.
+    Less,
+    /// Every value of the first interval is greater than every value
+    /// of the second one.
+    Greater,
+    /// The intervals have at least one value in common.
+    Overlapping,
+}
+
+impl<F> Interval<F> {
+    /// The lower bound must not be greater than the upper bound.
+    pub const fn new(lower: F, upper: F) -> Self {
+        Self { lower, upper }
+    }
+}
+
+impl<F: Copy> Interval<F> {
+    /// The interval containing only `value`.
+    pub const fn point(value: F) -> Self {
+        Self::new(value, value)
+    }
+}
+
+impl<F: PartialOrd> Interval<F> {
+    pub fn compare(&self, other: &Self) -> IntervalOrdering {
+        if self.upper < other.lower {
+            IntervalOrdering::Less
+        } else if self.lower > other.upper {
+            IntervalOrdering::Greater
+        } else {
+            IntervalOrdering::Overlapping
+        }
+    }
+
+    pub fn contains(&self, value: &F) -> bool {
+        self.lower <= *value && *value <= self.upper
+    }
+}
+
+/// Overlapping intervals are unordered, unless they are equal.
+impl<F: PartialOrd> PartialOrd for Interval<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        match self.compare(other) {
+            IntervalOrdering::Less => Some(Ordering::Less),
+            IntervalOrdering::Greater => Some(Ordering::Greater),
+            IntervalOrdering::Overlapping => None,
+        }
+    }
+}
+
+/// Formats the interval as `[lower, upper]`. Formatting options such
+/// as the precision apply to both bounds.
+impl<F: fmt::Display> fmt::Display for Interval<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        self.lower.fmt(f)?;
+        f.write_str(", ")?;
+        self.upper.fmt(f)?;
+        f.write_str("]")
+    }
+}
+
+macro_rules! impl_interval {
+    ($($real: ident: $conversion_method: ident),*) => {
+        $(
+            impl Interval<$real> {
+                /// The interval of all values, which is the result of
+                /// dividing by an interval that contains zero.
+                pub const ENTIRE: Self = Self::new($real::NEG_INFINITY, $real::INFINITY);
+
+                pub fn midpoint(self) -> $real {
+                    self.lower / 2.0 + self.upper / 2.0
+                }
+
+                /// The width of the interval, rounded up.
+                pub fn width(self) -> $real {
+                    Self::sum_bounds(self.upper, -self.lower).1
+                }
+
+                pub fn abs(self) -> Self {
+                    if self.lower >= 0.0 {
+                        self
+                    } else if self.upper <= 0.0 {
+                        -self
+                    } else {
+                        Self::new(0.0, (-self.lower).max(self.upper))
+                    }
+                }
+
+                /// The closest floats below and above the exact result,
+                /// given the result rounded to nearest and the sign of
+                /// the rounding error.
+                fn bracket(value: $real, error: $real) -> ($real, $real) {
+                    if value.is_infinite() {
+                        if value > 0.0 {
+                            ($real::MAX, value)
+                        } else {
+                            (value, $real::MIN)
+                        }
+                    } else if error > 0.0 {
+                        (value, value.next_up())
+                    } else if error < 0.0 {
+                        (value.next_down(), value)
+                    } else {
+                        (value, value)
+                    }
+                }
+
+                fn sum_bounds(a: $real, b: $real) -> ($real, $real) {
+                    let sum = a + b;
+                    let b_rounded = sum - a;
+                    let error = (a - (sum - b_rounded)) + (b - b_rounded);
+                    Self::bracket(sum, error)
+                }
+
+                fn product_bounds(a: $real, b: $real) -> ($real, $real) {
+                    let product = a * b;
+                    // Zero times infinity is zero for the purpose of bounds.
+                    if product.is_nan() && !a.is_nan() && !b.is_nan() {
+                        return (0.0, 0.0);
+                    }
+                    Self::bracket(product, a.mul_add(b, -product))
+                }
+
+                fn quotient_bounds(a: $real, b: $real) -> ($real, $real) {
+                    let quotient = a / b;
+                    let error = (-quotient).mul_add(b, a);
+                    Self::bracket(quotient, if b > 0.0 { error } else { -error })
+                }
+
+                fn sqrt_bounds(a: $real) -> ($real, $real) {
+                    let sqrt = a.sqrt();
+                    Self::bracket(sqrt, (-sqrt).mul_add(sqrt, a))
+                }
+
+                fn from_bounds(bounds: [($real, $real); 4]) -> Self {
+                    let lower = bounds.iter().map(|b| b.0).fold($real::INFINITY, $real::min);
+                    let upper = bounds.iter().map(|b| b.1).fold($real::NEG_INFINITY, $real::max);
+                    Self::new(lower, upper)
+                }
+
+                /// The interval containing the factor, which is a
+                /// single float if the factor is exactly representable.
+                fn from_magnitude(magnitude: Magnitude) -> Self {
+                    let value = magnitude.$conversion_method();
+                    if Magnitude::from_f64(value as f64) == magnitude {
+                        Self::point(value)
+                    } else {
+                        Self::new(value.next_down(), value.next_up())
+                    }
+                }
+
+                fn increasing(self, f: fn($real) -> $real) -> Self {
+                    Self::new(f(self.lower).next_down(), f(self.upper).next_up())
+                }
+
+                fn decreasing(self, f: fn($real) -> $real) -> Self {
+                    Self::new(f(self.upper).next_down(), f(self.lower).next_up())
+                }
+
+                /// Whether the interval contains `offset + k * period`
+                /// for any integer `k`. Since `offset` and `period` are
+                /// rounded, the error of the position grows with `k`,
+                /// so positions within that error of the interval count
+                /// as contained. For large arguments, the error exceeds
+                /// the period and every interval contains a position.
+                fn contains_periodic(self, offset: $real, period: $real) -> bool {
+                    let width = self.upper - self.lower;
+                    if width.is_nan() || width >= period {
+                        return true;
+                    }
+                    let k = ((self.lower - offset) / period).ceil();
+                    [k - 1.0, k, k + 1.0].into_iter().any(|k| {
+                        let position = k.mul_add(period, offset);
+                        let error = ((k.abs() + 1.0) * period + position.abs()) * $real::EPSILON;
+                        position - error <= self.upper && position + error >= self.lower
+                    })
+                }
+
+                /// The bounds of `sin` or `cos`, given the positions of
+                /// their maximum and minimum.
+                fn periodic(self, f: fn($real) -> $real, maximum_at: $real, minimum_at: $real) -> Self {
+                    let (a, b) = (f(self.lower), f(self.upper));
+                    let upper = if self.contains_periodic(maximum_at, core::$real::consts::TAU) {
+                        1.0
+                    } else {
+                        a.max(b).next_up().min(1.0)
+                    };
+                    let lower = if self.contains_periodic(minimum_at, core::$real::consts::TAU) {
+                        -1.0
+                    } else {
+                        a.min(b).next_down().max(-1.0)
+                    };
+                    Self::new(lower, upper)
+                }
+
+                fn powu(self, n: u32) -> Self {
+                    if self.lower >= 0.0 {
+                        let (mut result, mut base, mut n) = (Self::point(1.0), self, n);
+                        while n > 0 {
+                            if n % 2 == 1 {
+                                result *= base;
+                            }
+                            base *= base;
+                            n /= 2;
+                        }
+                        result
+                    } else if self.upper <= 0.0 {
+                        let result = (-self).powu(n);
+                        if n % 2 == 1 {
+                            -result
+                        } else {
+                            result
+                        }
+                    } else {
+                        let positive = Self::new(0.0, self.upper).powu(n).upper;
+                        let negative = Self::new(0.0, -self.lower).powu(n).upper;
+                        if n % 2 == 1 {
+                            Self::new(-negative, positive)
+                        } else {
+                            Self::new(0.0, positive.max(negative))
+                        }
+                    }
+                }
+            }
+
+            impl QuantityStorage for Interval<$real> {}
+
+            impl Add for Interval<$real> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self::new(
+                        Self::sum_bounds(self.lower, rhs.lower).0,
+                        Self::sum_bounds(self.upper, rhs.upper).1,
+                    )
+                }
+            }
+
+            impl Sub for Interval<$real> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    self + (-rhs)
+                }
+            }
+
+            impl Mul for Interval<$real> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    Self::from_bounds([
+                        Self::product_bounds(self.lower, rhs.lower),
+                        Self::product_bounds(self.lower, rhs.upper),
+                        Self::product_bounds(self.upper, rhs.lower),
+                        Self::product_bounds(self.upper, rhs.upper),
+                    ])
+                }
+            }
+
+            impl Div for Interval<$real> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self {
+                    if rhs.lower <= 0.0 && rhs.upper >= 0.0 {
+                        return Self::ENTIRE;
+                    }
+                    Self::from_bounds([
+                        Self::quotient_bounds(self.lower, rhs.lower),
+                        Self::quotient_bounds(self.lower, rhs.upper),
+                        Self::quotient_bounds(self.upper, rhs.lower),
+                        Self::quotient_bounds(self.upper, rhs.upper),
+                    ])
+                }
+            }
+
+            impl Neg for Interval<$real> {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    Self::new(-self.upper, -self.lower)
+                }
+            }
+
+            impl AddAssign for Interval<$real> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl SubAssign for Interval<$real> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl MulAssign for Interval<$real> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl DivAssign for Interval<$real> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+
+            impl Mul<$real> for Interval<$real> {
+                type Output = Self;
+
+                fn mul(self, rhs: $real) -> Self {
+                    self * Self::point(rhs)
+                }
+            }
+
+            impl Div<$real> for Interval<$real> {
+                type Output = Self;
+
+                fn div(self, rhs: $real) -> Self {
+                    self / Self::point(rhs)
+                }
+            }
+
+            impl Mul<Interval<$real>> for $real {
+                type Output = Interval<$real>;
+
+                fn mul(self, rhs: Interval<$real>) -> Interval<$real> {
+                    Interval::point(self) * rhs
+                }
+            }
+
+            impl Div<Interval<$real>> for $real {
+                type Output = Interval<$real>;
+
+                fn div(self, rhs: Interval<$real>) -> Interval<$real> {
+                    Interval::point(self) / rhs
+                }
+            }
+
+            impl Mul<Magnitude> for Interval<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * Self::from_magnitude(rhs)
+                }
+            }
+
+            impl Div<Magnitude> for Interval<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / Self::from_magnitude(rhs)
+                }
+            }
+
+            /// The minimum and maximum are taken over all pairs of
+            /// values in both intervals.
+            impl RealStorage for Interval<$real> {
+                fn zero() -> Self {
+                    Self::point(0.0)
+                }
+
+                fn min(self, other: Self) -> Self {
+                    Self::new(self.lower.min(other.lower), self.upper.min(other.upper))
+                }
+
+                fn max(self, other: Self) -> Self {
+                    Self::new(self.lower.max(other.lower), self.upper.max(other.upper))
+                }
+            }
+
+            impl FloatStorage for Interval<$real> {
+                fn powi(self, n: i32) -> Self {
+                    if n >= 0 {
+                        self.powu(n as u32)
+                    } else {
+                        1.0 / self.powu(n.unsigned_abs())
+                    }
+                }
+
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    RealStorage::max(RealStorage::min(self, max), min)
+                }
+
+                fn is_nan(self) -> bool {
+                    self.lower.is_nan() || self.upper.is_nan()
+                }
+
+                /// Negative values are outside the domain, so the lower
+                /// bound is at least zero.
+                fn sqrt(self) -> Self {
+                    Self::new(
+                        Self::sqrt_bounds(self.lower.max(0.0)).0,
+                        Self::sqrt_bounds(self.upper).1,
+                    )
+                }
+
+                fn cbrt(self) -> Self {
+                    self.increasing($real::cbrt)
+                }
+
+                fn log2(self) -> Self {
+                    self.increasing($real::log2)
+                }
+
+                fn ln(self) -> Self {
+                    self.increasing($real::ln)
+                }
+
+                fn log10(self) -> Self {
+                    self.increasing($real::log10)
+                }
+
+                fn exp(self) -> Self {
+                    self.increasing($real::exp)
+                }
+
+                fn exp2(self) -> Self {
+                    self.increasing($real::exp2)
+                }
+
+                fn ceil(self) -> Self {
+                    Self::new(self.lower.ceil(), self.upper.ceil())
+                }
+
+                fn floor(self) -> Self {
+                    Self::new(self.lower.floor(), self.upper.floor())
+                }
+
+                fn sin(self) -> Self {
+                    self.periodic(
+                        $real::sin,
+                        core::$real::consts::FRAC_PI_2,
+                        -core::$real::consts::FRAC_PI_2,
+                    )
+                }
+
+                fn cos(self) -> Self {
+                    self.periodic($real::cos, 0.0, core::$real::consts::PI)
+                }
+
+                fn tan(self) -> Self {
+                    if self.contains_periodic(core::$real::consts::FRAC_PI_2, core::$real::consts::PI) {
+                        Self::ENTIRE
+                    } else {
+                        self.increasing($real::tan)
+                    }
+                }
+
+                fn asin(self) -> Self {
+                    self.increasing($real::asin)
+                }
+
+                fn acos(self) -> Self {
+                    self.decreasing($real::acos)
+                }
+
+                fn atan(self) -> Self {
+                    self.increasing($real::atan)
+                }
+
+                fn sinh(self) -> Self {
+                    self.increasing($real::sinh)
+                }
+
+                fn cosh(self) -> Self {
+                    if self.lower >= 0.0 {
+                        self.increasing($real::cosh)
+                    } else if self.upper <= 0.0 {
+                        self.decreasing($real::cosh)
+                    } else {
+                        Self::new(1.0, self.lower.cosh().max(self.upper.cosh()).next_up())
+                    }
+                }
+
+                fn tanh(self) -> Self {
+                    self.increasing($real::tanh)
+                }
+
+                fn asinh(self) -> Self {
+                    self.increasing($real::asinh)
+                }
+
+                fn acosh(self) -> Self {
+                    self.increasing($real::acosh)
+                }
+
+                fn atanh(self) -> Self {
+                    self.increasing($real::atanh)
+                }
+
+                fn exp_m1(self) -> Self {
+                    self.increasing($real::exp_m1)
+                }
+
+                fn ln_1p(self) -> Self {
+                    self.increasing($real::ln_1p)
+                }
+            }
+
+            impl IntervalStorage for Interval<$real> {
+                type Bound = $real;
+
+                fn midpoint(self) -> $real {
+                    Self::midpoint(self)
+                }
+
+                fn width(self) -> $real {
+                    Self::width(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_interval!(f32: into_f32, f64: into_f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::{Interval, IntervalOrdering};
+    use crate::magnitude::Magnitude;
+    use crate::storage::FloatStorage;
+
+    #[test]
+    fn interval_arithmetic_rounds_outward() {
+        let sum = Interval::point(0.1) + Interval::point(0.2);
+        assert_eq!(sum, Interval::new(0.3, 0.30000000000000004));
+        let third = Interval::point(1.0f64) / Interval::point(3.0);
+        assert_eq!(third.upper, third.lower.next_up());
+        let exact = Interval::new(1.5, 2.0) * Interval::new(-2.0, 4.0);
+        assert_eq!(exact, Interval::new(-4.0, 8.0));
+        assert_eq!(
+            Interval::new(1.0, 2.0) / Interval::new(-1.0, 1.0),
+            Interval::<f64>::ENTIRE
+        );
+    }
+
+    #[test]
+    fn interval_magnitude_conversion() {
+        let x = Interval::new(1.5, 2.0);
+        assert_eq!(
+            x * Magnitude::from_decimal(1, 3),
+            Interval::new(1500.0, 2000.0)
+        );
+        let tenth = Interval::point(1.0f64) * Magnitude::from_decimal(1, -1);
+        assert!(tenth.lower < tenth.upper);
+        assert!(tenth.contains(&0.1));
+    }
+
+    #[test]
+    fn interval_float_functions() {
+        assert_eq!(Interval::new(-2.0, 3.0).powi(2), Interval::new(0.0, 9.0));
+        assert_eq!(Interval::new(-2.0, 3.0).powi(3), Interval::new(-8.0, 27.0));
+        assert_eq!(Interval::new(4.0, 9.0).sqrt(), Interval::new(2.0, 3.0));
+        let sin = Interval::new(1.0, 2.0).sin();
+        assert_eq!(sin.upper, 1.0);
+        assert!(sin.contains(&1.0f64.sin()));
+        assert_eq!(Interval::new(-4.0, 0.0).cos().lower, -1.0);
+        assert_eq!(Interval::new(1.0, 2.0).tan(), Interval::<f64>::ENTIRE);
+    }
+
+    #[test]
+    fn interval_float_functions_at_domain_edges() {
+        assert_eq!(Interval::new(-1.0, 4.0).sqrt(), Interval::new(0.0, 2.0));
+        // Contains the minimum of sin at 3 pi / 2 + 159153 tau,
+        // which the rounded tau places just above the interval.
+        let sin = Interval::new(999986.2203972259, 999986.220397226).sin();
+        assert_eq!(sin.lower, -1.0);
+        let sin = Interval::new(1_000_001.9_f32, 1_000_001.94).sin();
+        assert_eq!(sin.upper, 1.0);
+        assert_eq!(Interval::point(1e17).sin(), Interval::new(-1.0, 1.0));
+    }
+
+    #[test]
+    fn interval_comparison() {
+        let x = Interval::new(1.0, 2.0);
+        assert_eq!(x.compare(&Interval::new(3.0, 4.0)), IntervalOrdering::Less);
+        assert_eq!(
+            x.compare(&Interval::new(-1.0, 0.5)),
+            IntervalOrdering::Greater
+        );
+        assert_eq!(
+            x.compare(&Interval::new(1.5, 4.0)),
+            IntervalOrdering::Overlapping
+        );
+        assert!(x < Interval::new(3.0, 4.0));
+        assert_eq!(x.partial_cmp(&Interval::new(1.5, 4.0)), None);
+    }
+}
@@ -3,10 +3,20 @@
 #![feature(generic_const_exprs, adt_const_params)]
 
 pub mod dimension_exponent;
+#[cfg(feature = "dual")]
+pub mod dual;
+#[cfg(feature = "interval")]
+pub mod interval;
+pub mod logarithmic_scale;
 pub mod magnitude;
+#[cfg(feature = "measurement")]
+pub mod measurement;
+pub mod ordered;
 #[cfg(any(feature = "std", feature = "num-traits-libm"))]
 pub mod ratio;
 pub mod runtime_unit_storage;
+pub mod scaling;
+pub mod storage;
 
 pub mod num_traits_reexport {
     #[cfg(feature = "num-traits-libm")]
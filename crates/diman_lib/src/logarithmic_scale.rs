@@ -0,0 +1,30 @@
+use core::marker::ConstParamTy;
+
+use crate::magnitude::Magnitude;
+
+/// Describes a logarithmic unit such as the decibel-milliwatt.
+/// The level `L` of a quantity `q` is given by
+/// `L = factor * log_base(q / reference)`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ConstParamTy)]
+pub struct LogarithmicScale {
+    /// The reference quantity in base units.
+    pub reference: Magnitude,
+    pub factor: Magnitude,
+    pub base: Magnitude,
+    /// Whether the scale measures a root-power quantity (such as a
+    /// voltage or a sound pressure), the square of which is
+    /// proportional to power.
+    pub root_power: bool,
+}
+
+impl LogarithmicScale {
+    /// The exponent that turns the underlying quantity into a quantity
+    /// proportional to power. Levels are added by summing these powers.
+    pub fn power_exponent(&self) -> f64 {
+        if self.root_power {
+            2.0
+        } else {
+            1.0
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::{
     marker::ConstParamTy,
-    ops::{Div, Mul},
+    ops::{Add, Div, Mul, Sub},
 };
 
 #[cfg(feature = "num-traits-libm")]
@@ -9,17 +9,30 @@ pub use num_traits::float::Float;
 #[cfg(not(feature = "num-traits-libm"))]
 pub use num_traits::float::FloatCore;
 
-pub const MAX_NUM_FACTORS: usize = 10;
-
+/// The largest power of five which fits into a `u64`.
+const MAX_POWER_OF_FIVE: u32 = 27;
+
+/// An exact representation of the factor of a unit, given by
+/// `sign * num / denom * 2^pow2 * 10^pow10`.
+///
+/// Magnitudes are always normalized such that `num` and `denom`
+/// are coprime and divisible by neither two nor five. This makes the
+/// representation of a value unique, so that equal magnitudes result
+/// in equal const parameters. Products and quotients of magnitudes
+/// are exact, unless the numerator or denominator exceeds 64 bits, in
+/// which case they are rounded to 64 bits of precision. Conversions
+/// to floats only round once.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, ConstParamTy)]
 pub struct Magnitude {
-    pub mantissa: u64,
-    pub exponent: i16,
     pub sign: i8,
+    pub num: u64,
+    pub denom: u64,
+    pub pow2: i16,
+    pub pow10: i16,
 }
 
 // From num-traits
-fn integer_decode_f64(f: f64) -> (u64, i16, i8) {
+const fn integer_decode_f64(f: f64) -> (u64, i16, i8) {
     let bits: u64 = f.to_bits();
     let sign: i8 = if bits >> 63 == 0 { 1 } else { -1 };
     let mut exponent: i16 = ((bits >> 52) & 0x7ff) as i16;
@@ -33,31 +46,347 @@ fn integer_decode_f64(f: f64) -> (u64, i16, i8) {
     (mantissa, exponent, sign)
 }
 
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Rounds `x` to the nearest multiple of `2^shift` and divides by it.
+const fn shift_right_rounded(x: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        x
+    } else {
+        (x >> shift) + ((x >> (shift - 1)) & 1)
+    }
+}
+
+/// Multiplies `x` by `2^exponent`. This is exact as long as
+/// the result is a normal float.
+#[inline(always)]
+const fn mul_pow2(mut x: f64, mut exponent: i32) -> f64 {
+    while exponent > 1000 {
+        x *= f64::from_bits(((1000 + 1023) as u64) << 52);
+        exponent -= 1000;
+    }
+    while exponent < -1000 {
+        x *= f64::from_bits(((-1000 + 1023) as u64) << 52);
+        exponent += 1000;
+    }
+    x * f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+/// A binary floating point number with a wide mantissa, which is
+/// used as an intermediate result when converting magnitudes to floats.
+/// The value is given by `mantissa * 2^exponent`. `inexact` records
+/// whether any nonzero bits were truncated along the way.
+struct WideFloat {
+    mantissa: u128,
+    exponent: i32,
+    inexact: bool,
+}
+
+impl WideFloat {
+    /// Truncates the mantissa to at most `bits` bits.
+    #[inline(always)]
+    const fn truncate(mut self, bits: u32) -> Self {
+        let len = 128 - self.mantissa.leading_zeros();
+        if len > bits {
+            let shift = len - bits;
+            self.inexact |= self.mantissa & ((1 << shift) - 1) != 0;
+            self.mantissa >>= shift;
+            self.exponent += shift as i32;
+        }
+        self
+    }
+
+    #[inline(always)]
+    const fn mul(self, factor: u64) -> Self {
+        let mut result = self.truncate(64);
+        result.mantissa *= factor as u128;
+        result
+    }
+
+    #[inline(always)]
+    const fn div(mut self, divisor: u64) -> Self {
+        let shift = self.mantissa.leading_zeros();
+        self.mantissa <<= shift;
+        self.exponent -= shift as i32;
+        self.inexact |= !self.mantissa.is_multiple_of(divisor as u128);
+        self.mantissa /= divisor as u128;
+        self
+    }
+
+    /// Returns a 64 bit mantissa and the corresponding exponent. Truncated
+    /// bits are accounted for in the lowest bit of the mantissa, so that
+    /// converting the mantissa to a float rounds correctly.
+    #[inline(always)]
+    const fn into_parts(self) -> (u64, i32) {
+        let result = self.truncate(64);
+        (
+            result.mantissa as u64 | result.inexact as u64,
+            result.exponent,
+        )
+    }
+}
+
 impl Magnitude {
-    pub fn from_f64(f: f64) -> Self {
-        let (mantissa, exponent, sign) = integer_decode_f64(f);
+    const ZERO: Self = Self {
+        sign: 1,
+        num: 0,
+        denom: 1,
+        pow2: 0,
+        pow10: 0,
+    };
+
+    const fn normalize(
+        sign: i8,
+        mut num: u128,
+        mut denom: u128,
+        mut pow2: i32,
+        mut pow10: i32,
+    ) -> Self {
+        if num == 0 {
+            return Self::ZERO;
+        }
+        loop {
+            let divisor = gcd(num, denom);
+            num /= divisor;
+            denom /= divisor;
+            let zeros = num.trailing_zeros();
+            num >>= zeros;
+            pow2 += zeros as i32;
+            let zeros = denom.trailing_zeros();
+            denom >>= zeros;
+            pow2 -= zeros as i32;
+            // 5 = 10 / 2
+            while num.is_multiple_of(5) {
+                num /= 5;
+                pow10 += 1;
+                pow2 -= 1;
+            }
+            while denom.is_multiple_of(5) {
+                denom /= 5;
+                pow10 -= 1;
+                pow2 += 1;
+            }
+            let num_excess = (128 - num.leading_zeros()).saturating_sub(64);
+            let denom_excess = (128 - denom.leading_zeros()).saturating_sub(64);
+            if num_excess == 0 && denom_excess == 0 {
+                break;
+            }
+            num = shift_right_rounded(num, num_excess);
+            pow2 += num_excess as i32;
+            denom = shift_right_rounded(denom, denom_excess);
+            pow2 -= denom_excess as i32;
+        }
         Self {
-            mantissa,
-            exponent,
             sign,
+            num: num as u64,
+            denom: denom as u64,
+            pow2: pow2 as i16,
+            pow10: pow10 as i16,
+        }
+    }
+
+    pub const fn one() -> Self {
+        Self::from_decimal(1, 0)
+    }
+
+    /// Returns `significand * 10^exponent`.
+    pub const fn from_decimal(significand: u64, exponent: i16) -> Self {
+        Self::normalize(1, significand as u128, 1, 0, exponent as i32)
+    }
+
+    pub const fn from_power_of_two(exponent: i16) -> Self {
+        Self::normalize(1, 1, 1, exponent as i32, 0)
+    }
+
+    /// Returns the exact value of the float.
+    pub const fn from_f64(f: f64) -> Self {
+        let (mantissa, exponent, sign) = integer_decode_f64(f);
+        Self::normalize(sign, mantissa as u128, 1, exponent as i32, 0)
+    }
+
+    // The conversion into floats is always inlined, so that it is
+    // folded into a constant for the magnitudes of units.
+    #[inline(always)]
+    const fn into_parts(self) -> (u64, i32) {
+        if self.num == 0 {
+            return (0, 0);
+        }
+        let mut value = WideFloat {
+            mantissa: self.num as u128,
+            exponent: self.pow2 as i32 + self.pow10 as i32,
+            inexact: false,
+        }
+        .div(self.denom);
+        let mut remaining = self.pow10.unsigned_abs() as u32;
+        while remaining > 0 {
+            let power = if remaining < MAX_POWER_OF_FIVE {
+                remaining
+            } else {
+                MAX_POWER_OF_FIVE
+            };
+            let factor = 5u64.pow(power);
+            value = if self.pow10 > 0 {
+                value.mul(factor)
+            } else {
+                value.div(factor)
+            };
+            remaining -= power;
+        }
+        value.into_parts()
+    }
+
+    #[inline(always)]
+    pub const fn into_f64(self) -> f64 {
+        let (mantissa, exponent) = self.into_parts();
+        self.sign as f64 * mul_pow2(mantissa as f64, exponent)
+    }
+
+    #[inline(always)]
+    pub const fn into_f32(self) -> f32 {
+        let (mantissa, exponent) = self.into_parts();
+        // The product is exact, so this only rounds once.
+        (self.sign as f64 * mul_pow2(mantissa as f32 as f64, exponent)) as f32
+    }
+
+    /// Rounds the exact value once, like [`Magnitude::into_f32`].
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    pub const fn into_f16(self) -> half::f16 {
+        half::f16::from_f64_const(self.into_rounded_f64(11, -24))
+    }
+
+    /// Rounds the exact value once, like [`Magnitude::into_f32`].
+    #[cfg(feature = "half")]
+    #[inline(always)]
+    pub const fn into_bf16(self) -> half::bf16 {
+        half::bf16::from_f64_const(self.into_rounded_f64(8, -133))
+    }
+
+    /// Rounds the magnitude to `bits` significant bits (ties to even),
+    /// but not below a resolution of `2^min_exponent`, as for the
+    /// subnormal numbers of a smaller float type. The result is exact
+    /// in `f64`, so that converting it into that type does not round
+    /// a second time.
+    #[cfg(feature = "half")]
+    const fn into_rounded_f64(self, bits: u32, min_exponent: i32) -> f64 {
+        let (mantissa, exponent) = self.into_parts();
+        if mantissa == 0 {
+            return 0.0;
+        }
+        let length = (u64::BITS - mantissa.leading_zeros()) as i32;
+        let mut shift = length - bits as i32;
+        if exponent + shift < min_exponent {
+            shift = min_exponent - exponent;
         }
+        if shift <= 0 {
+            return self.sign as f64 * mul_pow2(mantissa as f64, exponent);
+        }
+        if shift >= 128 {
+            return self.sign as f64 * 0.0;
+        }
+        // `into_parts` sets the last bit of the mantissa if the
+        // magnitude is not exact, so that ties are only detected for
+        // exact values.
+        let mantissa = mantissa as u128;
+        let kept = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        let rounded = if remainder > half || (remainder == half && kept & 1 == 1) {
+            kept + 1
+        } else {
+            kept
+        };
+        self.sign as f64 * mul_pow2(rounded as f64, exponent + shift)
+    }
+
+    pub const fn neg(self) -> Self {
+        Self {
+            sign: -self.sign,
+            ..self
+        }
+    }
+
+    pub const fn inv(self) -> Self {
+        if self.num == 0 {
+            panic!("Division by zero magnitude.");
+        }
+        Self {
+            sign: self.sign,
+            num: self.denom,
+            denom: self.num,
+            pow2: -self.pow2,
+            pow10: -self.pow10,
+        }
+    }
+
+    pub const fn mul(self, rhs: Self) -> Self {
+        Self::normalize(
+            self.sign * rhs.sign,
+            self.num as u128 * rhs.num as u128,
+            self.denom as u128 * rhs.denom as u128,
+            self.pow2 as i32 + rhs.pow2 as i32,
+            self.pow10 as i32 + rhs.pow10 as i32,
+        )
     }
 
-    pub fn into_f64(self) -> f64 {
-        self.sign as f64 * self.mantissa as f64 * 2.0f64.powi(self.exponent as i32)
+    pub const fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inv())
     }
 
-    pub fn into_f32(self) -> f32 {
-        self.into_f64() as f32
+    pub const fn powi(self, exponent: i32) -> Self {
+        let mut result = Self::one();
+        let mut base = if exponent < 0 { self.inv() } else { self };
+        let mut exponent = exponent.unsigned_abs();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(base);
+            }
+            base = base.mul(base);
+            exponent >>= 1;
+        }
+        result
     }
 
+    /// Returns the exact root if there is one.
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn root(&self, n: i64) -> Option<Self> {
+        fn integer_root(x: u64, n: i64) -> Option<u64> {
+            let estimate = (x as f64).powf(1.0 / n as f64).round() as u64;
+            (estimate.saturating_sub(1)..=estimate.saturating_add(1))
+                .find(|root| root.checked_pow(n as u32) == Some(x))
+        }
+        if (self.sign < 0 && n % 2 == 0) || self.pow2 as i64 % n != 0 || self.pow10 as i64 % n != 0
+        {
+            return None;
+        }
+        Some(Self {
+            sign: self.sign,
+            num: integer_root(self.num, n)?,
+            denom: integer_root(self.denom, n)?,
+            pow2: (self.pow2 as i64 / n) as i16,
+            pow10: (self.pow10 as i64 / n) as i16,
+        })
+    }
+
+    /// Raises the magnitude to the power `num / denom`. The result is
+    /// exact if the root exists, and rounded otherwise.
     #[cfg(any(feature = "std", feature = "num-traits-libm"))]
     pub fn pow_rational(&self, num: i64, denom: i64) -> Magnitude {
-        Self::from_f64(self.into_f64().powf(num as f64 / denom as f64))
+        match self.root(denom) {
+            Some(root) => root.powi(num as i32),
+            None => Self::from_f64(self.into_f64().powf(num as f64 / denom as f64)),
+        }
     }
 
     pub fn is_one(&self) -> bool {
-        self.into_f64() == 1.0
+        *self == Self::one()
     }
 }
 
@@ -65,7 +394,7 @@ impl Mul<Magnitude> for Magnitude {
     type Output = Self;
 
     fn mul(self, rhs: Magnitude) -> Self::Output {
-        Self::from_f64(self.into_f64() * rhs.into_f64())
+        Magnitude::mul(self, rhs)
     }
 }
 
@@ -73,7 +402,7 @@ impl Div<Magnitude> for Magnitude {
     type Output = Self;
 
     fn div(self, rhs: Magnitude) -> Self::Output {
-        Self::from_f64(self.into_f64() / rhs.into_f64())
+        Magnitude::div(self, rhs)
     }
 }
 
@@ -81,7 +410,7 @@ impl Mul<f64> for Magnitude {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        Self::from_f64(self.into_f64() * rhs)
+        Magnitude::mul(self, Self::from_f64(rhs))
     }
 }
 
@@ -89,13 +418,14 @@ impl Div<f64> for Magnitude {
     type Output = Self;
 
     fn div(self, rhs: f64) -> Self::Output {
-        Self::from_f64(self.into_f64() / rhs)
+        Magnitude::div(self, Self::from_f64(rhs))
     }
 }
 
 impl Mul<Magnitude> for f64 {
     type Output = Self;
 
+    #[inline(always)]
     fn mul(self, rhs: Magnitude) -> Self::Output {
         self * rhs.into_f64()
     }
@@ -104,6 +434,7 @@ impl Mul<Magnitude> for f64 {
 impl Div<Magnitude> for f64 {
     type Output = Self;
 
+    #[inline(always)]
     fn div(self, rhs: Magnitude) -> Self::Output {
         self / rhs.into_f64()
     }
@@ -113,7 +444,7 @@ impl Mul<f32> for Magnitude {
     type Output = Self;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        Self::from_f64((self.into_f32() * rhs) as f64)
+        Magnitude::mul(self, Self::from_f64(rhs as f64))
     }
 }
 
@@ -121,13 +452,14 @@ impl Div<f32> for Magnitude {
     type Output = Self;
 
     fn div(self, rhs: f32) -> Self::Output {
-        Self::from_f64((self.into_f32() / rhs) as f64)
+        Magnitude::div(self, Self::from_f64(rhs as f64))
     }
 }
 
 impl Mul<Magnitude> for f32 {
     type Output = Self;
 
+    #[inline(always)]
     fn mul(self, rhs: Magnitude) -> Self::Output {
         self * rhs.into_f32()
     }
@@ -136,11 +468,48 @@ impl Mul<Magnitude> for f32 {
 impl Div<Magnitude> for f32 {
     type Output = Self;
 
+    #[inline(always)]
     fn div(self, rhs: Magnitude) -> Self::Output {
         self / rhs.into_f32()
     }
 }
 
+impl Add<Magnitude> for f64 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Magnitude) -> Self::Output {
+        self + rhs.into_f64()
+    }
+}
+
+impl Sub<Magnitude> for f64 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Magnitude) -> Self::Output {
+        self - rhs.into_f64()
+    }
+}
+
+impl Add<Magnitude> for f32 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Magnitude) -> Self::Output {
+        self + rhs.into_f32()
+    }
+}
+
+impl Sub<Magnitude> for f32 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Magnitude) -> Self::Output {
+        self - rhs.into_f32()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::magnitude::Magnitude;
@@ -153,10 +522,87 @@ mod tests {
         for x in 0..10000 {
             let x = (x as f64) * 0.01;
             check_equality(x);
+            check_equality(-x);
         }
         for exp in -50..50 {
             let x = 2.0f64.powi(exp);
             check_equality(x);
         }
+        check_equality(f64::MAX);
+        check_equality(f64::MIN_POSITIVE);
+    }
+
+    #[test]
+    fn magnitude_arithmetic_is_exact() {
+        let third = Magnitude::from_decimal(1, 0) / Magnitude::from_decimal(3, 0);
+        assert_eq!(third * Magnitude::from_decimal(3, 0), Magnitude::one());
+        let inch = Magnitude::from_decimal(254, -4);
+        assert_eq!(inch / inch, Magnitude::one());
+        assert_eq!(
+            Magnitude::from_decimal(1, -1).powi(3),
+            Magnitude::from_decimal(1, -3)
+        );
+        assert_eq!(
+            Magnitude::from_decimal(1000, 0),
+            Magnitude::from_decimal(1, 3)
+        );
+        assert_eq!(
+            Magnitude::from_power_of_two(10),
+            Magnitude::from_decimal(1024, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn magnitude_exact_roots() {
+        assert_eq!(
+            Magnitude::from_decimal(1, 2).pow_rational(1, 2),
+            Magnitude::from_decimal(1, 1)
+        );
+        assert_eq!(
+            Magnitude::from_decimal(8, -3).pow_rational(2, 3),
+            Magnitude::from_decimal(4, -2)
+        );
+    }
+
+    #[test]
+    fn magnitude_rounds_once() {
+        let third = Magnitude::one() / Magnitude::from_decimal(3, 0);
+        assert_eq!(third.into_f64(), 1.0 / 3.0);
+        assert_eq!(third.into_f32(), 1.0f32 / 3.0);
+        assert_eq!(Magnitude::from_decimal(1, -3).into_f64(), 1e-3);
+        assert_eq!(Magnitude::from_decimal(1, -30).into_f64(), 1e-30);
+        assert_eq!(
+            Magnitude::from_decimal(1602176634, -28).into_f64(),
+            1.602176634e-19
+        );
+        assert_eq!(Magnitude::from_decimal(1, 30).into_f32(), 1e30f32);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn magnitude_into_half() {
+        use half::{bf16, f16};
+        let third = Magnitude::one() / Magnitude::from_decimal(3, 0);
+        assert_eq!(third.into_f16(), f16::from_f64(1.0 / 3.0));
+        assert_eq!(third.into_bf16(), bf16::from_f64(1.0 / 3.0));
+        assert_eq!(Magnitude::from_decimal(1, -9).into_f16(), f16::ZERO);
+        assert_eq!(
+            Magnitude::from_decimal(1, -9).into_bf16(),
+            bf16::from_f64(1e-9)
+        );
+        assert_eq!(
+            Magnitude::from_decimal(3, -8).into_f16(),
+            f16::from_f64(3e-8),
+            "subnormal"
+        );
+        // Just above halfway between two values, which rounding
+        // via `f32` would turn into a tie and round down.
+        let above_halfway = |bits: u32| {
+            Magnitude::from_decimal((1u64 << 30) + (1 << (30 - bits)) + 1, 0)
+                * Magnitude::from_power_of_two(-30)
+        };
+        assert_eq!(above_halfway(11).into_f16(), f16::from_bits(0x3c01));
+        assert_eq!(above_halfway(8).into_bf16(), bf16::from_bits(0x3f81));
     }
 }
@@ -0,0 +1,309 @@
+//! Measured values together with their standard uncertainty.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+use crate::magnitude::Magnitude;
+use crate::storage::{
+    first_order_float_methods, FloatStorage, MeasurementStorage, QuantityStorage, RealStorage,
+};
+
+/// A value together with its standard uncertainty.
+///
+/// Arithmetic propagates the uncertainty to first order, assuming
+/// that the uncertainties of the operands are uncorrelated. In
+/// particular, `x - x` has a nonzero uncertainty.
+///
+/// Measurements are ordered by their value first and by their
+/// uncertainty second.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Measurement<F> {
+    pub value: F,
+    pub uncertainty: F,
+}
+
+impl<F> Measurement<F> {
+    pub const fn new(value: F, uncertainty: F) -> Self {
+        Self { value, uncertainty }
+    }
+}
+
+/// Formats the measurement as `value ± uncertainty`. Formatting
+/// options such as the precision apply to both numbers.
+impl<F: fmt::Display> fmt::Display for Measurement<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)?;
+        f.write_str(" ± ")?;
+        self.uncertainty.fmt(f)
+    }
+}
+
+/// The error returned when a string is not a valid measurement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseMeasurementError;
+
+impl fmt::Display for ParseMeasurementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid measurement, expected a string such as `9.81(2)` or `9.81 ± 0.02`")
+    }
+}
+
+/// Parses measurements written as `9.81 ± 0.02` (or `9.81 +/- 0.02`)
+/// or in the concise notation `9.81(2)`, where the digits in
+/// parentheses are the uncertainty in the last digits of the value.
+/// A plain number is parsed as a measurement without uncertainty.
+impl<F: FromStr + PartialOrd + Default> FromStr for Measurement<F> {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| s.trim().parse::<F>().map_err(|_| ParseMeasurementError);
+        let s = s.trim();
+        let (value, uncertainty) =
+            if let Some((value, uncertainty)) = s.split_once('±').or_else(|| s.split_once("+/-")) {
+                (parse(value)?, parse(uncertainty)?)
+            } else if let Some((mantissa, rest)) = s.split_once('(') {
+                let (digits, exponent) = rest.split_once(')').ok_or(ParseMeasurementError)?;
+                let uncertainty = if digits.contains('.') {
+                    parse(&format!("{}{}", digits, exponent))?
+                } else {
+                    let exponent = match exponent.strip_prefix(['e', 'E']) {
+                        Some(exponent) => exponent.parse().map_err(|_| ParseMeasurementError)?,
+                        None if exponent.is_empty() => 0,
+                        None => return Err(ParseMeasurementError),
+                    };
+                    let decimals = mantissa.split_once('.').map(|(_, d)| d.len()).unwrap_or(0);
+                    parse(&format!("{}e{}", digits, exponent - decimals as i32))?
+                };
+                (parse(&format!("{}{}", mantissa, exponent))?, uncertainty)
+            } else {
+                (parse(s)?, F::default())
+            };
+        if uncertainty < F::default() {
+            return Err(ParseMeasurementError);
+        }
+        Ok(Self::new(value, uncertainty))
+    }
+}
+
+macro_rules! impl_measurement {
+    ($($real: ident: $conversion_method: ident),*) => {
+        $(
+            impl Measurement<$real> {
+                /// Propagates the uncertainty through a function with the
+                /// given value and derivative at `self.value`. Exact
+                /// values stay exact, even where the derivative is
+                /// infinite, as for the square root of zero.
+                fn propagate(self, value: $real, derivative: $real) -> Self {
+                    if self.uncertainty == 0.0 {
+                        return Self::new(value, 0.0);
+                    }
+                    Self::new(value, (derivative * self.uncertainty).abs())
+                }
+            }
+
+            impl QuantityStorage for Measurement<$real> {}
+
+            impl Add for Measurement<$real> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self::new(self.value + rhs.value, self.uncertainty.hypot(rhs.uncertainty))
+                }
+            }
+
+            impl Sub for Measurement<$real> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    Self::new(self.value - rhs.value, self.uncertainty.hypot(rhs.uncertainty))
+                }
+            }
+
+            impl Mul for Measurement<$real> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    Self::new(
+                        self.value * rhs.value,
+                        (rhs.value * self.uncertainty).hypot(self.value * rhs.uncertainty),
+                    )
+                }
+            }
+
+            impl Div for Measurement<$real> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self {
+                    Self::new(
+                        self.value / rhs.value,
+                        (self.uncertainty / rhs.value)
+                            .hypot(self.value * rhs.uncertainty / (rhs.value * rhs.value)),
+                    )
+                }
+            }
+
+            impl Neg for Measurement<$real> {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    Self::new(-self.value, self.uncertainty)
+                }
+            }
+
+            impl AddAssign for Measurement<$real> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl SubAssign for Measurement<$real> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl MulAssign for Measurement<$real> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl DivAssign for Measurement<$real> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+
+            impl Mul<$real> for Measurement<$real> {
+                type Output = Self;
+
+                fn mul(self, rhs: $real) -> Self {
+                    Self::new(self.value * rhs, self.uncertainty * rhs.abs())
+                }
+            }
+
+            impl Div<$real> for Measurement<$real> {
+                type Output = Self;
+
+                fn div(self, rhs: $real) -> Self {
+                    Self::new(self.value / rhs, self.uncertainty / rhs.abs())
+                }
+            }
+
+            impl Mul<Measurement<$real>> for $real {
+                type Output = Measurement<$real>;
+
+                fn mul(self, rhs: Measurement<$real>) -> Measurement<$real> {
+                    rhs * self
+                }
+            }
+
+            impl Div<Measurement<$real>> for $real {
+                type Output = Measurement<$real>;
+
+                fn div(self, rhs: Measurement<$real>) -> Measurement<$real> {
+                    rhs.propagate(self / rhs.value, self / (rhs.value * rhs.value))
+                }
+            }
+
+            impl Mul<Magnitude> for Measurement<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl Div<Magnitude> for Measurement<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / rhs.$conversion_method()
+                }
+            }
+
+            /// The minimum and maximum ignore the uncertainties.
+            impl RealStorage for Measurement<$real> {
+                fn zero() -> Self {
+                    Self::new(0.0, 0.0)
+                }
+
+                fn min(self, other: Self) -> Self {
+                    if other.value < self.value {
+                        other
+                    } else {
+                        self
+                    }
+                }
+
+                fn max(self, other: Self) -> Self {
+                    if other.value > self.value {
+                        other
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            impl FloatStorage for Measurement<$real> {
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    RealStorage::max(RealStorage::min(self, max), min)
+                }
+
+                fn is_nan(self) -> bool {
+                    self.value.is_nan() || self.uncertainty.is_nan()
+                }
+
+                first_order_float_methods!($real, propagate);
+            }
+
+            impl MeasurementStorage for Measurement<$real> {
+                type Value = $real;
+
+                fn relative_uncertainty(self) -> $real {
+                    self.uncertainty / self.value.abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_measurement!(f32: into_f32, f64: into_f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::measurement::{Measurement, ParseMeasurementError};
+    use crate::storage::FloatStorage;
+
+    #[test]
+    fn parse_measurement() {
+        let parse = |s: &str| s.parse::<Measurement<f64>>();
+        assert_eq!(parse("9.81(2)"), Ok(Measurement::new(9.81, 0.02)));
+        assert_eq!(parse("9.81 ± 0.02"), Ok(Measurement::new(9.81, 0.02)));
+        assert_eq!(parse("9.81 +/- 0.02"), Ok(Measurement::new(9.81, 0.02)));
+        assert_eq!(
+            parse("1.234(56)e-3"),
+            Ok(Measurement::new(1.234e-3, 5.6e-5))
+        );
+        assert_eq!(parse("12.3(1.2)"), Ok(Measurement::new(12.3, 1.2)));
+        assert_eq!(parse("5"), Ok(Measurement::new(5.0, 0.0)));
+        assert_eq!(parse("9.81(2"), Err(ParseMeasurementError));
+        assert_eq!(parse("1 ± -1"), Err(ParseMeasurementError));
+    }
+
+    #[test]
+    fn exact_values_stay_exact() {
+        assert_eq!(
+            Measurement::new(0.0, 0.0).sqrt(),
+            Measurement::new(0.0, 0.0)
+        );
+        assert_eq!(Measurement::new(0.0, 0.0).ln().uncertainty, 0.0);
+        assert_eq!(
+            Measurement::new(4.0, 0.4).sqrt(),
+            Measurement::new(2.0, 0.1)
+        );
+    }
+}
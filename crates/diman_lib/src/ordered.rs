@@ -0,0 +1,85 @@
+//! Total ordering and hashing of floating point quantities.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Types with a total order, such as the one of `f64::total_cmp`.
+/// Values which compare as equal must hash equally.
+pub trait TotalOrder {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    fn total_hash<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! impl_total_order {
+    ($($float: ty),*) => {
+        $(
+            impl TotalOrder for $float {
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$float>::total_cmp(self, other)
+                }
+
+                fn total_hash<H: Hasher>(&self, state: &mut H) {
+                    self.to_bits().hash(state);
+                }
+            }
+        )*
+    };
+}
+
+impl_total_order!(f32, f64);
+
+/// Wraps a value so that it is compared and hashed according to its
+/// total order. This makes it possible to sort quantities with float
+/// storage types or to use them as keys of a `BTreeMap` or a
+/// `HashSet`.
+///
+/// As for `f64::total_cmp`, `-0.0` is less than `0.0` and NaNs are
+/// ordered by their sign and payload, below negative infinity or
+/// above positive infinity.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ordered<T>(pub T);
+
+impl<T: TotalOrder> PartialEq for Ordered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrder> Eq for Ordered<T> {}
+
+impl<T: TotalOrder> PartialOrd for Ordered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder> Ord for Ordered<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T: TotalOrder> Hash for Ordered<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ordered::Ordered;
+
+    #[test]
+    fn ordered_floats() {
+        let mut values = [2.0, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN].map(Ordered);
+        values.sort();
+        assert!(values[0].0.is_nan());
+        assert_eq!(values[1], Ordered(f64::NEG_INFINITY));
+        assert_eq!(values[2].0.to_bits(), (-0.0f64).to_bits());
+        assert_eq!(values[3].0.to_bits(), 0.0f64.to_bits());
+        assert_eq!(values[4], Ordered(2.0));
+        assert!(values[5].0.is_nan());
+        assert_eq!(Ordered(f64::NAN), Ordered(f64::NAN));
+        assert_ne!(Ordered(0.0), Ordered(-0.0));
+    }
+}
@@ -2,14 +2,16 @@ pub struct RuntimeUnit<'a, D> {
     pub symbol: &'a str,
     pub dimension: D,
     pub magnitude: f64,
+    pub offset: f64,
 }
 
 impl<'a, D> RuntimeUnit<'a, D> {
-    pub fn new(symbol: &'a str, dimension: D, magnitude: f64) -> Self {
+    pub fn new(symbol: &'a str, dimension: D, magnitude: f64, offset: f64) -> Self {
         Self {
             symbol,
             dimension,
             magnitude,
+            offset,
         }
     }
 }
@@ -0,0 +1,530 @@
+//! Unit conversions for integer and fixed-point storage types.
+//!
+//! Scaling an integer by a magnitude is done exactly. Since
+//! quantities are stored in base units, a conversion such as
+//! 2500 millivolts into volts has no integer result. The checked
+//! variant of [`ScaleByMagnitude`] and the `Mul<Magnitude>` and
+//! `Div<Magnitude>` impls therefore treat such results like results
+//! which are out of range, returning `None` or panicking. Only the
+//! wrapping and saturating variants round, to the nearest integer
+//! (with ties rounded away from zero).
+//!
+//! Fixed-point numbers are approximations with a given resolution,
+//! like floats, so their conversions are always rounded to the
+//! nearest multiple of the resolution.
+//!
+//! Exact storage types, such as decimals and rationals, are never
+//! rounded. [`ScaleExactly`] returns an error if the result is not
+//! representable, in which case the `Mul<Magnitude>` and
+//! `Div<Magnitude>` impls panic.
+
+use core::fmt;
+use core::ops::{Div, Mul};
+
+use crate::magnitude::Magnitude;
+
+/// Scales a value by a magnitude, with explicit handling of results
+/// which are out of range.
+pub trait ScaleByMagnitude: Sized {
+    /// Returns `None` if the result is out of range or, for
+    /// integers, not an integer.
+    fn checked_scale(self, factor: Magnitude) -> Option<Self>;
+
+    /// Wraps around at the boundary of the type if the result is out
+    /// of range and rounds to the nearest representable value.
+    fn wrapping_scale(self, factor: Magnitude) -> Self;
+
+    /// Clamps the result to the range of the type and rounds to the
+    /// nearest representable value.
+    fn saturating_scale(self, factor: Magnitude) -> Self;
+}
+
+/// Scales a value by a magnitude without rounding.
+pub trait ScaleExactly: Sized {
+    /// Returns an error if the result is not exactly representable
+    /// by the type, for example because it is out of range or
+    /// because the type cannot represent the unit factor.
+    fn exact_scale(self, factor: Magnitude) -> Result<Self, InexactConversion>;
+}
+
+/// The error returned when a unit conversion cannot be done exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InexactConversion;
+
+impl fmt::Display for InexactConversion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the result of the unit conversion is not exactly representable")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InexactConversion {}
+
+/// The result of scaling an integer, before it is converted back
+/// into the storage type.
+struct Scaled {
+    negative: bool,
+    /// The absolute value of the result, modulo `2^128`.
+    abs: u128,
+    /// Whether the absolute value is at least `2^128`.
+    overflow: bool,
+    /// Whether `abs` is exact, as opposed to rounded.
+    exact: bool,
+}
+
+impl Scaled {
+    fn checked(&self) -> Option<i128> {
+        if self.overflow {
+            return None;
+        }
+        if self.negative {
+            0i128.checked_sub_unsigned(self.abs)
+        } else {
+            i128::try_from(self.abs).ok()
+        }
+    }
+
+    fn checked_into<T: TryFrom<i128>>(&self) -> Option<T> {
+        T::try_from(self.checked()?).ok()
+    }
+
+    fn wrapping(&self) -> i128 {
+        if self.negative {
+            (self.abs as i128).wrapping_neg()
+        } else {
+            self.abs as i128
+        }
+    }
+}
+
+/// Returns `factor` as a fraction `num / denom` of 128 bit integers,
+/// if both fit.
+pub(crate) fn as_fraction(factor: Magnitude) -> Option<(u128, u128)> {
+    let mut num = factor.num as u128;
+    let mut denom = factor.denom as u128;
+    let scale = |x: u128, base: u128, exponent: i16| {
+        x.checked_mul(base.checked_pow(exponent.unsigned_abs() as u32)?)
+    };
+    if factor.pow2 >= 0 {
+        num = scale(num, 2, factor.pow2)?;
+    } else {
+        denom = scale(denom, 2, factor.pow2)?;
+    }
+    if factor.pow10 >= 0 {
+        num = scale(num, 10, factor.pow10)?;
+    } else {
+        denom = scale(denom, 10, factor.pow10)?;
+    }
+    Some((num, denom))
+}
+
+/// Computes `a * num / denom`, rounded to the nearest integer, with
+/// ties rounded away from zero. Also returns whether the result
+/// overflows and whether it is exact.
+fn mul_div_rounded(a: u64, num: u128, denom: u128) -> (u128, bool, bool) {
+    // The 192 bit product, split into the upper and lower 128 bits.
+    let low = a as u128 * num as u64 as u128;
+    let mid = a as u128 * (num >> 64);
+    let (low, carry) = low.overflowing_add(mid << 64);
+    let high = (mid >> 64) + carry as u128;
+    let (mut quotient, remainder, mut overflow) = if high == 0 {
+        (low / denom, low % denom, false)
+    } else {
+        // Binary long division. Bits of the quotient beyond 128
+        // only mark the result as overflowing.
+        let mut quotient = 0u128;
+        let mut remainder = 0u128;
+        let mut overflow = false;
+        for i in (0..256).rev() {
+            let bit = if i >= 128 {
+                (high >> (i - 128)) & 1
+            } else {
+                (low >> i) & 1
+            };
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | bit;
+            if carry == 1 || remainder >= denom {
+                remainder = remainder.wrapping_sub(denom);
+                if i >= 128 {
+                    overflow = true;
+                } else {
+                    quotient |= 1 << i;
+                }
+            }
+        }
+        (quotient, remainder, overflow)
+    };
+    if remainder >= denom - remainder {
+        let (rounded, carry) = quotient.overflowing_add(1);
+        quotient = rounded;
+        overflow |= carry;
+    }
+    (quotient, overflow, remainder == 0)
+}
+
+/// Scales an integer which fits into 64 bits (signed or unsigned).
+fn scale_integer(value: i128, factor: Magnitude) -> Scaled {
+    let abs = value.unsigned_abs() as u64;
+    let negative = (value < 0) != (factor.sign < 0);
+    match as_fraction(factor) {
+        Some((num, denom)) => {
+            let (abs, overflow, exact) = mul_div_rounded(abs, num, denom);
+            Scaled {
+                negative,
+                abs,
+                overflow,
+                exact,
+            }
+        }
+        // Factors which do not fit into 128 bit fractions scale
+        // any nonzero value out of range (or to below the resolution)
+        // of all storage types, so they are treated as overflowing.
+        None => Scaled {
+            negative,
+            abs: 0,
+            overflow: abs != 0 && factor.num != 0,
+            exact: abs == 0 || factor.num == 0,
+        },
+    }
+}
+
+/// Scales an integer, panicking if the result is out of range or
+/// not an integer.
+fn scale_integer_or_panic<T: TryFrom<i128>>(value: i128, factor: Magnitude) -> T {
+    let scaled = scale_integer(value, factor);
+    let value = scaled
+        .checked_into()
+        .expect("attempt to convert to a unit with overflow");
+    assert!(scaled.exact, "attempt to convert to a unit inexactly");
+    value
+}
+
+macro_rules! impl_integer {
+    ($($ty: ty),*) => {
+        $(
+            impl ScaleByMagnitude for $ty {
+                fn checked_scale(self, factor: Magnitude) -> Option<Self> {
+                    let scaled = scale_integer(self as i128, factor);
+                    scaled.exact.then(|| scaled.checked_into()).flatten()
+                }
+
+                fn wrapping_scale(self, factor: Magnitude) -> Self {
+                    scale_integer(self as i128, factor).wrapping() as $ty
+                }
+
+                fn saturating_scale(self, factor: Magnitude) -> Self {
+                    let scaled = scale_integer(self as i128, factor);
+                    match scaled.checked_into() {
+                        Some(value) => value,
+                        None if scaled.negative => <$ty>::MIN,
+                        None => <$ty>::MAX,
+                    }
+                }
+            }
+
+            impl ScaleExactly for $ty {
+                fn exact_scale(self, factor: Magnitude) -> Result<Self, InexactConversion> {
+                    self.checked_scale(factor).ok_or(InexactConversion)
+                }
+            }
+
+            impl Mul<Magnitude> for $ty {
+                type Output = Self;
+
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    scale_integer_or_panic(self as i128, rhs)
+                }
+            }
+
+            impl Div<Magnitude> for $ty {
+                type Output = Self;
+
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    scale_integer_or_panic(self as i128, rhs.inv())
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i32, i64, u32, u64);
+
+/// Fixed-point numbers are scaled via their underlying integer
+/// representation, which is rounded to the nearest multiple of the
+/// resolution.
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed {
+    ($($ty: ident: $frac_bound: ident),*) => {
+        $(
+            impl<Frac: ::fixed::types::extra::$frac_bound> ScaleByMagnitude for ::fixed::$ty<Frac> {
+                fn checked_scale(self, factor: Magnitude) -> Option<Self> {
+                    scale_integer(self.to_bits() as i128, factor)
+                        .checked_into()
+                        .map(Self::from_bits)
+                }
+
+                fn wrapping_scale(self, factor: Magnitude) -> Self {
+                    Self::from_bits(self.to_bits().wrapping_scale(factor))
+                }
+
+                fn saturating_scale(self, factor: Magnitude) -> Self {
+                    Self::from_bits(self.to_bits().saturating_scale(factor))
+                }
+            }
+
+            impl<Frac: ::fixed::types::extra::$frac_bound> Mul<Magnitude> for ::fixed::$ty<Frac> {
+                type Output = Self;
+
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self.checked_scale(rhs)
+                        .expect("attempt to convert to a unit with overflow")
+                }
+            }
+
+            impl<Frac: ::fixed::types::extra::$frac_bound> Div<Magnitude> for ::fixed::$ty<Frac> {
+                type Output = Self;
+
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self.checked_scale(rhs.inv())
+                        .expect("attempt to convert to a unit with overflow")
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed!(FixedI32: LeEqU32, FixedI64: LeEqU64, FixedU32: LeEqU32, FixedU64: LeEqU64);
+
+/// Decimals are scaled via their mantissa. A factor `2^-k` is
+/// written as `5^k * 10^-k`, so that only factors whose denominator
+/// is not a product of twos and fives are inexact, apart from
+/// results which are out of range.
+#[cfg(feature = "rust_decimal")]
+impl ScaleExactly for ::rust_decimal::Decimal {
+    fn exact_scale(self, factor: Magnitude) -> Result<Self, InexactConversion> {
+        if self.is_zero() || factor.num == 0 {
+            return Ok(Self::ZERO);
+        }
+        let mantissa = self.mantissa().unsigned_abs();
+        if !mantissa.is_multiple_of(factor.denom as u128) {
+            return Err(InexactConversion);
+        }
+        let mut exponent = factor.pow10 as i32 - self.scale() as i32;
+        let (base, power) = if factor.pow2 >= 0 {
+            (2u128, factor.pow2 as u32)
+        } else {
+            exponent += factor.pow2 as i32;
+            (5u128, factor.pow2.unsigned_abs() as u32)
+        };
+        let mut mantissa = (mantissa / factor.denom as u128)
+            .checked_mul(factor.num as u128)
+            .and_then(|mantissa| mantissa.checked_mul(base.checked_pow(power)?))
+            .ok_or(InexactConversion)?;
+        // Trailing zeros are only removed where necessary, in order to
+        // keep the scale of the value otherwise.
+        while exponent < 0 && mantissa.is_multiple_of(10) && (exponent < -28 || mantissa >> 96 != 0)
+        {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            mantissa = 10u128
+                .checked_pow(exponent as u32)
+                .and_then(|power| mantissa.checked_mul(power))
+                .ok_or(InexactConversion)?;
+            exponent = 0;
+        }
+        let mantissa = i128::try_from(mantissa).map_err(|_| InexactConversion)?;
+        let negative = (self.mantissa() < 0) != (factor.sign < 0);
+        let mantissa = if negative { -mantissa } else { mantissa };
+        Self::try_from_i128_with_scale(mantissa, exponent.unsigned_abs())
+            .map_err(|_| InexactConversion)
+    }
+}
+
+/// Rationals are scaled by the factor written as a fraction, which
+/// is only inexact if the result does not fit.
+#[cfg(feature = "num-rational")]
+impl ScaleExactly for ::num_rational::Ratio<i64> {
+    fn exact_scale(self, factor: Magnitude) -> Result<Self, InexactConversion> {
+        let (num, denom) = as_fraction(factor).ok_or(InexactConversion)?;
+        let num = i128::try_from(num).map_err(|_| InexactConversion)? * factor.sign as i128;
+        let denom = i128::try_from(denom).map_err(|_| InexactConversion)?;
+        let scaled = (*self.numer() as i128)
+            .checked_mul(num)
+            .zip((*self.denom() as i128).checked_mul(denom))
+            .map(|(numer, denom)| ::num_rational::Ratio::new(numer, denom))
+            .ok_or(InexactConversion)?;
+        let numer = i64::try_from(*scaled.numer()).map_err(|_| InexactConversion)?;
+        let denom = i64::try_from(*scaled.denom()).map_err(|_| InexactConversion)?;
+        Ok(Self::new_raw(numer, denom))
+    }
+}
+
+#[cfg(any(feature = "rust_decimal", feature = "num-rational"))]
+macro_rules! impl_exact {
+    ($($ty: ty),*) => {
+        $(
+            impl Mul<Magnitude> for $ty {
+                type Output = Self;
+
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self.exact_scale(rhs)
+                        .expect("attempt to convert to a unit inexactly")
+                }
+            }
+
+            impl Div<Magnitude> for $ty {
+                type Output = Self;
+
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self.exact_scale(rhs.inv())
+                        .expect("attempt to convert to a unit inexactly")
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_exact!(::rust_decimal::Decimal);
+
+#[cfg(feature = "num-rational")]
+impl_exact!(::num_rational::Ratio<i64>);
+
+#[cfg(test)]
+mod tests {
+    use super::ScaleByMagnitude;
+    use crate::magnitude::Magnitude;
+
+    #[test]
+    fn integer_scaling_is_exact() {
+        let milli = Magnitude::from_decimal(1, -3);
+        assert_eq!(2000i32 * milli, 2);
+        assert_eq!(-1000i32 * milli, -1);
+        assert_eq!(6u64 / Magnitude::from_decimal(3, 0), 2);
+        assert_eq!(5u32 * Magnitude::from_decimal(1, 3), 5000);
+        assert_eq!(7i64 * Magnitude::from_decimal(1, 0).neg(), -7);
+        assert_eq!(
+            (i64::MAX / 7 * 7)
+                .checked_scale(Magnitude::from_decimal(3, 0) / Magnitude::from_decimal(7, 0)),
+            Some(i64::MAX / 7 * 3),
+            "large intermediate products are exact"
+        );
+        assert_eq!(1499i32.checked_scale(milli), None);
+        assert_eq!(
+            2u64.checked_scale(Magnitude::from_decimal(3, 0).inv()),
+            None
+        );
+    }
+
+    #[test]
+    fn integer_scaling_rounds_when_wrapping_or_saturating() {
+        let milli = Magnitude::from_decimal(1, -3);
+        assert_eq!(1499i32.saturating_scale(milli), 1);
+        assert_eq!(1500i32.saturating_scale(milli), 2);
+        assert_eq!((-1500i32).wrapping_scale(milli), -2);
+        assert_eq!(
+            i64::MAX.saturating_scale(Magnitude::from_decimal(1, -18)),
+            9
+        );
+    }
+
+    #[test]
+    fn integer_scaling_overflow() {
+        let kilo = Magnitude::from_decimal(1, 3);
+        assert_eq!(3_000_000i32.checked_scale(kilo), None);
+        assert_eq!(3_000_000i32.saturating_scale(kilo), i32::MAX);
+        assert_eq!((-3_000_000i32).saturating_scale(kilo), i32::MIN);
+        assert_eq!(3_000_000i32.wrapping_scale(kilo), 3_000_000_000u32 as i32);
+        assert_eq!(1u32.checked_scale(kilo.neg()), None);
+        assert_eq!(1u32.saturating_scale(kilo.neg()), 0);
+        assert_eq!(1u64.checked_scale(Magnitude::from_decimal(1, 30)), None);
+        assert_eq!(0u64.checked_scale(Magnitude::from_decimal(1, 30)), Some(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn integer_scaling_panics_on_overflow() {
+        let _ = u32::MAX * Magnitude::from_decimal(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "inexactly")]
+    fn integer_scaling_panics_if_inexact() {
+        let _ = 2500i32 * Magnitude::from_decimal(1, -3);
+    }
+
+    #[test]
+    #[cfg(feature = "rust_decimal")]
+    fn decimal_scaling_is_exact() {
+        use super::{InexactConversion, ScaleExactly};
+        use rust_decimal::Decimal;
+        let kilowatt_hour = Magnitude::from_decimal(36, 5);
+        assert_eq!(
+            Decimal::new(15, 1) * kilowatt_hour,
+            Decimal::new(5_400_000, 0)
+        );
+        assert_eq!(
+            Decimal::new(5_400_000, 0) / kilowatt_hour,
+            Decimal::new(15, 1)
+        );
+        assert_eq!(
+            Decimal::new(-1, 0) * Magnitude::from_decimal(254, -4),
+            Decimal::new(-254, 4)
+        );
+        assert_eq!(
+            Decimal::new(1, 0) * Magnitude::from_power_of_two(-3),
+            Decimal::new(125, 3)
+        );
+        assert_eq!(
+            Decimal::new(3, 0) * Magnitude::from_power_of_two(10),
+            Decimal::new(3072, 0)
+        );
+        let third = Magnitude::one() / Magnitude::from_decimal(3, 0);
+        assert_eq!(
+            Decimal::new(3, 0).exact_scale(third),
+            Ok(Decimal::new(1, 0))
+        );
+        assert_eq!(
+            Decimal::new(1, 0).exact_scale(third),
+            Err(InexactConversion)
+        );
+        assert_eq!(
+            Decimal::new(1, 0).exact_scale(Magnitude::from_decimal(1, -29)),
+            Err(InexactConversion)
+        );
+        assert_eq!(
+            Decimal::MAX.exact_scale(Magnitude::from_decimal(1, 3)),
+            Err(InexactConversion)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-rational")]
+    fn rational_scaling_is_exact() {
+        use super::{InexactConversion, ScaleExactly};
+        use num_rational::Ratio;
+        let hour = Magnitude::from_decimal(3600, 0);
+        assert_eq!(Ratio::new(1, 3) * hour, Ratio::from_integer(1200));
+        assert_eq!(Ratio::from_integer(1000) / hour, Ratio::new(5, 18));
+        let third = Magnitude::one() / Magnitude::from_decimal(3, 0);
+        assert_eq!(Ratio::from_integer(-1) * third.neg(), Ratio::new(1, 3));
+        assert_eq!(
+            Ratio::from_integer(i64::MAX).exact_scale(Magnitude::from_decimal(1, 3)),
+            Err(InexactConversion)
+        );
+        assert_eq!(
+            Ratio::from_integer(1).exact_scale(Magnitude::from_decimal(1, -30)),
+            Err(InexactConversion)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rust_decimal")]
+    #[should_panic(expected = "inexactly")]
+    fn decimal_scaling_panics_if_inexact() {
+        let _ = rust_decimal::Decimal::new(1, 0) / Magnitude::from_decimal(3, 0);
+    }
+}
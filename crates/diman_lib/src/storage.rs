@@ -0,0 +1,908 @@
+//! Traits for the storage types of quantities.
+//!
+//! Most operators and methods on quantities are implemented once,
+//! generically over these traits, instead of once per storage type.
+//! Storage types declared in a unit system via `storage_type` need to
+//! implement them in order to get the corresponding methods.
+
+#[cfg(any(
+    feature = "glam",
+    feature = "num-complex",
+    feature = "nalgebra",
+    feature = "ndarray",
+    feature = "half"
+))]
+use crate::magnitude::Magnitude;
+
+use core::ops::{Rem, RemAssign};
+
+#[allow(unused)]
+#[cfg(feature = "num-traits-libm")]
+use num_traits::float::Float;
+
+#[allow(unused)]
+#[cfg(not(any(feature = "std", feature = "num-traits-libm")))]
+use num_traits::float::FloatCore;
+
+use crate::ordered::TotalOrder;
+
+/// A type which can be used as the storage type of a quantity.
+/// Multiplying a quantity by its storage type (as in `length * 2.0`)
+/// is implemented for all types implementing this trait.
+pub trait QuantityStorage {}
+
+/// Storage types with a zero and a (componentwise) minimum and maximum.
+pub trait RealStorage: QuantityStorage + Copy {
+    fn zero() -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+/// Floating point storage types.
+pub trait FloatStorage: RealStorage + PartialOrd {
+    fn powi(self, n: i32) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn is_nan(self) -> bool;
+
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn sqrt(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn cbrt(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn log2(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn ln(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn log10(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn exp(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn exp2(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn ceil(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn floor(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn sin(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn cos(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn tan(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn asin(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn acos(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn atan(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn sinh(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn cosh(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn tanh(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn asinh(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn acosh(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn atanh(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn exp_m1(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn ln_1p(self) -> Self;
+}
+
+/// The primitive float types `f32` and `f64`, with the constants and
+/// methods which have no counterpart in `FloatStorage`.
+pub trait PrimitiveFloatStorage:
+    FloatStorage + TotalOrder + Rem<Output = Self> + RemAssign
+{
+    const NAN: Self;
+    const INFINITY: Self;
+    const EPSILON: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn recip(self) -> Self;
+    fn signum(self) -> Self;
+    /// The least nonnegative remainder of `self` divided by `rhs`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn to_degrees(self) -> Self;
+
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn hypot(self, other: Self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn atan2(self, x: Self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn copysign(self, sign: Self) -> Self;
+}
+
+/// Vectors of floating point numbers. The methods take their
+/// arguments by reference, since vectors of dynamic size are not
+/// `Copy`.
+pub trait VectorStorage: QuantityStorage {
+    /// The type of the components of the vector.
+    type Scalar: FloatStorage;
+
+    fn length(&self) -> Self::Scalar;
+    fn distance(&self, other: &Self) -> Self::Scalar;
+    fn distance_squared(&self, other: &Self) -> Self::Scalar;
+    fn normalize(&self) -> Self;
+    fn dot(&self, other: &Self) -> Self::Scalar;
+}
+
+/// Complex numbers.
+pub trait ComplexStorage: NormedStorage + Copy {
+    fn conj(self) -> Self;
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn arg(self) -> Self::Real;
+}
+
+/// Storage types with a norm, such as complex numbers or `nalgebra`
+/// vectors.
+pub trait NormedStorage: QuantityStorage {
+    /// The type of the norm, which is also the type of the real and
+    /// imaginary parts of complex numbers.
+    type Real: FloatStorage;
+
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn norm(&self) -> Self::Real;
+}
+
+/// Measured values with a standard uncertainty.
+pub trait MeasurementStorage: FloatStorage {
+    /// The type of the value and of the uncertainty.
+    type Value: FloatStorage;
+
+    /// The uncertainty divided by the absolute value.
+    fn relative_uncertainty(self) -> Self::Value;
+}
+
+/// Intervals with outward-rounded arithmetic.
+pub trait IntervalStorage: FloatStorage {
+    /// The type of the bounds.
+    type Bound: FloatStorage;
+
+    fn midpoint(self) -> Self::Bound;
+    /// The width of the interval, rounded up.
+    fn width(self) -> Self::Bound;
+}
+
+/// Dual numbers, which carry their derivative with respect to a
+/// variable next to their value.
+pub trait DualStorage: FloatStorage {
+    /// The type of the value and of the derivative.
+    type Value: FloatStorage;
+
+    /// A value which does not depend on the variable.
+    fn constant(value: Self::Value) -> Self;
+    /// The variable itself, whose derivative is one.
+    fn variable(value: Self::Value) -> Self;
+}
+
+macro_rules! impl_quantity_storage {
+    ($($ty: ty),*) => {
+        $(
+            impl QuantityStorage for $ty {}
+        )*
+    };
+}
+
+impl_quantity_storage!(f32, f64, i32, i64, u32, u64);
+
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed {
+    ($($ty: ident),*) => {
+        $(
+            impl<Frac> QuantityStorage for ::fixed::$ty<Frac> {}
+        )*
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed!(FixedI32, FixedI64, FixedU32, FixedU64);
+
+/// Exact storage types, which are compared via `Ord`.
+#[cfg(any(feature = "rust_decimal", feature = "num-rational"))]
+macro_rules! impl_exact {
+    ($($ty: ty: $zero: expr),*) => {
+        $(
+            impl_quantity_storage!($ty);
+
+            impl RealStorage for $ty {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn min(self, other: Self) -> Self {
+                    Ord::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    Ord::max(self, other)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_exact!(::rust_decimal::Decimal: ::rust_decimal::Decimal::ZERO);
+
+#[cfg(feature = "num-rational")]
+impl_exact!(::num_rational::Ratio<i64>: ::num_rational::Ratio::from_integer(0));
+
+/// Forwards the given methods of `FloatStorage` to the methods of
+/// the same name on the float type. These are inherent methods with
+/// `std` and provided by `num_traits` otherwise. Further arguments
+/// are of the float type as well.
+macro_rules! forward_float_methods {
+    ($ty: ty: $($name: ident $(($($arg: ident),*))?),*) => {
+        $(
+            #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+            fn $name(self $($(, $arg: Self)*)?) -> Self {
+                #[cfg(feature = "std")]
+                {
+                    <$ty>::$name(self $($(, $arg)*)?)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    Float::$name(self $($(, $arg)*)?)
+                }
+            }
+        )*
+    };
+}
+
+/// Forwards the given methods to the methods of the same name on the
+/// float type, which are available without `std` via `FloatCore`.
+macro_rules! forward_float_core_methods {
+    ($ty: ty: $($name: ident -> $output: ty),*) => {
+        $(
+            fn $name(self) -> $output {
+                #[cfg(feature = "std")]
+                {
+                    <$ty>::$name(self)
+                }
+                #[cfg(all(not(feature = "std"), feature = "num-traits-libm"))]
+                {
+                    Float::$name(self)
+                }
+                #[cfg(not(any(feature = "std", feature = "num-traits-libm")))]
+                {
+                    FloatCore::$name(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($($ty: ty),*) => {
+        $(
+            impl RealStorage for $ty {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn min(self, other: Self) -> Self {
+                    <$ty>::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$ty>::max(self, other)
+                }
+            }
+
+            impl FloatStorage for $ty {
+                fn powi(self, n: i32) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        <$ty>::powi(self, n)
+                    }
+                    #[cfg(all(not(feature = "std"), feature = "num-traits-libm"))]
+                    {
+                        Float::powi(self, n)
+                    }
+                    #[cfg(not(any(feature = "std", feature = "num-traits-libm")))]
+                    {
+                        FloatCore::powi(self, n)
+                    }
+                }
+
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    <$ty>::clamp(self, min, max)
+                }
+
+                fn is_nan(self) -> bool {
+                    <$ty>::is_nan(self)
+                }
+
+                forward_float_methods!(
+                    $ty: sqrt, cbrt, log2, ln, log10, exp, exp2, ceil, floor, sin, cos, tan, asin,
+                    acos, atan, sinh, cosh, tanh, asinh, acosh, atanh, exp_m1, ln_1p
+                );
+            }
+
+            impl PrimitiveFloatStorage for $ty {
+                const NAN: Self = <$ty>::NAN;
+                const INFINITY: Self = <$ty>::INFINITY;
+                const EPSILON: Self = <$ty>::EPSILON;
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    let remainder = self % rhs;
+                    if remainder >= 0.0 {
+                        remainder
+                    } else if rhs < 0.0 {
+                        remainder - rhs
+                    } else {
+                        remainder + rhs
+                    }
+                }
+
+                forward_float_core_methods!(
+                    $ty: recip -> Self, signum -> Self, is_finite -> bool, is_infinite -> bool,
+                    to_degrees -> Self
+                );
+
+                forward_float_methods!($ty: hypot(other), mul_add(a, b), atan2(x), copysign(sign));
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Implements the math functions of `FloatStorage` for types which
+/// carry a first-order term next to their value, such as the
+/// uncertainty of a measurement or the derivative of a dual number.
+/// The method `$step` receives the value of the function and its
+/// derivative at `self.value` and computes the new first-order term.
+#[cfg(any(feature = "measurement", feature = "dual"))]
+macro_rules! first_order_float_methods {
+    ($real: ident, $step: ident) => {
+        fn powi(self, n: i32) -> Self {
+            if n == 0 {
+                return Self::new(1.0, 0.0);
+            }
+            self.$step(self.value.powi(n), n as $real * self.value.powi(n - 1))
+        }
+
+        fn sqrt(self) -> Self {
+            let sqrt = self.value.sqrt();
+            self.$step(sqrt, 0.5 / sqrt)
+        }
+
+        fn cbrt(self) -> Self {
+            let cbrt = self.value.cbrt();
+            self.$step(cbrt, 1.0 / (3.0 * cbrt * cbrt))
+        }
+
+        fn log2(self) -> Self {
+            self.$step(
+                self.value.log2(),
+                1.0 / (self.value * core::$real::consts::LN_2),
+            )
+        }
+
+        fn ln(self) -> Self {
+            self.$step(self.value.ln(), 1.0 / self.value)
+        }
+
+        fn log10(self) -> Self {
+            self.$step(
+                self.value.log10(),
+                1.0 / (self.value * core::$real::consts::LN_10),
+            )
+        }
+
+        fn exp(self) -> Self {
+            let exp = self.value.exp();
+            self.$step(exp, exp)
+        }
+
+        fn exp2(self) -> Self {
+            let exp2 = self.value.exp2();
+            self.$step(exp2, exp2 * core::$real::consts::LN_2)
+        }
+
+        fn ceil(self) -> Self {
+            self.$step(self.value.ceil(), 0.0)
+        }
+
+        fn floor(self) -> Self {
+            self.$step(self.value.floor(), 0.0)
+        }
+
+        fn sin(self) -> Self {
+            self.$step(self.value.sin(), self.value.cos())
+        }
+
+        fn cos(self) -> Self {
+            self.$step(self.value.cos(), -self.value.sin())
+        }
+
+        fn tan(self) -> Self {
+            let tan = self.value.tan();
+            self.$step(tan, 1.0 + tan * tan)
+        }
+
+        fn asin(self) -> Self {
+            self.$step(
+                self.value.asin(),
+                1.0 / (1.0 - self.value * self.value).sqrt(),
+            )
+        }
+
+        fn acos(self) -> Self {
+            self.$step(
+                self.value.acos(),
+                -1.0 / (1.0 - self.value * self.value).sqrt(),
+            )
+        }
+
+        fn atan(self) -> Self {
+            self.$step(self.value.atan(), 1.0 / (1.0 + self.value * self.value))
+        }
+
+        fn sinh(self) -> Self {
+            self.$step(self.value.sinh(), self.value.cosh())
+        }
+
+        fn cosh(self) -> Self {
+            self.$step(self.value.cosh(), self.value.sinh())
+        }
+
+        fn tanh(self) -> Self {
+            let tanh = self.value.tanh();
+            self.$step(tanh, 1.0 - tanh * tanh)
+        }
+
+        fn asinh(self) -> Self {
+            self.$step(
+                self.value.asinh(),
+                1.0 / (self.value * self.value + 1.0).sqrt(),
+            )
+        }
+
+        fn acosh(self) -> Self {
+            self.$step(
+                self.value.acosh(),
+                1.0 / (self.value * self.value - 1.0).sqrt(),
+            )
+        }
+
+        fn atanh(self) -> Self {
+            self.$step(self.value.atanh(), 1.0 / (1.0 - self.value * self.value))
+        }
+
+        fn exp_m1(self) -> Self {
+            self.$step(self.value.exp_m1(), self.value.exp())
+        }
+
+        fn ln_1p(self) -> Self {
+            self.$step(self.value.ln_1p(), 1.0 / (1.0 + self.value))
+        }
+    };
+}
+
+#[cfg(any(feature = "measurement", feature = "dual"))]
+pub(crate) use first_order_float_methods;
+
+/// Forwards the given methods of `FloatStorage` to the
+/// implementation for `f32`.
+#[cfg(feature = "half")]
+macro_rules! forward_half_methods {
+    ($ty: ty: $($name: ident),*) => {
+        $(
+            #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+            fn $name(self) -> Self {
+                <$ty>::from_f32(<f32 as FloatStorage>::$name(self.to_f32()))
+            }
+        )*
+    };
+}
+
+/// Half-precision floats of the `half` crate. Their math functions
+/// and unit conversions are computed in `f32` and rounded back, so
+/// that the factors of units such as nanometers, which are not
+/// representable as an `f16`, are not rounded to zero.
+#[cfg(feature = "half")]
+macro_rules! impl_half {
+    ($($ty: ty),*) => {
+        $(
+            impl_quantity_storage!($ty);
+
+            impl RealStorage for $ty {
+                fn zero() -> Self {
+                    <$ty>::ZERO
+                }
+
+                fn min(self, other: Self) -> Self {
+                    <$ty>::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$ty>::max(self, other)
+                }
+            }
+
+            impl FloatStorage for $ty {
+                fn powi(self, n: i32) -> Self {
+                    <$ty>::from_f32(<f32 as FloatStorage>::powi(self.to_f32(), n))
+                }
+
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    <$ty>::clamp(self, min, max)
+                }
+
+                fn is_nan(self) -> bool {
+                    <$ty>::is_nan(self)
+                }
+
+                forward_half_methods!(
+                    $ty: sqrt, cbrt, log2, ln, log10, exp, exp2, ceil, floor, sin, cos, tan, asin,
+                    acos, atan, sinh, cosh, tanh, asinh, acosh, atanh, exp_m1, ln_1p
+                );
+            }
+
+            impl core::ops::Mul<Magnitude> for $ty {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    <$ty>::from_f32(self.to_f32() * rhs.into_f32())
+                }
+            }
+
+            impl core::ops::Div<Magnitude> for $ty {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    <$ty>::from_f32(self.to_f32() / rhs.into_f32())
+                }
+            }
+
+            impl core::ops::Add<Magnitude> for $ty {
+                type Output = Self;
+
+                #[inline(always)]
+                fn add(self, rhs: Magnitude) -> Self::Output {
+                    <$ty>::from_f32(self.to_f32() + rhs.into_f32())
+                }
+            }
+
+            impl core::ops::Sub<Magnitude> for $ty {
+                type Output = Self;
+
+                #[inline(always)]
+                fn sub(self, rhs: Magnitude) -> Self::Output {
+                    <$ty>::from_f32(self.to_f32() - rhs.into_f32())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half!(::half::f16, ::half::bf16);
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_vector {
+    ($($ty: ty: $scalar: ty),*) => {
+        $(
+            impl_quantity_storage!($ty);
+
+            impl RealStorage for $ty {
+                fn zero() -> Self {
+                    <$ty>::ZERO
+                }
+
+                fn min(self, other: Self) -> Self {
+                    <$ty>::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$ty>::max(self, other)
+                }
+            }
+
+            impl VectorStorage for $ty {
+                type Scalar = $scalar;
+
+                fn length(&self) -> Self::Scalar {
+                    <$ty>::length(*self)
+                }
+
+                fn distance(&self, other: &Self) -> Self::Scalar {
+                    <$ty>::distance(*self, *other)
+                }
+
+                fn distance_squared(&self, other: &Self) -> Self::Scalar {
+                    <$ty>::distance_squared(*self, *other)
+                }
+
+                fn normalize(&self) -> Self {
+                    <$ty>::normalize(*self)
+                }
+
+                fn dot(&self, other: &Self) -> Self::Scalar {
+                    <$ty>::dot(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_vector!(
+    ::glam::Vec2: f32,
+    ::glam::DVec2: f64,
+    ::glam::Vec3: f32,
+    ::glam::DVec3: f64,
+    ::glam::Vec4: f32,
+    ::glam::DVec4: f64
+);
+
+/// Unit conversions of `glam` matrices scale every entry by the
+/// magnitude, converted into the type of the entries. `glam` does
+/// not implement dividing a matrix by a scalar, so this is done
+/// entrywise.
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_matrix {
+    ($($ty: ty: $conversion_method: ident),*) => {
+        $(
+            impl_quantity_storage!($ty);
+
+            impl core::ops::Mul<Magnitude> for $ty {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl core::ops::Div<Magnitude> for $ty {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    let rhs = rhs.$conversion_method();
+                    Self::from_cols_array(&self.to_cols_array().map(|x| x / rhs))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_matrix!(
+    ::glam::Mat2: into_f32,
+    ::glam::Mat3: into_f32,
+    ::glam::DMat3: into_f64
+);
+
+/// Unit conversions of complex numbers scale the real and imaginary
+/// parts by the magnitude, converted into the type of the parts.
+#[cfg(feature = "num-complex")]
+macro_rules! impl_complex {
+    ($($real: ty: $conversion_method: ident),*) => {
+        $(
+            impl_quantity_storage!(::num_complex::Complex<$real>);
+
+            impl NormedStorage for ::num_complex::Complex<$real> {
+                type Real = $real;
+
+                #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+                fn norm(&self) -> Self::Real {
+                    ::num_complex::Complex::norm(*self)
+                }
+            }
+
+            impl ComplexStorage for ::num_complex::Complex<$real> {
+                fn conj(self) -> Self {
+                    ::num_complex::Complex::conj(&self)
+                }
+
+                #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+                fn arg(self) -> Self::Real {
+                    ::num_complex::Complex::arg(self)
+                }
+            }
+
+            impl core::ops::Mul<Magnitude> for ::num_complex::Complex<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl core::ops::Div<Magnitude> for ::num_complex::Complex<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / rhs.$conversion_method()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "num-complex")]
+impl_complex!(f32: into_f32, f64: into_f64);
+
+/// Unit conversions of `nalgebra` vectors scale every component by
+/// the magnitude, converted into the type of the components.
+#[cfg(feature = "nalgebra")]
+macro_rules! impl_nalgebra_vector {
+    ($($real: ty: $conversion_method: ident),*) => {
+        $(
+            impl<const N: usize> QuantityStorage for ::nalgebra::SVector<$real, N> {}
+
+            impl<const N: usize> RealStorage for ::nalgebra::SVector<$real, N> {
+                fn zero() -> Self {
+                    Self::zeros()
+                }
+
+                fn min(self, other: Self) -> Self {
+                    self.inf(&other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    self.sup(&other)
+                }
+            }
+
+            impl<const N: usize> NormedStorage for ::nalgebra::SVector<$real, N> {
+                type Real = $real;
+
+                #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+                fn norm(&self) -> Self::Real {
+                    ::nalgebra::Matrix::norm(self)
+                }
+            }
+
+            impl<const N: usize> VectorStorage for ::nalgebra::SVector<$real, N> {
+                type Scalar = $real;
+
+                fn length(&self) -> Self::Scalar {
+                    self.norm()
+                }
+
+                fn distance(&self, other: &Self) -> Self::Scalar {
+                    (self - other).norm()
+                }
+
+                fn distance_squared(&self, other: &Self) -> Self::Scalar {
+                    (self - other).norm_squared()
+                }
+
+                fn normalize(&self) -> Self {
+                    ::nalgebra::Matrix::normalize(self)
+                }
+
+                fn dot(&self, other: &Self) -> Self::Scalar {
+                    ::nalgebra::Matrix::dot(self, other)
+                }
+            }
+
+            impl<const N: usize> core::ops::Mul<Magnitude> for ::nalgebra::SVector<$real, N> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl<const N: usize> core::ops::Div<Magnitude> for ::nalgebra::SVector<$real, N> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / rhs.$conversion_method()
+                }
+            }
+
+            impl QuantityStorage for ::nalgebra::DVector<$real> {}
+
+            impl NormedStorage for ::nalgebra::DVector<$real> {
+                type Real = $real;
+
+                #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+                fn norm(&self) -> Self::Real {
+                    ::nalgebra::Matrix::norm(self)
+                }
+            }
+
+            impl VectorStorage for ::nalgebra::DVector<$real> {
+                type Scalar = $real;
+
+                fn length(&self) -> Self::Scalar {
+                    self.norm()
+                }
+
+                fn distance(&self, other: &Self) -> Self::Scalar {
+                    (self - other).norm()
+                }
+
+                fn distance_squared(&self, other: &Self) -> Self::Scalar {
+                    (self - other).norm_squared()
+                }
+
+                fn normalize(&self) -> Self {
+                    ::nalgebra::Matrix::normalize(self)
+                }
+
+                fn dot(&self, other: &Self) -> Self::Scalar {
+                    ::nalgebra::Matrix::dot(self, other)
+                }
+            }
+
+            impl core::ops::Mul<Magnitude> for ::nalgebra::DVector<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl core::ops::Div<Magnitude> for ::nalgebra::DVector<$real> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / rhs.$conversion_method()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_vector!(f32: into_f32, f64: into_f64);
+
+/// Unit conversions of `ndarray` arrays scale every element by the
+/// magnitude, converted into the type of the elements, in a single
+/// pass over the array.
+#[cfg(feature = "ndarray")]
+macro_rules! impl_ndarray {
+    ($($real: ty: $conversion_method: ident),*) => {
+        $(
+            impl<Ix: ::ndarray::Dimension> QuantityStorage for ::ndarray::Array<$real, Ix> {}
+
+            impl<Ix: ::ndarray::Dimension> core::ops::Mul<Magnitude> for ::ndarray::Array<$real, Ix> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: Magnitude) -> Self::Output {
+                    self * rhs.$conversion_method()
+                }
+            }
+
+            impl<Ix: ::ndarray::Dimension> core::ops::Div<Magnitude> for ::ndarray::Array<$real, Ix> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: Magnitude) -> Self::Output {
+                    self / rhs.$conversion_method()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "ndarray")]
+impl_ndarray!(f32: into_f32, f64: into_f64);
@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{storage_types::StorageKind, Codegen};
+
+impl Codegen {
+    pub fn gen_complex_methods(&self) -> TokenStream {
+        let is_complex = |kind: &StorageKind| matches!(kind, StorageKind::Complex(_));
+        let generic_methods = if self.needs_storage_trait_impls(is_complex) {
+            self.generic_complex_methods()
+        } else {
+            quote! {}
+        };
+        let num_complex_methods = if self.has_storage_kind(is_complex) {
+            self.num_complex_methods()
+        } else {
+            quote! {}
+        };
+        quote! {
+            #generic_methods
+            #num_complex_methods
+        }
+    }
+
+    fn generic_complex_methods(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let complex_storage = quote! { #path_prefix::storage::ComplexStorage };
+
+        #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+        let arg = quote! {
+            /// The angle of the complex number in the complex plane,
+            /// which is dimensionless.
+            pub fn arg(&self) -> #quantity_type<S::Real, { #dimension_type::none() }> {
+                #quantity_type::<S::Real, { #dimension_type::none() }>(S::arg(self.0))
+            }
+        };
+        #[cfg(all(not(feature = "std"), not(feature = "num-traits-libm")))]
+        let arg = quote! {};
+
+        quote! {
+            impl<const D: #dimension_type, S: #complex_storage> #quantity_type<S, D> {
+                pub fn conj(&self) -> Self {
+                    Self(S::conj(self.0))
+                }
+
+                #arg
+            }
+        }
+    }
+
+    fn num_complex_methods(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let float_storage = self.float_storage_trait();
+        quote! {
+            impl<const D: #dimension_type, F: #float_storage> #quantity_type<::num_complex::Complex<F>, D> {
+                pub fn new(re: #quantity_type<F, D>, im: #quantity_type<F, D>) -> Self {
+                    Self(::num_complex::Complex::new(re.value_unchecked(), im.value_unchecked()))
+                }
+
+                pub fn re(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.re)
+                }
+
+                pub fn im(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.im)
+                }
+            }
+        }
+    }
+}
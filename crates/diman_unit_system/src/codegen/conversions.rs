@@ -0,0 +1,241 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::Path;
+
+use super::Codegen;
+use crate::types::{
+    base_dimension::BaseDimension,
+    conversion::{BaseDimensionConversion, Conversion},
+    Exponent,
+};
+use diman_lib::dimension_exponent::DimensionExponent;
+
+#[cfg(feature = "rational-dimensions")]
+impl Codegen {
+    fn exponent_literal(&self, value: &Exponent) -> TokenStream {
+        let num = value.num();
+        let denom = value.denom();
+        quote! { Exponent::new(#num, #denom) }
+    }
+
+    fn exponent_mul_add(
+        &self,
+        acc: TokenStream,
+        field: TokenStream,
+        factor: &Exponent,
+    ) -> TokenStream {
+        let factor = self.exponent_literal(factor);
+        quote! { #acc.add(#field.mul(#factor)) }
+    }
+}
+
+#[cfg(not(feature = "rational-dimensions"))]
+impl Codegen {
+    fn exponent_literal(&self, value: &Exponent) -> TokenStream {
+        quote! { #value }
+    }
+
+    fn exponent_mul_add(
+        &self,
+        acc: TokenStream,
+        field: TokenStream,
+        factor: &Exponent,
+    ) -> TokenStream {
+        quote! { #acc + #field * #factor }
+    }
+}
+
+/// The conversion functions live in a submodule, so relative
+/// paths to the other system need to be adjusted. Paths relative
+/// to the current module are covered by a glob import.
+fn path_from_submodule(path: &Path) -> Path {
+    let mut path = path.clone();
+    let first = &mut path.segments[0].ident;
+    if first == "self" {
+        *first = Ident::new("super", first.span());
+    } else if first == "super" {
+        let segment = first.clone().into();
+        path.segments.insert(0, segment);
+    }
+    path
+}
+
+impl Codegen {
+    pub fn gen_conversions(&self) -> TokenStream {
+        self.defs
+            .conversions
+            .iter()
+            .map(|conversion| self.gen_conversion(conversion))
+            .collect()
+    }
+
+    fn gen_conversion(&self, conversion: &Conversion) -> TokenStream {
+        let Conversion {
+            path,
+            quantity_type: other_quantity_type,
+            dimension_type: other_dimension_type,
+            ..
+        } = conversion;
+        let quantity_type = &self.defs.quantity_type;
+        let dimension_type = &self.defs.dimension_type;
+        let module = format_ident!(
+            "conversion_{}",
+            path.segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("_")
+        );
+        let functions = self.gen_conversion_functions(conversion, &path_from_submodule(path));
+        quote! {
+            // The other system may have base dimensions beyond the
+            // ones listed in the conversion, so struct updates are
+            // needed even if they are redundant for some systems.
+            #[allow(unused, clippy::needless_update)]
+            mod #module {
+                use super::*;
+                #functions
+            }
+
+            impl<S, const D: #path::#other_dimension_type> From<#path::#other_quantity_type<S, D>>
+                for #quantity_type<S, { #module::dimension_from_other(D) }>
+            where
+                S: ::core::ops::Mul<Magnitude, Output = S>,
+            {
+                fn from(quantity: #path::#other_quantity_type<S, D>) -> Self {
+                    #quantity_type(quantity.value_unchecked() * #module::magnitude_from_other(D))
+                }
+            }
+
+            impl<S, const D: #dimension_type> From<#quantity_type<S, D>>
+                for #path::#other_quantity_type<S, { #module::dimension_into_other(D) }>
+            where
+                S: ::core::ops::Mul<Magnitude, Output = S>,
+            {
+                fn from(quantity: #quantity_type<S, D>) -> Self {
+                    #path::#other_quantity_type::new_unchecked(
+                        quantity.value_unchecked() * #module::magnitude_into_other(D),
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns the base dimension of the other system which
+    /// corresponds exactly to the given base dimension of this system,
+    /// if there is one. Only these are used for converting into the
+    /// other system.
+    fn inverse_conversion<'a>(
+        &self,
+        conversion: &'a Conversion,
+        base_dimension: &BaseDimension,
+    ) -> Option<&'a BaseDimensionConversion> {
+        conversion.base_dimensions.iter().find(|entry| {
+            let mut fields = entry
+                .dimensions
+                .fields()
+                .filter(|(_, exponent)| **exponent != Exponent::zero());
+            matches!(fields.next(), Some((field, exponent)) if *field == base_dimension.0 && *exponent == Exponent::one())
+                && fields.next().is_none()
+        })
+    }
+
+    fn gen_conversion_functions(&self, conversion: &Conversion, path: &Path) -> TokenStream {
+        let other_dimension_type = &conversion.dimension_type;
+        let dimension_type = &self.defs.dimension_type;
+        let zero = self.exponent_literal(&Exponent::zero());
+        let other_fields: Vec<&Ident> = conversion
+            .base_dimensions
+            .iter()
+            .map(|entry| &entry.base_dimension.0)
+            .collect();
+        let fields_from_other: TokenStream = self
+            .defs
+            .base_dimensions
+            .iter()
+            .map(|base_dimension| {
+                let field = &base_dimension.0;
+                let value = conversion
+                    .base_dimensions
+                    .iter()
+                    .fold(zero.clone(), |acc, entry| {
+                        match entry.dimensions.get(base_dimension) {
+                            Some(factor) => {
+                                let other_field = &entry.base_dimension.0;
+                                self.exponent_mul_add(acc, quote! { other.#other_field }, factor)
+                            }
+                            None => acc,
+                        }
+                    });
+                quote! { #field: #value, }
+            })
+            .collect();
+        let magnitude_from_other: TokenStream = conversion
+            .base_dimensions
+            .iter()
+            .map(|entry| {
+                let other_field = &entry.base_dimension.0;
+                let magnitude = self.get_magnitude_expr(entry.magnitude);
+                quote! {
+                    * <Exponent as DimensionExponent>::float_pow(#magnitude, other.#other_field)
+                }
+            })
+            .collect();
+        let inverse: Vec<(&Ident, &BaseDimensionConversion)> = self
+            .defs
+            .base_dimensions
+            .iter()
+            .filter_map(|base_dimension| {
+                self.inverse_conversion(conversion, base_dimension)
+                    .map(|entry| (&base_dimension.0, entry))
+            })
+            .collect();
+        let inverse_fields: Vec<&Ident> = inverse.iter().map(|(field, _)| *field).collect();
+        let fields_into_other: TokenStream = inverse
+            .iter()
+            .map(|(field, entry)| {
+                let other_field = &entry.base_dimension.0;
+                quote! { #other_field: dim.#field, }
+            })
+            .collect();
+        let magnitude_into_other: TokenStream = inverse
+            .iter()
+            .map(|(field, entry)| {
+                let magnitude = self.get_magnitude_expr(entry.magnitude);
+                quote! {
+                    / <Exponent as DimensionExponent>::float_pow(#magnitude, dim.#field)
+                }
+            })
+            .collect();
+        let other_dimension = quote! { #path::#other_dimension_type };
+        quote! {
+            pub(super) const fn dimension_from_other(other: #other_dimension) -> #dimension_type {
+                if !(#other_dimension { #(#other_fields: #zero,)* ..other }).is_none() {
+                    panic!("Cannot convert a quantity whose dimension contains a base dimension without a counterpart in the other unit system.");
+                }
+                #dimension_type {
+                    #fields_from_other
+                    ..#dimension_type::none()
+                }
+            }
+
+            pub(super) const fn dimension_into_other(dim: #dimension_type) -> #other_dimension {
+                if !(#dimension_type { #(#inverse_fields: #zero,)* ..dim }).is_none() {
+                    panic!("Cannot convert a quantity whose dimension contains a base dimension without a counterpart in the other unit system.");
+                }
+                #other_dimension {
+                    #fields_into_other
+                    ..#other_dimension::none()
+                }
+            }
+
+            pub(super) fn magnitude_from_other(other: #other_dimension) -> Magnitude {
+                Magnitude::one() #magnitude_from_other
+            }
+
+            pub(super) fn magnitude_into_other(dim: #dimension_type) -> Magnitude {
+                Magnitude::one() #magnitude_into_other
+            }
+        }
+    }
+}
@@ -21,18 +21,28 @@ impl Codegen {
             CallerType::Internal => quote! { diman_lib::runtime_unit_storage::RuntimeUnit },
             CallerType::External => quote! { ::diman::internal::runtime_unit_storage::RuntimeUnit },
         };
+        // Alternative symbols come after the symbol of the unit, so
+        // that the latter is found first when formatting.
         let units: TokenStream = units
             .filter_map(|unit| {
                 let dim = self.get_dimension_expr(&unit.dimensions);
                 let magnitude = unit.magnitude.into_f64();
-                let symbol = &unit.symbol.as_ref()?.0.to_string();
-                Some(quote! {
-                    #runtime_unit::new(
-                         #symbol,
-                         #dim,
-                         #magnitude,
-                    ),
-                })
+                let offset = unit.offset.map(|offset| offset.into_f64()).unwrap_or(0.0);
+                let symbols = unit.symbol.as_ref()?.all();
+                Some(
+                    symbols
+                        .map(|symbol| {
+                            quote! {
+                                #runtime_unit::new(
+                                     #symbol,
+                                     #dim,
+                                     #magnitude,
+                                     #offset,
+                                ),
+                            }
+                        })
+                        .collect::<TokenStream>(),
+                )
             })
             .collect();
         let dimension_type = &self.defs.dimension_type;
@@ -45,12 +55,9 @@ impl Codegen {
     pub fn gen_debug_trait_impl(&self) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
-        let units_storage = self.runtime_unit_storage(
-            self.defs
-                .units
-                .iter()
-                .filter(|unit| unit.magnitude.is_one()),
-        );
+        let units_storage = self.runtime_unit_storage(self.defs.units.iter().filter(|unit| {
+            unit.magnitude.is_one() && unit.offset.is_none() && unit.logarithmic.is_none()
+        }));
         let get_base_dimension_symbols = self
             .defs
             .base_dimensions
@@ -74,6 +81,35 @@ impl Codegen {
         }
     }
 
+    pub fn gen_level_debug_trait_impl(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let symbols: TokenStream = self
+            .defs
+            .units
+            .iter()
+            .filter_map(|unit| {
+                let scale = self.get_logarithmic_scale_expr(unit.logarithmic?);
+                let dim = self.get_dimension_expr(&unit.dimensions);
+                let symbol = &unit.symbol.as_ref()?.name;
+                Some(quote! {
+                    (#dim, #scale, #symbol),
+                })
+            })
+            .collect();
+        quote! {
+            impl<const D: #dimension_type, const L: LogarithmicScale, S: core::fmt::Display> core::fmt::Debug for Level<S, D, L> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let symbols: &[(#dimension_type, LogarithmicScale, &str)] = &[#symbols];
+                    self.0.fmt(f)?;
+                    match symbols.iter().find(|(dim, scale, _)| *dim == D && *scale == L) {
+                        Some((_, _, symbol)) => write!(f, " {}", symbol),
+                        None => Ok(()),
+                    }
+                }
+            }
+        }
+    }
+
     fn get_base_dimension_symbol(&self, base_dim: &BaseDimension) -> TokenStream {
         let dim = self.get_dimension_expr(&BaseDimensions::for_base_dimension(base_dim.clone()));
         // We know that symbols exist for base dimensions, so we can unwrap here.
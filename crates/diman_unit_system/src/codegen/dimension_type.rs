@@ -60,6 +60,12 @@ impl Codegen {
         }
     }
 
+    fn is_zero_entry(&self, ident: &Ident) -> TokenStream {
+        quote! {
+            self.#ident.num() == 0 &&
+        }
+    }
+
     fn sqrt_safety(&self, _ident: &Ident) -> TokenStream {
         quote! {}
     }
@@ -115,6 +121,12 @@ impl Codegen {
         }
     }
 
+    fn is_zero_entry(&self, ident: &Ident) -> TokenStream {
+        quote! {
+            self.#ident == 0 &&
+        }
+    }
+
     fn sqrt_safety(&self, ident: &Ident) -> TokenStream {
         quote! {
             if self.#ident % 2 != 0 {
@@ -143,7 +155,7 @@ impl Codegen {
             .map(|dim| {
                 let dim = &dim.0;
                 quote! {
-                    #dim: #dim_type,
+                    pub #dim: #dim_type,
                 }
             })
             .collect();
@@ -155,13 +167,17 @@ impl Codegen {
             #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug, ::core::marker::ConstParamTy)]
             pub struct #name {
                 #dimensions
+                /// The quantity kind, which distinguishes dimensions declared
+                /// via `kind` from other dimensions with the same base dimensions.
+                /// A value of 0 denotes the absence of a kind.
+                pub kind: u32,
             }
 
             #methods_impl
         }
     }
 
-    fn use_exponent_and_dimension_exponent_trait(&self) -> TokenStream {
+    pub(crate) fn use_exponent_and_dimension_exponent_trait(&self) -> TokenStream {
         let path_prefix = self.caller_type.path_prefix();
         let use_exponent = match self.caller_type {
             CallerType::External => {
@@ -207,6 +223,7 @@ impl Codegen {
                 .collect::<TokenStream>()
         };
         let none_gen = gen(&Self::zero_entry);
+        let is_none_gen = gen(&Self::is_zero_entry);
         let mul_gen = gen(&Self::add_entry);
         let div_gen = gen(&Self::sub_entry);
         let inv_gen = gen(&Self::neg_entry);
@@ -221,30 +238,51 @@ impl Codegen {
                 pub const fn none() -> Self {
                     Self {
                         #none_gen
+                        kind: 0,
+                    }
+                }
+
+                /// Whether all base dimensions vanish, regardless of the kind.
+                pub const fn is_none(&self) -> bool {
+                    #is_none_gen true
+                }
+
+                /// Returns the dimension with the kind of `other`, after making sure
+                /// that the base dimensions agree, which makes conversions between
+                /// kinds possible.
+                pub const fn cast_kind(self, other: Self) -> Self {
+                    let result = Self { kind: other.kind, ..self };
+                    if !self.sub(other).is_none() {
+                        panic!("Cannot convert between kinds with different base dimensions.");
                     }
+                    result
                 }
 
                 pub const fn add(self, other: Self) -> Self {
                     Self {
                         #mul_gen
+                        kind: 0,
                     }
                 }
 
                 pub const fn sub(self, other: Self) -> Self {
                     Self {
                         #div_gen
+                        kind: 0,
                     }
                 }
 
                 pub const fn neg(self) -> Self {
                     Self {
                         #inv_gen
+                        kind: 0,
                     }
                 }
 
                 pub const fn mul(self, other: i32) -> Self {
                     Self {
                         #powi_gen
+                        kind: 0,
                     }
                 }
 
@@ -252,6 +290,7 @@ impl Codegen {
                     #sqrt_safety_gen
                     Self {
                         #sqrt_gen
+                        kind: 0,
                     }
                 }
 
@@ -259,6 +298,7 @@ impl Codegen {
                     #cbrt_safety_gen
                     Self {
                         #cbrt_gen
+                        kind: 0,
                     }
                 }
             }
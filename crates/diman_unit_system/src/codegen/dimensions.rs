@@ -1,4 +1,7 @@
-use crate::dimension_math::BaseDimensions;
+use std::iter::once;
+
+use crate::{dimension_math::BaseDimensions, types::Exponent};
+use diman_lib::dimension_exponent::DimensionExponent;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 
@@ -12,26 +15,73 @@ impl Codegen {
             .map(|(field, value)| self.get_base_dimension_entry(field, value))
             .collect();
         let span = self.defs.quantity_type.span();
-        let none_update = if dim.num_fields() < self.defs.base_dimensions.len() {
-            quote! { ..#dimension_type::none() }
-        } else {
-            quote! {}
+        let kind_update = match dim.kind {
+            Some(kind) => quote! { kind: #kind, },
+            None => quote! {},
         };
+        let none_update =
+            if dim.num_fields() < self.defs.base_dimensions.len() || dim.kind.is_none() {
+                quote! { ..#dimension_type::none() }
+            } else {
+                quote! {}
+            };
         quote_spanned! {span =>
             #dimension_type {
                 #field_updates
+                #kind_update
                 #none_update
             }
         }
     }
 
+    /// The dimensions of all dimensionless quantities, i.e. the
+    /// plain dimensionless one and those of the dimensionless kinds
+    /// (such as angles).
+    pub fn dimensionless_dimensions(&self) -> Vec<TokenStream> {
+        let dimension_type = &self.defs.dimension_type;
+        let kinds = self
+            .defs
+            .dimensions
+            .iter()
+            .filter(|dimension| {
+                dimension.dimensions.kind.is_some()
+                    && dimension
+                        .dimensions
+                        .fields()
+                        .all(|(_, exponent)| *exponent == Exponent::zero())
+            })
+            .map(|dimension| self.get_dimension_expr(&dimension.dimensions));
+        once(quote! { #dimension_type::none() })
+            .chain(kinds)
+            .collect()
+    }
+
+    /// The dimension of angles, if the unit system declares a
+    /// dimensionless kind called `Angle`, as the SI system does.
+    pub fn angle_dimension(&self) -> Option<TokenStream> {
+        self.defs
+            .dimensions
+            .iter()
+            .find(|dimension| {
+                dimension.name == "Angle"
+                    && dimension.dimensions.kind.is_some()
+                    && dimension
+                        .dimensions
+                        .fields()
+                        .all(|(_, exponent)| *exponent == Exponent::zero())
+            })
+            .map(|dimension| self.get_dimension_expr(&dimension.dimensions))
+    }
+
     pub fn gen_dimensions(&self) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
         let defs = self.gen_dimension_definitions();
+        let reexport = self.reexport_from_extended_system(quote! { dimensions });
         quote! {
             #[allow(unused)]
             pub mod dimensions {
+                #reexport
                 use super::#dimension_type;
                 use super::#quantity_type;
                 use super::Exponent;
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{storage_types::StorageKind, Codegen};
+
+impl Codegen {
+    pub fn gen_dual_methods(&self) -> TokenStream {
+        if !self.has_storage_kind(|kind| matches!(kind, StorageKind::Dual(_))) {
+            return quote! {};
+        }
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let float_storage = quote! { #path_prefix::storage::FloatStorage };
+        let dual_storage = quote! { #path_prefix::storage::DualStorage };
+        let dual_type = quote! {
+            #path_prefix::dual::Dual<F, #quantity_type<F, DX>>
+        };
+        let variable_type = quote! {
+            #path_prefix::dual::Dual<F, #quantity_type<F, D>>
+        };
+        quote! {
+            impl<const D: #dimension_type, const DX: #dimension_type, F: #float_storage> #quantity_type<#dual_type, D>
+            where
+                #dual_type: #dual_storage<Value = F>,
+            {
+                pub fn new(
+                    value: #quantity_type<F, D>,
+                    derivative: #quantity_type<F, { D.sub(DX) }>,
+                ) -> Self {
+                    Self(<#dual_type>::new(value.value_unchecked(), derivative.value_unchecked()))
+                }
+
+                /// A quantity which does not depend on the variable.
+                pub fn constant(value: #quantity_type<F, D>) -> Self {
+                    Self(#dual_storage::constant(value.value_unchecked()))
+                }
+
+                /// The value of the quantity, without its derivative.
+                pub fn real(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.value)
+                }
+
+                /// The derivative of the quantity with respect to the
+                /// variable, whose dimension is the dimension of the
+                /// quantity divided by the dimension of the variable.
+                pub fn derivative(&self) -> #quantity_type<F, { D.sub(DX) }> {
+                    #quantity_type(self.0.derivative)
+                }
+            }
+
+            impl<const D: #dimension_type, F: #float_storage> #quantity_type<#variable_type, D>
+            where
+                #variable_type: #dual_storage<Value = F>,
+            {
+                /// The variable with respect to which derivatives are
+                /// taken, whose derivative is one.
+                pub fn variable(value: #quantity_type<F, D>) -> Self {
+                    Self(#dual_storage::variable(value.value_unchecked()))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+use super::{join, Codegen};
+
+impl Codegen {
+    /// Generates the code for a unit system which extends an
+    /// existing one. All types (and their trait impls) are
+    /// re-exported from the extended system, so that quantities of
+    /// both systems are interchangeable. Only the new dimensions,
+    /// units and constants are defined.
+    pub fn gen_extension(&self, path: &Path) -> TokenStream {
+        let quantity_type = &self.defs.quantity_type;
+        let dimension_type = &self.defs.dimension_type;
+        let use_exponent = self.use_exponent_and_dimension_exponent_trait();
+        join([
+            quote! {
+                #use_exponent
+                #[allow(unused)]
+                pub use #path::{
+                    #quantity_type,
+                    #dimension_type,
+                    Magnitude,
+                    Unit,
+                    AffineUnit,
+                    Point,
+                    LogarithmicScale,
+                    LogarithmicUnit,
+                    Level,
+                };
+            },
+            self.gen_dimensions(),
+            self.gen_units_and_constants(),
+        ])
+    }
+
+    /// Re-exports the contents of the given module of the
+    /// extended system, if there is one.
+    pub(crate) fn reexport_from_extended_system(&self, module: TokenStream) -> TokenStream {
+        match self.defs.extends {
+            Some(ref path) => quote! { pub use #path::#module::*; },
+            None => quote! {},
+        }
+    }
+}
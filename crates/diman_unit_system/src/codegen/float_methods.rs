@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::{join, storage_types::FloatType, CallerType, Codegen};
+use super::{join, storage_types::StorageKind, CallerType, Codegen};
 
 impl Codegen {
     fn ensure_float_traits(&self) -> TokenStream {
@@ -20,161 +20,308 @@ impl Codegen {
         }
     }
 
-    fn dimensionless_float_method(
+    /// The path to the trait for floating point storage types.
+    pub(crate) fn float_storage_trait(&self) -> TokenStream {
+        let path_prefix = self.caller_type.path_prefix();
+        quote! { #path_prefix::storage::FloatStorage }
+    }
+
+    fn dimensionless_float_methods(
         &self,
-        float_type: &FloatType,
-        method_name: &TokenStream,
+        dimension: &TokenStream,
+        method_names: &[TokenStream],
     ) -> TokenStream {
-        let float_type_name = &float_type.name;
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
+        let float_storage = self.float_storage_trait();
         quote! {
-            impl #quantity_type<#float_type_name, {#dimension_type::none()} > {
-                pub fn #method_name(&self) -> #quantity_type<#float_type_name, {#dimension_type::none()}> {
-                    Self(self.0.#method_name())
-                }
+            impl<S: #float_storage> #quantity_type<S, { #dimension } > {
+                #(
+                    pub fn #method_names(&self) -> #quantity_type<S, {#dimension_type::none()}> {
+                        #quantity_type(S::#method_names(self.0))
+                    }
+                )*
             }
         }
     }
 
-    fn dimensionless_float_method_for_all_float_types(
-        &self,
-        method_name: &TokenStream,
-    ) -> TokenStream {
-        self.float_types()
-            .iter()
-            .map(|float_type| self.dimensionless_float_method(float_type, method_name))
-            .collect()
-    }
-
     #[cfg_attr(
         not(any(feature = "std", feature = "num-traits-libm")),
         allow(dead_code)
     )]
     fn all_dimensionless_float_methods(&self) -> TokenStream {
-        join([
-            self.dimensionless_float_method_for_all_float_types(&quote! { log2 }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { ln }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { log10 }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { exp }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { exp2 }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { ceil }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { floor }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { sin }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { cos }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { tan }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { asin }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { acos }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { atan }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { sinh }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { cosh }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { tanh }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { asinh }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { acosh }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { atanh }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { exp_m1 }),
-            self.dimensionless_float_method_for_all_float_types(&quote! { ln_1p }),
-        ])
+        let method_names = [
+            quote! { log2 },
+            quote! { ln },
+            quote! { log10 },
+            quote! { exp },
+            quote! { exp2 },
+            quote! { ceil },
+            quote! { floor },
+            quote! { sin },
+            quote! { cos },
+            quote! { tan },
+            quote! { asin },
+            quote! { acos },
+            quote! { atan },
+            quote! { sinh },
+            quote! { cosh },
+            quote! { tanh },
+            quote! { asinh },
+            quote! { acosh },
+            quote! { atanh },
+            quote! { exp_m1 },
+            quote! { ln_1p },
+        ];
+        self.dimensionless_dimensions()
+            .iter()
+            .map(|dimension| self.dimensionless_float_methods(dimension, &method_names))
+            .collect()
     }
 
     pub fn gen_float_methods(&self) -> TokenStream {
         join([
             self.ensure_float_traits(),
+            self.real_methods(),
             #[cfg(any(feature = "std", feature = "num-traits-libm"))]
             self.all_dimensionless_float_methods(),
-            self.specific_float_methods_for_all_float_types(),
+            self.specific_float_methods(),
+            self.primitive_float_methods(),
         ])
     }
 
-    fn specific_float_methods_for_all_float_types(&self) -> TokenStream {
-        self.float_types()
-            .iter()
-            .map(|float_type| self.specific_float_methods(float_type))
-            .collect()
+    /// Methods shared by float and vector storage types.
+    fn real_methods(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let real_storage = quote! { #path_prefix::storage::RealStorage };
+        quote! {
+            impl<const D: #dimension_type, S: #real_storage> #quantity_type<S, D> {
+                pub fn zero() -> Self {
+                    Self(S::zero())
+                }
+
+                pub fn min<Q: Into<Self>>(self, other: Q) -> Self {
+                    Self(S::min(self.0, other.into().0))
+                }
+
+                pub fn max<Q: Into<Self>>(self, other: Q) -> Self {
+                    Self(S::max(self.0, other.into().0))
+                }
+            }
+        }
     }
 
-    fn specific_float_methods(&self, float_type: &FloatType) -> TokenStream {
-        let float_type = &float_type.name;
+    fn specific_float_methods(&self) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
+        let float_storage = self.float_storage_trait();
+        let zero = quote! { S::zero() };
 
         #[cfg(any(feature = "std", feature = "num-traits-libm"))]
         let roots = quote! {
-                pub fn sqrt(&self) -> #quantity_type<#float_type, { D.div_2() }>
+                pub fn sqrt(&self) -> #quantity_type<S, { D.div_2() }>
                 {
-                    #quantity_type::<#float_type, { D.div_2() }>(self.0.sqrt())
+                    #quantity_type::<S, { D.div_2() }>(S::sqrt(self.0))
                 }
 
-                pub fn cbrt(&self) -> #quantity_type<#float_type, { D.div_3() }>
+                pub fn cbrt(&self) -> #quantity_type<S, { D.div_3() }>
                 {
-                    #quantity_type::<#float_type, { D.div_3() }>(self.0.cbrt())
+                    #quantity_type::<S, { D.div_3() }>(S::cbrt(self.0))
                 }
         };
         #[cfg(all(not(feature = "std"), not(feature = "num-traits-libm")))]
         let roots = quote! {};
 
         quote! {
-            impl<const D: #dimension_type> #quantity_type<#float_type, D> {
-                pub fn squared(&self) -> #quantity_type<#float_type, { D.mul(2) }>
+            impl<const D: #dimension_type, S: #float_storage> #quantity_type<S, D> {
+                pub fn squared(&self) -> #quantity_type<S, { D.mul(2) }>
                 where
-                    #quantity_type::<#float_type, { D.mul(2) }>:
+                    #quantity_type::<S, { D.mul(2) }>:
                 {
-                    #quantity_type::<#float_type, { D.mul(2) }>(self.0.powi(2))
+                    #quantity_type::<S, { D.mul(2) }>(S::powi(self.0, 2))
                 }
 
-                pub fn cubed(&self) -> #quantity_type<#float_type, { D.mul(3) }>
+                pub fn cubed(&self) -> #quantity_type<S, { D.mul(3) }>
                 where
-                    #quantity_type::<#float_type, { D.mul(3) }>:
+                    #quantity_type::<S, { D.mul(3) }>:
                 {
-                    #quantity_type::<#float_type, { D.mul(3) }>(self.0.powi(3))
+                    #quantity_type::<S, { D.mul(3) }>(S::powi(self.0, 3))
                 }
 
 
-                pub fn powi<const I: i32>(&self) -> #quantity_type<#float_type, { D.mul(I) }>
+                pub fn powi<const I: i32>(&self) -> #quantity_type<S, { D.mul(I) }>
                 where
-                    #quantity_type::<#float_type, { D.mul(I) }>:
+                    #quantity_type::<S, { D.mul(I) }>:
                 {
-                    #quantity_type::<#float_type, { D.mul(I) }>(self.0.powi(I))
+                    #quantity_type::<S, { D.mul(I) }>(S::powi(self.0, I))
                 }
 
                 #roots
 
-                pub fn min<Q: Into<Self>>(self, other: Q) -> Self {
-                    Self(self.0.min(other.into().0))
-                }
-
-                pub fn max<Q: Into<Self>>(self, other: Q) -> Self {
-                    Self(self.0.max(other.into().0))
-                }
-
                 pub fn clamp<Q: Into<Self>>(self, min: Q, max: Q) -> Self {
-                    Self(self.0.clamp(min.into().0, max.into().0))
-                }
-
-                pub fn zero() -> Self {
-                    Self(0.0)
+                    Self(S::clamp(self.0, min.into().0, max.into().0))
                 }
 
                 pub fn is_positive(&self) -> bool {
-                    self.0 > 0.0
+                    self.0 > #zero
                 }
 
                 pub fn is_positive_or_zero(&self) -> bool {
-                    self.0 >= 0.0
+                    self.0 >= #zero
                 }
 
                 pub fn is_negative(&self) -> bool {
-                    self.0 < 0.0
+                    self.0 < #zero
                 }
 
                 pub fn is_negative_or_zero(&self) -> bool {
-                    self.0 <= 0.0
+                    self.0 <= #zero
                 }
 
                 pub fn is_nan(&self) -> bool {
-                    self.0.is_nan()
+                    S::is_nan(self.0)
+                }
+            }
+        }
+    }
+
+    /// Constants and methods of `f32` and `f64` which have no
+    /// counterpart in `FloatStorage`.
+    fn primitive_float_methods(&self) -> TokenStream {
+        if !self.needs_storage_trait_impls(|kind| matches!(kind, StorageKind::Float(_))) {
+            return quote! {};
+        }
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let primitive_float = quote! { #path_prefix::storage::PrimitiveFloatStorage };
+        let total_order = quote! { #path_prefix::ordered::TotalOrder };
+
+        #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+        let angle = self
+            .angle_dimension()
+            .unwrap_or_else(|| quote! { #dimension_type::none() });
+        #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+        let libm_methods = quote! {
+                pub fn hypot(self, other: Self) -> Self {
+                    Self(S::hypot(self.0, other.0))
+                }
+
+                /// Computes `self * a + b` with only one rounding error.
+                pub fn mul_add<const DA: #dimension_type>(
+                    self,
+                    a: #quantity_type<S, DA>,
+                    b: #quantity_type<S, { D.add(DA) }>,
+                ) -> #quantity_type<S, { D.add(DA) }>
+                where
+                    #quantity_type<(), { D.add(DA) }>:,
+                {
+                    #quantity_type(S::mul_add(self.0, a.0, b.0))
+                }
+
+                /// The four quadrant arctangent of `self` (the `y`
+                /// coordinate) and `x`.
+                pub fn atan2(self, x: Self) -> #quantity_type<S, { #angle }> {
+                    #quantity_type(S::atan2(self.0, x.0))
+                }
+
+                /// The quantity with the magnitude of `self` and the
+                /// sign of `sign`, which can be of any dimension.
+                pub fn copysign<const DS: #dimension_type>(
+                    self,
+                    sign: #quantity_type<S, DS>,
+                ) -> Self {
+                    Self(S::copysign(self.0, sign.0))
+                }
+        };
+        #[cfg(all(not(feature = "std"), not(feature = "num-traits-libm")))]
+        let libm_methods = quote! {};
+
+        let angle_methods = match self.angle_dimension() {
+            Some(angle) => quote! {
+                /// Unlike `to_degrees` and `to_radians` of the storage
+                /// type, which would be reached via `Deref`, these take
+                /// into account that angles are stored in radians.
+                impl<S: #primitive_float> #quantity_type<S, { #angle }> {
+                    pub fn to_degrees(self) -> S {
+                        S::to_degrees(self.0)
+                    }
+
+                    pub fn to_radians(self) -> S {
+                        self.0
+                    }
+                }
+            },
+            None => quote! {},
+        };
+
+        quote! {
+            impl<const D: #dimension_type, S: #primitive_float> #quantity_type<S, D> {
+                pub const NAN: Self = Self(S::NAN);
+                pub const INFINITY: Self = Self(S::INFINITY);
+                pub const EPSILON: Self = Self(S::EPSILON);
+                pub const MIN: Self = Self(S::MIN);
+                pub const MAX: Self = Self(S::MAX);
+
+                #libm_methods
+
+                pub fn recip(self) -> #quantity_type<S, { D.neg() }>
+                where
+                    #quantity_type<(), { D.neg() }>:,
+                {
+                    #quantity_type(S::recip(self.0))
+                }
+
+                pub fn signum(self) -> #quantity_type<S, { #dimension_type::none() }> {
+                    #quantity_type(S::signum(self.0))
+                }
+
+                /// The least nonnegative remainder of `self` divided
+                /// by `rhs`.
+                pub fn rem_euclid(self, rhs: Self) -> Self {
+                    Self(S::rem_euclid(self.0, rhs.0))
+                }
+
+                pub fn is_finite(&self) -> bool {
+                    S::is_finite(self.0)
+                }
+
+                pub fn is_infinite(&self) -> bool {
+                    S::is_infinite(self.0)
+                }
+
+                pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    S::total_cmp(&self.0, &other.0)
                 }
             }
+
+            impl<const D: #dimension_type, S: #total_order> #total_order for #quantity_type<S, D> {
+                fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    S::total_cmp(&self.0, &other.0)
+                }
+
+                fn total_hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    S::total_hash(&self.0, state)
+                }
+            }
+
+            impl<const D: #dimension_type, S: #primitive_float> core::ops::Rem for #quantity_type<S, D> {
+                type Output = Self;
+
+                fn rem(self, rhs: Self) -> Self {
+                    Self(self.0 % rhs.0)
+                }
+            }
+
+            impl<const D: #dimension_type, S: #primitive_float> core::ops::RemAssign for #quantity_type<S, D> {
+                fn rem_assign(&mut self, rhs: Self) {
+                    self.0 %= rhs.0;
+                }
+            }
+
+            #angle_methods
         }
     }
 }
@@ -2,13 +2,63 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Type;
 
-use super::Codegen;
+use super::{storage_types::StorageKind, Codegen};
 
 impl Codegen {
     pub fn gen_generic_methods(&self) -> TokenStream {
-        self.storage_type_names()
-            .map(|name| self.impl_method_for_generic_storage_type(&name, &quote! { abs }))
-            .collect()
+        let abs: TokenStream = self
+            .storage_type_names()
+            .iter()
+            .map(|ty| match ty.kind {
+                // Complex numbers have `norm` instead of `abs`, and unsigned
+                // integer types have no `abs`.
+                StorageKind::Float(_)
+                | StorageKind::Vector { .. }
+                | StorageKind::Integer { signed: true }
+                | StorageKind::Custom(_) => {
+                    self.impl_method_for_generic_storage_type(&ty.name, &quote! { abs })
+                }
+                StorageKind::Half(_) => self.half_abs_method(&ty.name),
+                _ => quote! {},
+            })
+            .collect();
+        #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+        let norm = self.norm_method();
+        #[cfg(all(not(feature = "std"), not(feature = "num-traits-libm")))]
+        let norm = quote! {};
+        quote! {
+            #abs
+            #norm
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn norm_method(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let normed_storage = quote! { #path_prefix::storage::NormedStorage };
+        quote! {
+            impl<const D: #dimension_type, S: #normed_storage> #quantity_type<S, D> {
+                pub fn norm(&self) -> #quantity_type<S::Real, D> {
+                    #quantity_type::<S::Real, D>(S::norm(&self.0))
+                }
+            }
+        }
+    }
+
+    /// The types of the `half` crate have no `abs`, but taking the
+    /// absolute value in `f32` is exact.
+    fn half_abs_method(&self, storage_type: &Type) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#storage_type, D> {
+                pub fn abs(&self) -> #quantity_type<#storage_type, D> {
+                    Self(<#storage_type>::from_f32(self.0.to_f32().abs()))
+                }
+            }
+        }
     }
 
     fn impl_method_for_generic_storage_type(
@@ -1,19 +1,24 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::join;
-use super::storage_types::{FloatType, VectorType};
+use syn::Type;
+
+use super::storage_types::{FloatType, HalfType, StorageKind};
 use super::Codegen;
 
 impl Codegen {
     pub fn gen_hdf5_impl(&self) -> TokenStream {
-        join([self.hdf5_floats_impl(), self.hdf5_vectors_impl()])
-    }
-
-    fn hdf5_floats_impl(&self) -> TokenStream {
-        self.float_types()
+        self.storage_type_names()
             .iter()
-            .map(|float_type| self.hdf5_float_impl(float_type))
+            .map(|ty| match &ty.kind {
+                StorageKind::Float(float_type) => self.hdf5_float_impl(float_type),
+                StorageKind::Half(half_type) => self.hdf5_half_impl(&ty.name, half_type),
+                StorageKind::Vector {
+                    float_type,
+                    num_dims,
+                } => self.hdf5_vector_impl(&ty.name, float_type, *num_dims),
+                _ => quote! {},
+            })
             .collect()
     }
 
@@ -31,17 +36,26 @@ impl Codegen {
         }
     }
 
-    fn hdf5_vectors_impl(&self) -> TokenStream {
-        self.vector_types()
-            .iter()
-            .map(|vector_type| self.hdf5_vector_impl(vector_type))
-            .collect()
+    fn hdf5_half_impl(&self, half_type_name: &Type, half_type: &HalfType) -> TokenStream {
+        let hdf5_type = &half_type.hdf5_type;
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            unsafe impl<const D: #dimension_type> hdf5::H5Type for #quantity_type<#half_type_name, D> {
+                fn type_descriptor() -> hdf5::types::TypeDescriptor {
+                    #hdf5_type
+                }
+            }
+        }
     }
 
-    fn hdf5_vector_impl(&self, vector_type: &VectorType) -> TokenStream {
-        let vector_type_name = &vector_type.name;
-        let hdf5_type = &vector_type.float_type.hdf5_type;
-        let num_dims = vector_type.num_dims;
+    fn hdf5_vector_impl(
+        &self,
+        vector_type_name: &Type,
+        float_type: &FloatType,
+        num_dims: usize,
+    ) -> TokenStream {
+        let hdf5_type = &float_type.hdf5_type;
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
         quote! {
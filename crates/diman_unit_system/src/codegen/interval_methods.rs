@@ -0,0 +1,55 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{storage_types::StorageKind, Codegen};
+
+impl Codegen {
+    pub fn gen_interval_methods(&self) -> TokenStream {
+        if !self.has_storage_kind(|kind| matches!(kind, StorageKind::Interval(_))) {
+            return quote! {};
+        }
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let interval_storage = quote! { #path_prefix::storage::IntervalStorage };
+        let interval = quote! { #path_prefix::interval::Interval<F> };
+        quote! {
+            impl<const D: #dimension_type, F: #path_prefix::storage::FloatStorage> #quantity_type<#interval, D>
+            where
+                #interval: #interval_storage<Bound = F>,
+            {
+                /// The lower bound must not be greater than the upper bound.
+                pub fn new(lower: #quantity_type<F, D>, upper: #quantity_type<F, D>) -> Self {
+                    Self(<#interval>::new(lower.value_unchecked(), upper.value_unchecked()))
+                }
+
+                pub fn lower(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.lower)
+                }
+
+                pub fn upper(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.upper)
+                }
+
+                pub fn midpoint(&self) -> #quantity_type<F, D> {
+                    #quantity_type(#interval_storage::midpoint(self.0))
+                }
+
+                /// The width of the interval, rounded up.
+                pub fn width(&self) -> #quantity_type<F, D> {
+                    #quantity_type(#interval_storage::width(self.0))
+                }
+
+                pub fn contains(&self, value: #quantity_type<F, D>) -> bool {
+                    self.0.contains(&value.value_unchecked())
+                }
+
+                /// Whether this interval lies entirely below or above
+                /// `other`, or whether they overlap.
+                pub fn compare(&self, other: &Self) -> #path_prefix::interval::IntervalOrdering {
+                    self.0.compare(&other.0)
+                }
+            }
+        }
+    }
+}
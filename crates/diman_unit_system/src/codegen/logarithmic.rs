@@ -0,0 +1,133 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+#[cfg(any(feature = "std", feature = "num-traits-libm"))]
+use super::storage_types::{FloatType, StorageKind};
+use super::Codegen;
+
+impl Codegen {
+    pub(crate) fn gen_logarithmic_types(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let span = self.defs.quantity_type.span();
+        let functions = self.logarithmic_functions();
+        #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+        let float_impls: TokenStream = self
+            .storage_type_names()
+            .iter()
+            .filter_map(|ty| match &ty.kind {
+                StorageKind::Float(float_type) => Some(self.logarithmic_float_impls(float_type)),
+                _ => None,
+            })
+            .collect();
+        #[cfg(not(any(feature = "std", feature = "num-traits-libm")))]
+        let float_impls = quote! {};
+        quote_spanned! {span =>
+            pub use #path_prefix::logarithmic_scale::LogarithmicScale;
+            /// A logarithmic unit, such as the decibel-milliwatt. The
+            /// scale `L` contains the reference quantity of the unit.
+            pub struct LogarithmicUnit<const D: #dimension_type, const L: LogarithmicScale>;
+            /// A level of a quantity on the logarithmic scale `L`, such as
+            /// a power given in dBm. Levels can be converted to and from
+            /// quantities of the same dimension. Adding two levels adds
+            /// the underlying powers.
+            #[derive(Clone, Copy, PartialEq, PartialOrd, Default)]
+            #[repr(transparent)]
+            pub struct Level<S, const D: #dimension_type, const L: LogarithmicScale>(pub(crate) S);
+            #functions
+            #float_impls
+        }
+    }
+
+    fn logarithmic_functions(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<const D: #dimension_type, const L: LogarithmicScale> LogarithmicUnit<D, L> {
+                /// Create a level with the given value in this unit.
+                pub fn new<S>(self, val: S) -> Level<S, D, L> {
+                    Level(val)
+                }
+
+                /// Return the level of the given quantity in this unit.
+                pub fn level_of<S>(self, quantity: #quantity_type<S, D>) -> Level<S, D, L>
+                where
+                    Level<S, D, L>: From<#quantity_type<S, D>>,
+                {
+                    quantity.into()
+                }
+            }
+
+            impl<S, const D: #dimension_type, const L: LogarithmicScale> Level<S, D, L> {
+                /// Return the value of the level in its logarithmic unit.
+                pub fn value(self) -> S {
+                    self.0
+                }
+
+                /// Return a reference to the value of the level in its
+                /// logarithmic unit.
+                pub fn value_ref(&self) -> &S {
+                    &self.0
+                }
+
+                /// Convert the level into the corresponding (linear) quantity.
+                pub fn into_quantity(self) -> #quantity_type<S, D>
+                where
+                    #quantity_type<S, D>: From<Self>,
+                {
+                    self.into()
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+    fn logarithmic_float_impls(&self, float_type: &FloatType) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let conversion_method = &float_type.conversion_method;
+        let float_type = &float_type.name;
+        quote! {
+            impl<const D: #dimension_type, const L: LogarithmicScale> From<#quantity_type<#float_type, D>>
+                for Level<#float_type, D, L>
+            {
+                fn from(quantity: #quantity_type<#float_type, D>) -> Self {
+                    let ratio = quantity.value_unchecked() / L.reference.#conversion_method();
+                    Level(L.factor.#conversion_method() * ratio.ln() / L.base.#conversion_method().ln())
+                }
+            }
+
+            impl<const D: #dimension_type, const L: LogarithmicScale> From<Level<#float_type, D, L>>
+                for #quantity_type<#float_type, D>
+            {
+                fn from(level: Level<#float_type, D, L>) -> Self {
+                    let ratio = L.base.#conversion_method().powf(level.0 / L.factor.#conversion_method());
+                    #quantity_type(L.reference.#conversion_method() * ratio)
+                }
+            }
+
+            impl<const D: #dimension_type, const L: LogarithmicScale> core::ops::Add
+                for Level<#float_type, D, L>
+            {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self::Output {
+                    let base = L.base.#conversion_method();
+                    let factor = L.factor.#conversion_method();
+                    let exponent = L.power_exponent() as #float_type;
+                    let power = |level: #float_type| base.powf(exponent * level / factor);
+                    let total = power(self.0) + power(rhs.0);
+                    Level(factor / exponent * total.ln() / base.ln())
+                }
+            }
+
+            impl<const D: #dimension_type, const L: LogarithmicScale> core::ops::AddAssign
+                for Level<#float_type, D, L>
+            {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use syn::Type;
+
+use super::{
+    storage_types::{FloatType, StorageKind},
+    Codegen,
+};
+
+impl Codegen {
+    pub fn gen_matrix_methods(&self) -> TokenStream {
+        self.storage_type_names()
+            .iter()
+            .filter_map(|ty| match &ty.kind {
+                StorageKind::Matrix {
+                    float_type,
+                    vector_type,
+                    num_dims,
+                } => Some(self.impl_matrix_methods(&ty.name, float_type, vector_type, *num_dims)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn impl_matrix_methods(
+        &self,
+        matrix_type: &Type,
+        float_type: &FloatType,
+        vector_type: &Type,
+        num_dims: usize,
+    ) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let float_type = &float_type.name;
+        let columns: Vec<Ident> = ["x_axis", "y_axis", "z_axis"][..num_dims]
+            .iter()
+            .map(|name| format_ident!("{}", name))
+            .collect();
+        let num_dims = Literal::i32_unsuffixed(num_dims as i32);
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#matrix_type, D> {
+                pub fn from_cols(#(#columns: #quantity_type<#vector_type, D>),*) -> Self {
+                    Self(<#matrix_type>::from_cols(#(#columns.value_unchecked()),*))
+                }
+
+                pub fn from_diagonal(diagonal: #quantity_type<#vector_type, D>) -> Self {
+                    Self(<#matrix_type>::from_diagonal(diagonal.value_unchecked()))
+                }
+
+                /// Panics if the index is out of bounds.
+                pub fn col(&self, index: usize) -> #quantity_type<#vector_type, D> {
+                    #quantity_type(self.0.col(index))
+                }
+
+                /// Panics if the index is out of bounds.
+                pub fn row(&self, index: usize) -> #quantity_type<#vector_type, D> {
+                    #quantity_type(self.0.row(index))
+                }
+
+                pub fn transpose(&self) -> Self {
+                    Self(self.0.transpose())
+                }
+
+                pub fn determinant(&self) -> #quantity_type<#float_type, { D.mul(#num_dims) }>
+                where
+                    #quantity_type<#float_type, { D.mul(#num_dims) }>:,
+                {
+                    #quantity_type(self.0.determinant())
+                }
+
+                /// The result is invalid if the determinant is zero.
+                pub fn inverse(&self) -> #quantity_type<#matrix_type, { D.neg() }> {
+                    #quantity_type(self.0.inverse())
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{storage_types::StorageKind, Codegen};
+
+impl Codegen {
+    pub fn gen_measurement_methods(&self) -> TokenStream {
+        if !self.has_storage_kind(|kind| matches!(kind, StorageKind::Measurement(_))) {
+            return quote! {};
+        }
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let measurement_storage = quote! { #path_prefix::storage::MeasurementStorage };
+        let measurement = quote! { #path_prefix::measurement::Measurement<F> };
+        quote! {
+            impl<const D: #dimension_type, F: #path_prefix::storage::FloatStorage> #quantity_type<#measurement, D>
+            where
+                #measurement: #measurement_storage<Value = F>,
+            {
+                pub fn new(
+                    value: #quantity_type<F, D>,
+                    uncertainty: #quantity_type<F, D>,
+                ) -> Self {
+                    Self(<#measurement>::new(
+                        value.value_unchecked(),
+                        uncertainty.value_unchecked(),
+                    ))
+                }
+
+                /// The measured value, without its uncertainty.
+                pub fn nominal(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.value)
+                }
+
+                /// The standard uncertainty of the measured value.
+                pub fn uncertainty(&self) -> #quantity_type<F, D> {
+                    #quantity_type(self.0.uncertainty)
+                }
+
+                /// The uncertainty divided by the absolute value, which
+                /// is dimensionless.
+                pub fn relative_uncertainty(&self) -> #quantity_type<F, { #dimension_type::none() }> {
+                    #quantity_type(#measurement_storage::relative_uncertainty(self.0))
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,24 @@
+mod complex_methods;
+mod conversions;
 mod debug_trait;
 mod dimension_type;
 mod dimensions;
+mod dual_methods;
+mod extension;
 mod float_methods;
 mod generic_methods;
 #[cfg(feature = "hdf5")]
 mod hdf5;
+mod interval_methods;
+mod logarithmic;
+mod matrix_methods;
+mod measurement_methods;
 #[cfg(feature = "mpi")]
 mod mpi;
+mod nalgebra_methods;
+mod ndarray_methods;
 mod num_traits;
+mod point_type;
 mod quantity_type;
 #[cfg(feature = "rand")]
 mod rand;
@@ -53,15 +64,30 @@ fn join<const D: usize>(streams: [TokenStream; D]) -> TokenStream {
 
 impl Codegen {
     pub fn code_gen(&self) -> TokenStream {
+        if let Some(ref path) = self.defs.extends {
+            return self.gen_extension(path);
+        }
         join([
             self.gen_dimension(),
             self.gen_quantity(),
+            self.gen_point(),
+            self.gen_logarithmic_types(),
             self.gen_dimensions(),
+            self.gen_unit_types(),
             self.gen_units_and_constants(),
+            self.gen_conversions(),
             self.gen_numeric_trait_impls(),
             self.gen_debug_trait_impl(),
+            self.gen_level_debug_trait_impl(),
             self.gen_float_methods(),
             self.gen_vector_methods(),
+            self.gen_complex_methods(),
+            self.gen_measurement_methods(),
+            self.gen_interval_methods(),
+            self.gen_dual_methods(),
+            self.gen_nalgebra_methods(),
+            self.gen_ndarray_methods(),
+            self.gen_matrix_methods(),
             self.gen_generic_methods(),
             #[cfg(feature = "serde")]
             self.gen_serde_impl(),
@@ -1,21 +1,24 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::storage_types::{FloatType, VectorType};
+use syn::Type;
 
-use super::join;
+use super::storage_types::{FloatType, StorageKind};
 
 use super::Codegen;
 
 impl Codegen {
     pub fn gen_mpi_impl(&self) -> TokenStream {
-        join([self.mpi_floats_impl(), self.mpi_vectors_impl()])
-    }
-
-    fn mpi_floats_impl(&self) -> TokenStream {
-        self.float_types()
+        self.storage_type_names()
             .iter()
-            .map(|float_type| self.mpi_float_impl(float_type))
+            .map(|ty| match &ty.kind {
+                StorageKind::Float(float_type) => self.mpi_float_impl(float_type),
+                StorageKind::Vector {
+                    float_type,
+                    num_dims,
+                } => self.mpi_vector_impl(&ty.name, &float_type.name, *num_dims),
+                _ => quote! {},
+            })
             .collect()
     }
 
@@ -37,17 +40,13 @@ impl Codegen {
         }
     }
 
-    fn mpi_vectors_impl(&self) -> TokenStream {
-        self.vector_types()
-            .iter()
-            .map(|vector_type| self.mpi_vector_impl(vector_type))
-            .collect()
-    }
-
-    fn mpi_vector_impl(&self, vector_type: &VectorType) -> TokenStream {
-        let vector_type_name = &vector_type.name;
-        let float_type = &vector_type.float_type.name;
-        let num_dims = vector_type.num_dims as i32;
+    fn mpi_vector_impl(
+        &self,
+        vector_type_name: &Type,
+        float_type: &Type,
+        num_dims: usize,
+    ) -> TokenStream {
+        let num_dims = num_dims as i32;
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
         quote! {
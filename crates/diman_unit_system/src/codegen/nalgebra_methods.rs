@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Type;
+
+use super::{
+    storage_types::{StorageKind, StorageTypeName},
+    Codegen,
+};
+
+impl Codegen {
+    pub fn gen_nalgebra_methods(&self) -> TokenStream {
+        self.storage_type_names()
+            .iter()
+            .filter_map(|ty| match &ty.kind {
+                StorageKind::NalgebraVector {
+                    float_type,
+                    is_static,
+                } => Some(self.impl_nalgebra_methods(ty, &float_type.name, *is_static)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn impl_nalgebra_methods(
+        &self,
+        ty: &StorageTypeName,
+        float_type: &Type,
+        is_static: bool,
+    ) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let vector_type = &ty.name;
+        let generics = &ty.generics;
+        let constructors = if is_static {
+            quote! {
+                impl<const D: #dimension_type, const N: usize> From<[#quantity_type<#float_type, D>; N]>
+                    for #quantity_type<#vector_type, D>
+                {
+                    fn from(components: [#quantity_type<#float_type, D>; N]) -> Self {
+                        Self(<#vector_type>::from(components.map(|x| x.value_unchecked())))
+                    }
+                }
+
+                impl<const D: #dimension_type> #quantity_type<::nalgebra::SVector<#float_type, 3>, D> {
+                    pub fn cross<const DR: #dimension_type>(
+                        &self,
+                        rhs: &#quantity_type<::nalgebra::SVector<#float_type, 3>, DR>,
+                    ) -> #quantity_type<::nalgebra::SVector<#float_type, 3>, { D.add(DR) }> {
+                        #quantity_type(self.0.cross(&rhs.0))
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl<const D: #dimension_type, const N: usize> From<[#quantity_type<#float_type, D>; N]>
+                    for #quantity_type<#vector_type, D>
+                {
+                    fn from(components: [#quantity_type<#float_type, D>; N]) -> Self {
+                        Self::from_slice(&components)
+                    }
+                }
+
+                impl<const D: #dimension_type> #quantity_type<#vector_type, D> {
+                    pub fn from_slice(components: &[#quantity_type<#float_type, D>]) -> Self {
+                        Self(<#vector_type>::from_iterator(
+                            components.len(),
+                            components.iter().map(|x| x.value_unchecked()),
+                        ))
+                    }
+
+                    /// Panics unless both vectors have three components.
+                    pub fn cross<const DR: #dimension_type>(
+                        &self,
+                        rhs: &#quantity_type<#vector_type, DR>,
+                    ) -> #quantity_type<#vector_type, { D.add(DR) }> {
+                        #quantity_type(self.0.cross(&rhs.0))
+                    }
+                }
+            }
+        };
+        quote! {
+            impl<const D: #dimension_type, #(#generics),*> #quantity_type<#vector_type, D> {
+                /// Returns the component at the given index, or `None`
+                /// if the index is out of bounds.
+                pub fn get(&self, i: usize) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.get(i).map(|x| #quantity_type(*x))
+                }
+
+                /// Panics if the index is out of bounds.
+                pub fn set(&mut self, i: usize, value: #quantity_type<#float_type, D>) {
+                    self.0[i] = value.value_unchecked();
+                }
+
+                pub fn iter(&self) -> impl Iterator<Item = #quantity_type<#float_type, D>> + '_ {
+                    self.0.iter().map(|x| #quantity_type(*x))
+                }
+            }
+
+            #constructors
+        }
+    }
+}
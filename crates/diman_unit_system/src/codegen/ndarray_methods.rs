@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use syn::Type;
+
+use super::{storage_types::StorageKind, Codegen};
+
+impl Codegen {
+    pub fn gen_ndarray_methods(&self) -> TokenStream {
+        self.storage_type_names()
+            .iter()
+            .filter_map(|ty| match &ty.kind {
+                StorageKind::Ndarray(float_type) => {
+                    Some(self.impl_ndarray_methods(&ty.name, &float_type.name))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn impl_ndarray_methods(&self, array_type: &Type, float_type: &Type) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<const D: #dimension_type, Ix: ::ndarray::Dimension> #quantity_type<#array_type, D> {
+                /// Creates an array of the given shape, filled with `value`.
+                pub fn from_elem<Sh: ::ndarray::ShapeBuilder<Dim = Ix>>(
+                    shape: Sh,
+                    value: #quantity_type<#float_type, D>,
+                ) -> Self {
+                    Self(<#array_type>::from_elem(shape, value.value_unchecked()))
+                }
+
+                pub fn shape(&self) -> &[usize] {
+                    self.0.shape()
+                }
+
+                /// Returns the element at the given index, or `None`
+                /// if the index is out of bounds.
+                pub fn get<I: ::ndarray::NdIndex<Ix>>(
+                    &self,
+                    index: I,
+                ) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.get(index).map(|x| #quantity_type(*x))
+                }
+
+                /// Panics if the index is out of bounds.
+                pub fn set<I: ::ndarray::NdIndex<Ix>>(
+                    &mut self,
+                    index: I,
+                    value: #quantity_type<#float_type, D>,
+                ) {
+                    self.0[index] = value.value_unchecked();
+                }
+
+                /// Iterates over the elements in logical order.
+                pub fn iter(&self) -> impl Iterator<Item = #quantity_type<#float_type, D>> + '_ {
+                    self.0.iter().map(|x| #quantity_type(*x))
+                }
+
+                pub fn sum(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.sum())
+                }
+
+                /// Returns `None` if the array is empty.
+                pub fn mean(&self) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.mean().map(#quantity_type)
+                }
+            }
+        }
+    }
+}
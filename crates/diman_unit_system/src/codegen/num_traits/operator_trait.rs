@@ -1,7 +1,9 @@
-use super::super::Codegen;
+use super::super::{
+    storage_types::{StorageKind, StorageTypeName},
+    Codegen,
+};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::Type;
 
 #[derive(Clone, Copy)]
 pub enum Trait {
@@ -88,15 +90,45 @@ impl Trait {
 
 enum StorageType {
     Generic,
-    Concrete(Type),
+    Concrete(Box<StorageTypeName>),
+}
+
+impl StorageType {
+    fn concrete(ty: &StorageTypeName) -> Self {
+        StorageType::Concrete(Box::new(ty.clone()))
+    }
+
+    fn generics(&self) -> &[TokenStream] {
+        match self {
+            StorageType::Generic => &[],
+            StorageType::Concrete(ty) => &ty.generics,
+        }
+    }
 }
 
 enum QuantityType {
     Quantity,
     Dimensionless,
+    Point,
     Storage,
 }
 
+impl QuantityType {
+    /// Whether the operand is a quantity-like type, i.e.
+    /// whether it wraps its storage type.
+    fn is_quantity_like(&self) -> bool {
+        matches!(
+            self,
+            QuantityType::Quantity | QuantityType::Dimensionless | QuantityType::Point
+        )
+    }
+
+    /// Whether the operand has a generic dimension.
+    fn has_generic_dimension(&self) -> bool {
+        matches!(self, QuantityType::Quantity | QuantityType::Point)
+    }
+}
+
 enum ReferenceType {
     Value,
     Reference,
@@ -129,10 +161,7 @@ impl Operand {
     }
 
     fn is_storage(&self) -> bool {
-        !matches!(
-            self.type_,
-            QuantityType::Quantity | QuantityType::Dimensionless
-        )
+        !self.type_.is_quantity_like()
     }
 }
 
@@ -153,13 +182,25 @@ impl OutputQuantityDimension {
 struct OutputQuantity {
     storage: TokenStream,
     dimension: OutputQuantityDimension,
+    is_point: bool,
 }
 
 impl OutputQuantity {
+    fn type_name(&self, quantity_type: &Ident) -> TokenStream {
+        if self.is_point {
+            quote! { Point }
+        } else {
+            quote! { #quantity_type }
+        }
+    }
+
     fn output_type_def(&self, quantity_type: &Ident) -> TokenStream {
-        let OutputQuantity { storage, dimension } = self;
+        let type_name = self.type_name(quantity_type);
+        let OutputQuantity {
+            storage, dimension, ..
+        } = self;
         let dimension = dimension.unwrap();
-        let out = quote! { type Output = #quantity_type < #storage, #dimension >; };
+        let out = quote! { type Output = #type_name < #storage, #dimension >; };
         out
     }
 
@@ -233,13 +274,7 @@ impl OperatorTrait {
     fn different_storage_types_allowed(&self) -> bool {
         // This restriction could be restricted in principle, in practice however,
         // if I am too lose here, I run into duplicate trait impls
-        matches!(
-            self.lhs.type_,
-            QuantityType::Quantity | QuantityType::Dimensionless
-        ) && matches!(
-            self.rhs.type_,
-            QuantityType::Quantity | QuantityType::Dimensionless
-        )
+        self.lhs.type_.is_quantity_like() && self.rhs.type_.is_quantity_like()
     }
 
     /// The two names of the generic dimension types on LHS and RHS
@@ -247,18 +282,20 @@ impl OperatorTrait {
     /// operand on a side is either a storage type or a dimensionless
     /// quantity, it will not have a named dimension.
     fn dimension_types(&self) -> (Option<TokenStream>, Option<TokenStream>) {
-        use QuantityType::*;
-        match (&self.lhs.type_, &self.rhs.type_) {
-            (Quantity, Quantity) => {
+        match (
+            self.lhs.type_.has_generic_dimension(),
+            self.rhs.type_.has_generic_dimension(),
+        ) {
+            (true, true) => {
                 if self.different_dimensions_allowed() {
                     (Some(quote! { DL }), Some(quote! { DR }))
                 } else {
                     (Some(quote! { D }), Some(quote! { D }))
                 }
             }
-            (Quantity, _) => (Some(quote! { D }), None),
-            (_, Quantity) => (None, Some(quote! { D })),
-            _ => (None, None),
+            (true, false) => (Some(quote! { D }), None),
+            (false, true) => (None, Some(quote! { D })),
+            (false, false) => (None, None),
         }
     }
 
@@ -276,9 +313,18 @@ impl OperatorTrait {
                     (quote! { S }, quote! { S })
                 }
             }
-            (Concrete(ty), Generic) => (quote! {#ty}, quote! {S}),
-            (Generic, Concrete(ty)) => (quote! {S}, quote! {#ty}),
-            (Concrete(tyl), Concrete(tyr)) => (quote! {#tyl}, quote! {#tyr}),
+            (Concrete(ty), Generic) => {
+                let ty = &ty.name;
+                (quote! {#ty}, quote! {S})
+            }
+            (Generic, Concrete(ty)) => {
+                let ty = &ty.name;
+                (quote! {S}, quote! {#ty})
+            }
+            (Concrete(tyl), Concrete(tyr)) => {
+                let (tyl, tyr) = (&tyl.name, &tyr.name);
+                (quote! {#tyl}, quote! {#tyr})
+            }
         }
     }
 
@@ -315,9 +361,30 @@ impl OperatorTrait {
         {
             types.push(rhs_storage);
         }
+        types.extend(self.lhs.storage.generics().iter().cloned());
+        // Make sure we don't declare the generics of a concrete storage type twice
+        if !self.same_concrete_storage_types() {
+            types.extend(self.rhs.storage.generics().iter().cloned());
+        }
         types
     }
 
+    fn same_concrete_storage_types(&self) -> bool {
+        match (&self.lhs.storage, &self.rhs.storage) {
+            (StorageType::Concrete(lhs), StorageType::Concrete(rhs)) => lhs.name == rhs.name,
+            _ => false,
+        }
+    }
+
+    /// Whether one of the operands is a reference to a storage type
+    /// which is not `Copy`, which the trait function cannot move out of.
+    fn borrows_non_copy_storage(&self) -> bool {
+        [&self.lhs, &self.rhs].into_iter().any(|operand| {
+            operand.reference.is_ref()
+                && matches!(&operand.storage, StorageType::Concrete(ty) if !ty.is_copy)
+        })
+    }
+
     /// Generates all the generics, i.e. the <...> in `impl<...> Trait for`.
     /// Adds the appropriate amount of required lifetimes, const generics and
     /// generic storage types for the impl.
@@ -359,6 +426,9 @@ impl OperatorTrait {
             QuantityType::Dimensionless => {
                 quote_spanned! {span=>#quantity_type < #storage, { #dimension_type :: none() } >}
             }
+            QuantityType::Point => {
+                quote_spanned! {span=> Point < #storage, #dimension > }
+            }
             QuantityType::Storage => quote_spanned! {span=>#storage},
         };
         quote_spanned! {span=>#ref_sign #type_name}
@@ -371,13 +441,23 @@ impl OperatorTrait {
     ///    receive a &Quantity on the LHS/RHS respectively.
     /// 3. If necessary, a bound on the const generic expression for
     ///    mul/div-type traits, where a new dimension is created.
+    /// 4. A `QuantityStorage` bound for a generic storage type on the RHS of
+    ///    mul/div-type traits. Without it, the impl would overlap with the
+    ///    ones for quantities and units on the RHS.
+    ///
+    /// The bounds in 1. and 2. are also added for concrete storage types
+    /// with generic parameters (such as statically sized vectors),
+    /// which do not implement the trait for every choice of parameters.
     fn trait_bounds(
         &self,
         quantity_type: &Ident,
         output_type: &Option<OutputQuantity>,
+        storage_trait: &TokenStream,
     ) -> TokenStream {
         let storage_bounds = if matches!(self.lhs.storage, StorageType::Generic)
             || matches!(self.rhs.storage, StorageType::Generic)
+            || !self.lhs.storage.generics().is_empty()
+            || !self.rhs.storage.generics().is_empty()
         {
             let (lhs_storage, rhs_storage) = self.storage_types();
             let trait_name = self.name.name();
@@ -404,9 +484,19 @@ impl OperatorTrait {
             .as_ref()
             .map(|output_type| output_type.generic_const_bound(quantity_type))
             .unwrap_or_default();
+        let storage_trait_bound = if self.rhs.is_storage()
+            && matches!(self.rhs.storage, StorageType::Generic)
+            && self.different_dimensions_allowed()
+        {
+            let rhs_storage = self.storage_types().1;
+            quote! { #rhs_storage: #storage_trait, }
+        } else {
+            quote! {}
+        };
         quote! {
             #storage_bounds
             #generic_const_bound
+            #storage_trait_bound
         }
     }
 
@@ -416,8 +506,12 @@ impl OperatorTrait {
         let (lhs, rhs) = self.storage_types();
         if let StorageType::Concrete(lhs_ty) = &self.lhs.storage {
             if let StorageType::Concrete(rhs_ty) = &self.rhs.storage {
-                assert_eq!(lhs_ty, rhs_ty);
-                return quote! { #lhs_ty };
+                // Types with generic parameters get a bound on the trait,
+                // which the output type then needs to be expressed in.
+                if lhs_ty.name == rhs_ty.name && lhs_ty.generics.is_empty() {
+                    let lhs_ty = &lhs_ty.name;
+                    return quote! { #lhs_ty };
+                }
             }
         }
         quote! { < #lhs as #trait_name<#rhs> >::Output }
@@ -444,10 +538,23 @@ impl OperatorTrait {
             (Dimensionless, Storage) | (Storage, Dimensionless) => {
                 New(quote_spanned! {span=> { #dimension_type :: none() } })
             }
+            (Point, Quantity) | (Quantity, Point) | (Point, Point) => existing,
             _ => unreachable!(),
         }
     }
 
+    /// Whether the output of the trait function is a point.
+    /// Adding a quantity to a point or subtracting it from a point
+    /// results in a point, whereas the difference of two points is
+    /// a quantity.
+    fn output_is_point(&self) -> bool {
+        use QuantityType::*;
+        matches!(
+            (&self.lhs.type_, &self.rhs.type_),
+            (Point, Quantity) | (Quantity, Point)
+        )
+    }
+
     /// A representation of the output type of the trait function.
     /// If an output type exists (for Add, Sub, Mul and Div), it is
     /// always a quantity and is defined by its storage type and its dimension.
@@ -458,6 +565,7 @@ impl OperatorTrait {
             Some(OutputQuantity {
                 storage: self.output_quantity_storage(),
                 dimension: self.output_quantity_dimension(dimension_type),
+                is_point: self.output_is_point(),
             })
         }
     }
@@ -476,13 +584,15 @@ impl OperatorTrait {
         quantity_type: &Ident,
         output_type: &Option<OutputQuantity>,
     ) -> TokenStream {
-        let lhs = match self.lhs.type_ {
-            QuantityType::Quantity | QuantityType::Dimensionless => quote! { self.0 },
-            QuantityType::Storage => quote! { self },
+        let lhs = if self.lhs.type_.is_quantity_like() {
+            quote! { self.0 }
+        } else {
+            quote! { self }
         };
-        let rhs = match self.rhs.type_ {
-            QuantityType::Quantity | QuantityType::Dimensionless => quote! { rhs.0 },
-            QuantityType::Storage => quote! { rhs },
+        let rhs = if self.rhs.type_.is_quantity_like() {
+            quote! { rhs.0 }
+        } else {
+            quote! { rhs }
         };
         let fn_name = self.name.fn_name();
         let deref_or_ref = if self.rhs_takes_ref() {
@@ -497,9 +607,22 @@ impl OperatorTrait {
         } else {
             lhs
         };
-        let result = quote! { #lhs.#fn_name(#deref_or_ref #rhs) };
-        if output_type.is_some() {
-            quote! { #quantity_type ( #result ) }
+        // Comparisons call the trait function explicitly, since some storage
+        // types (such as nalgebra matrices) have inherent methods of the same name.
+        let result = if self.rhs_takes_ref() {
+            let trait_name = self.name.name();
+            let lhs = if self.lhs.type_.is_quantity_like() {
+                quote! { &#lhs }
+            } else {
+                lhs
+            };
+            quote! { #trait_name::#fn_name(#lhs, #deref_or_ref #rhs) }
+        } else {
+            quote! { #lhs.#fn_name(#deref_or_ref #rhs) }
+        };
+        if let Some(output_type) = output_type {
+            let type_name = output_type.type_name(quantity_type);
+            quote! { #type_name ( #result ) }
         } else {
             result
         }
@@ -580,6 +703,31 @@ impl Codegen {
             // for all A and B that implement it automatically, so we add no
             // &Quantity / &Quantity impl here.
         }
+        for t in [Add, Sub] {
+            add_trait!(traits, t, (Point, Generic), (Quantity, Generic));
+            add_trait!(traits, t, (Point, Generic), (&Quantity, Generic));
+            add_trait!(traits, t, (&Point, Generic), (Quantity, Generic));
+            add_trait!(traits, t, (&Point, Generic), (&Quantity, Generic));
+        }
+        add_trait!(traits, Add, (Quantity, Generic), (Point, Generic));
+        add_trait!(traits, Add, (Quantity, Generic), (&Point, Generic));
+        add_trait!(traits, Add, (&Quantity, Generic), (Point, Generic));
+        add_trait!(traits, Add, (&Quantity, Generic), (&Point, Generic));
+        add_trait!(traits, Sub, (Point, Generic), (Point, Generic));
+        add_trait!(traits, Sub, (Point, Generic), (&Point, Generic));
+        add_trait!(traits, Sub, (&Point, Generic), (Point, Generic));
+        add_trait!(traits, Sub, (&Point, Generic), (&Point, Generic));
+        for t in [AddAssign, SubAssign] {
+            add_trait!(traits, t, (Point, Generic), (Quantity, Generic));
+            add_trait!(traits, t, (Point, Generic), (&Quantity, Generic));
+            add_trait!(traits, t, (&mut Point, Generic), (Quantity, Generic));
+            add_trait!(traits, t, (&mut Point, Generic), (&Quantity, Generic));
+        }
+        for t in [PartialOrd, PartialEq] {
+            add_trait!(traits, t, (Point, Generic), (Point, Generic));
+            add_trait!(traits, t, (Point, Generic), (&Point, Generic));
+            add_trait!(traits, t, (&Point, Generic), (Point, Generic));
+        }
         for t in [Add, Sub] {
             add_trait!(traits, t, (Dimensionless, Generic), (Storage, Generic));
             add_trait!(traits, t, (Dimensionless, Generic), (&Storage, Generic));
@@ -592,42 +740,85 @@ impl Codegen {
             add_trait!(traits, t, (&mut Dimensionless, Generic), (Storage, Generic));
             add_trait!(traits, t, (&mut Dimensionless, Generic), (&Storage, Generic));
         }
+        for t in [Mul, Div] {
+            add_trait!(traits, t, (Quantity, Generic), (Storage, Generic));
+            add_trait!(traits, t, (&Quantity, Generic), (Storage, Generic));
+            add_trait!(traits, t, (Quantity, Generic), (&Storage, Generic));
+            add_trait!(traits, t, (&Quantity, Generic), (&Storage, Generic));
+        }
+        for t in [MulAssign, DivAssign] {
+            add_trait!(traits, t, (Quantity, Generic), (Storage, Generic));
+            add_trait!(traits, t, (Quantity, Generic), (&Storage, Generic));
+        }
+        // Impls with the storage type on the left-hand side cannot be
+        // generic over the storage type due to the orphan rules.
+        // Comparisons with a storage type on the right-hand side could,
+        // but the generic impl causes an ICE in the trait solver when
+        // comparing two quantities of an inferred float type.
         for ty in self.storage_type_names() {
             for t in [Add, Sub] {
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (Dimensionless, Concrete(ty.clone())));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (&Dimensionless, Concrete(ty.clone())));
-                add_trait!(traits, t, (&Storage, Concrete(ty.clone())), (Dimensionless, Concrete(ty.clone())));
-                add_trait!(traits, t, (&Storage, Concrete(ty.clone())), (&Dimensionless, Concrete(ty.clone())));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (Dimensionless, StorageType::concrete(&ty)));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (&Dimensionless, StorageType::concrete(&ty)));
+                add_trait!(traits, t, (&Storage, StorageType::concrete(&ty)), (Dimensionless, StorageType::concrete(&ty)));
+                add_trait!(traits, t, (&Storage, StorageType::concrete(&ty)), (&Dimensionless, StorageType::concrete(&ty)));
             }
             for t in [AddAssign, SubAssign] {
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (Dimensionless, Concrete(ty.clone())));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (&Dimensionless, Concrete(ty.clone())));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (Dimensionless, StorageType::concrete(&ty)));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (&Dimensionless, StorageType::concrete(&ty)));
                 // Primitive storage types like f32 dont implement &mut f32: AddAssign<f32>, so
                 // we won't either.
             }
             for t in [Mul, Div] {
-                add_trait!(traits, t, (Quantity, Generic), (Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (&Quantity, Generic), (Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (Quantity, Generic), (&Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (&Quantity, Generic), (&Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (Quantity, Generic));
-                add_trait!(traits, t, (&Storage, Concrete(ty.clone())), (Quantity, Generic));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (&Quantity, Generic));
-                add_trait!(traits, t, (&Storage, Concrete(ty.clone())), (&Quantity, Generic));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (Quantity, Generic));
+                add_trait!(traits, t, (&Storage, StorageType::concrete(&ty)), (Quantity, Generic));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (&Quantity, Generic));
+                add_trait!(traits, t, (&Storage, StorageType::concrete(&ty)), (&Quantity, Generic));
             }
-            for t in [MulAssign, DivAssign] {
-                add_trait!(traits, t, (Quantity, Concrete(ty.clone())), (Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (Quantity, Concrete(ty.clone())), (&Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (Dimensionless, Concrete(ty.clone())));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (&Dimensionless, Concrete(ty.clone())));
-                // Primitive storage types like f32 dont implement &mut f32: MulAssign<f32>, so
-                // we won't either.
+            if ty.has_product {
+                for t in [MulAssign, DivAssign] {
+                    add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (Dimensionless, StorageType::concrete(&ty)));
+                    add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (&Dimensionless, StorageType::concrete(&ty)));
+                    // Primitive storage types like f32 dont implement &mut f32: MulAssign<f32>, so
+                    // we won't either.
+                }
             }
             for t in [PartialEq, PartialOrd] {
-                add_trait!(traits, t, (Dimensionless, Generic), (Storage, Concrete(ty.clone())));
-                add_trait!(traits, t, (Storage, Concrete(ty.clone())), (Dimensionless, Generic));
+                add_trait!(traits, t, (Dimensionless, Generic), (Storage, StorageType::concrete(&ty)));
+                add_trait!(traits, t, (Storage, StorageType::concrete(&ty)), (Dimensionless, Generic));
+            }
+        }
+        // Quantities of composite storage types such as vectors can be multiplied and divided by
+        // quantities of their component type. These impls cannot be generic either, since they
+        // would overlap with the ones above and break type inference for float literals.
+        let types = self.storage_type_names();
+        for composite in &types {
+            let Some(component) = composite.component_type() else {
+                continue;
+            };
+            let Some(float) = types.iter().find(|ty| ty.name == *component) else {
+                continue;
+            };
+            // glam does not implement dividing a matrix by a scalar or vice versa, and nalgebra
+            // does not implement dividing a scalar by a vector.
+            let (composite_traits, float_traits): (&[Trait], &[Trait]) = match composite.kind {
+                StorageKind::Matrix { .. } => (&[Mul], &[Mul]),
+                StorageKind::NalgebraVector { .. } => (&[Mul, Div], &[Mul]),
+                _ => (&[Mul, Div], &[Mul, Div]),
+            };
+            for &t in composite_traits {
+                add_trait!(traits, t, (Quantity, StorageType::concrete(composite)), (Storage, StorageType::concrete(float)));
+                add_trait!(traits, t, (&Quantity, StorageType::concrete(composite)), (Storage, StorageType::concrete(float)));
+                add_trait!(traits, t, (Quantity, StorageType::concrete(composite)), (&Storage, StorageType::concrete(float)));
+                add_trait!(traits, t, (&Quantity, StorageType::concrete(composite)), (&Storage, StorageType::concrete(float)));
+            }
+            for &t in float_traits {
+                add_trait!(traits, t, (Quantity, StorageType::concrete(float)), (Storage, StorageType::concrete(composite)));
+                add_trait!(traits, t, (&Quantity, StorageType::concrete(float)), (Storage, StorageType::concrete(composite)));
+                add_trait!(traits, t, (Quantity, StorageType::concrete(float)), (&Storage, StorageType::concrete(composite)));
+                add_trait!(traits, t, (&Quantity, StorageType::concrete(float)), (&Storage, StorageType::concrete(composite)));
             }
         }
+        traits.retain(|t| !t.borrows_non_copy_storage());
         traits.into_iter()
     }
 
@@ -648,7 +839,10 @@ impl Codegen {
             .as_ref()
             .map(|output_type| output_type.output_type_def(&self.defs.quantity_type));
 
-        let trait_bounds = numeric_trait.trait_bounds(&self.defs.quantity_type, &output_type);
+        let path_prefix = self.caller_type.path_prefix();
+        let storage_trait = quote! { #path_prefix::storage::QuantityStorage };
+        let trait_bounds =
+            numeric_trait.trait_bounds(&self.defs.quantity_type, &output_type, &storage_trait);
         let fn_return_expr = numeric_trait.fn_return_expr(&self.defs.quantity_type, &output_type);
         quote! {
             impl #impl_generics #trait_name::<#rhs> for #lhs
@@ -0,0 +1,75 @@
+use proc_macro2::TokenStream;
+
+use super::Codegen;
+
+use quote::{quote, quote_spanned};
+
+impl Codegen {
+    pub(crate) fn gen_point(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let span = self.defs.quantity_type.span();
+        let functions = self.point_functions();
+        quote_spanned! {span =>
+            /// An absolute point on the scale of a dimension, such as an
+            /// absolute temperature, a position or a timestamp.
+            /// The difference of two points is a quantity, and
+            /// quantities can be added to or subtracted from points,
+            /// but points cannot be added to each other.
+            #[derive(Clone, Copy, Eq, Default)]
+            #[repr(transparent)]
+            pub struct Point<S, const D: #dimension_type>(pub(crate) S);
+            #functions
+        }
+    }
+
+    fn point_functions(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<S, const D: #dimension_type> Point<S, D> {
+                /// Create the point that is displaced from the origin of the
+                /// underlying base units by the given quantity.
+                pub fn from_origin(displacement: #quantity_type<S, D>) -> Self {
+                    Self(displacement.0)
+                }
+
+                /// Return the displacement of the point from the origin of the
+                /// underlying base units.
+                pub fn displacement_from_origin(self) -> #quantity_type<S, D> {
+                    #quantity_type(self.0)
+                }
+
+                /// Return the value of a point, regardless of whether
+                /// it is dimensionless or not. Use this carefully, since the
+                /// result depends on the underlying base units
+                pub fn value_unchecked(self) -> S {
+                    self.0
+                }
+
+                /// Return a reference to the value of a point, regardless of whether
+                /// it is dimensionless or not. Use this carefully, since the
+                /// result depends on the underlying base units
+                pub fn value_unchecked_ref(&self) -> &S {
+                    &self.0
+                }
+
+                /// Create a new point for the dimension with a given value.
+                /// Use carefully, since the constructed point depends on the
+                /// used base units.
+                pub const fn new_unchecked(s: S) -> Self {
+                    Self(s)
+                }
+
+                pub fn value_in<U: unit_type::UnitConversion<S>>(self, unit: U) -> S {
+                    unit.convert_from_base(self.value_unchecked())
+                }
+            }
+
+            impl<const D: #dimension_type, S: core::fmt::Display> core::fmt::Debug for Point<S, D> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #quantity_type::<&S, D>(&self.0).fmt(f)
+                }
+            }
+        }
+    }
+}
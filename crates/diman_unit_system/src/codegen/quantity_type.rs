@@ -18,11 +18,10 @@ impl Codegen {
         }
     }
 
-    fn quantity_functions(&self) -> TokenStream {
-        let dimension_type = &self.defs.dimension_type;
+    fn dimensionless_quantity_functions(&self, dimension: &TokenStream) -> TokenStream {
         let quantity_type = &self.defs.quantity_type;
         quote! {
-            impl<S> #quantity_type<S, { #dimension_type::none() }> {
+            impl<S> #quantity_type<S, { #dimension }> {
                 /// Return the stored value of a dimensionless quantity.
                 pub fn value(self) -> S {
                     self.0
@@ -34,6 +33,27 @@ impl Codegen {
                 }
             }
 
+            impl<S> core::ops::Deref for #quantity_type<S, { #dimension }> {
+                type Target = S;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+        }
+    }
+
+    fn quantity_functions(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let dimensionless_functions: TokenStream = self
+            .dimensionless_dimensions()
+            .iter()
+            .map(|dimension| self.dimensionless_quantity_functions(dimension))
+            .collect();
+        quote! {
+            #dimensionless_functions
+
             impl<S, const D: #dimension_type> #quantity_type<S, D> {
                 /// Return the value of a quantity, regardless of whether
                 /// it is dimensionless or not. Use this carefully, since the
@@ -55,22 +75,38 @@ impl Codegen {
                 pub const fn new_unchecked(s: S) -> Self {
                     Self(s)
                 }
+
+                /// Convert the quantity into a different kind with the
+                /// same base dimensions, such as a torque into an energy.
+                pub fn into_kind<const DR: #dimension_type>(self) -> #quantity_type<S, DR>
+                where
+                    #quantity_type<(), { D.cast_kind(DR) }>:,
+                {
+                    #quantity_type(self.0)
+                }
+            }
+
+            impl<const D: #dimension_type, S> #quantity_type<S, D> {
+                pub fn value_in<U: unit_type::UnitConversion<S>>(self, unit: U) -> S {
+                    unit.convert_from_base(self.value_unchecked())
+                }
             }
 
-            impl<const D: #dimension_type, S> #quantity_type<S, D>
+            /// Quantities with totally ordered storage types, such as
+            /// integers, are totally ordered as well. Float quantities
+            /// can be wrapped in `Ordered` instead.
+            impl<const D: #dimension_type, S: core::cmp::Ord> core::cmp::Ord for #quantity_type<S, D>
             where
-                S: core::ops::Div<Magnitude, Output = S> + core::fmt::Debug,
+                Self: PartialOrd,
             {
-                pub fn value_in<A: Into<Magnitude>>(self, a: A) -> S {
-                    self.value_unchecked() / a.into()
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.0.cmp(&other.0)
                 }
             }
 
-            impl<S> core::ops::Deref for #quantity_type<S, { #dimension_type::none() }> {
-                type Target = S;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.0
+            impl<const D: #dimension_type, S: core::hash::Hash> core::hash::Hash for #quantity_type<S, D> {
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.0.hash(state);
                 }
             }
         }
@@ -2,17 +2,36 @@ use quote::quote;
 
 use proc_macro2::TokenStream;
 
-use super::storage_types::FloatType;
+use syn::Type;
+
+use super::storage_types::StorageKind;
 use super::Codegen;
 
 impl Codegen {
     pub fn gen_rand_impl(&self) -> TokenStream {
-        let float_impls: TokenStream = self
-            .float_types()
+        let storage_type_impls: TokenStream = self
+            .storage_type_names()
             .iter()
-            .map(|float_type| self.rand_impl_float(float_type))
+            .map(|ty| match &ty.kind {
+                StorageKind::Float(_) => self.rand_impl_float(&ty.name),
+                StorageKind::Half(_) => self.rand_impl_half(&ty.name),
+                _ => quote! {},
+            })
             .collect();
         let dimension_type = &self.defs.dimension_type;
+        #[cfg(feature = "half")]
+        let half_sampler = quote! {
+            /// Half-precision floats are sampled in `f32` and rounded.
+            /// For half-open ranges, samples which are rounded up to the
+            /// upper bound are rejected.
+            #[derive(Clone, Copy, Debug)]
+            pub struct UniformHalfQuantity<S, const D: #dimension_type> {
+                sampler: UniformFloat<f32>,
+                excluded: Option<S>,
+            }
+        };
+        #[cfg(not(feature = "half"))]
+        let half_sampler = quote! {};
         quote! {
             use ::rand::distributions::uniform::SampleBorrow;
             use ::rand::distributions::uniform::SampleUniform;
@@ -23,14 +42,64 @@ impl Codegen {
             #[derive(Clone, Copy, Debug)]
             pub struct UniformQuantity<S, const D: #dimension_type>(UniformFloat<S>);
 
-            #float_impls
+            #half_sampler
+
+            #storage_type_impls
+        }
+    }
+
+    fn rand_impl_half(&self, half_type: &Type) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<const D: #dimension_type> UniformSampler for UniformHalfQuantity<#half_type, D> {
+                type X = #quantity_type::<#half_type, D>;
+                fn new<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<Self::X> + Sized,
+                    B2: SampleBorrow<Self::X> + Sized,
+                {
+                    UniformHalfQuantity {
+                        sampler: UniformFloat::<f32>::new(
+                            low.borrow().0.to_f32(),
+                            high.borrow().0.to_f32(),
+                        ),
+                        excluded: Some(high.borrow().0),
+                    }
+                }
+                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<Self::X> + Sized,
+                    B2: SampleBorrow<Self::X> + Sized,
+                {
+                    UniformHalfQuantity {
+                        sampler: UniformFloat::<f32>::new_inclusive(
+                            low.borrow().0.to_f32(),
+                            high.borrow().0.to_f32(),
+                        ),
+                        excluded: None,
+                    }
+                }
+
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                    loop {
+                        let value = <#half_type>::from_f32(self.sampler.sample(rng));
+                        if Some(value) != self.excluded {
+                            return #quantity_type::<#half_type, D>(value);
+                        }
+                    }
+                }
+            }
+
+            impl<const D: #dimension_type> SampleUniform for #quantity_type<#half_type, D> {
+                type Sampler = UniformHalfQuantity<#half_type, D>;
+            }
         }
     }
 
-    fn rand_impl_float(&self, float_type: &FloatType) -> TokenStream {
+    fn rand_impl_float(&self, float_type: &Type) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
-        let float_type = &float_type.name;
         quote! {
             impl<const D: #dimension_type> UniformSampler for UniformQuantity<#float_type, D> {
                 type X = #quantity_type::<#float_type, D>;
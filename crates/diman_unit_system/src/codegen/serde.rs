@@ -1,7 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::storage_types::{FloatType, VectorType};
+use syn::Type;
+
+use super::storage_types::StorageKind;
 
 use super::join;
 
@@ -9,17 +11,34 @@ use super::Codegen;
 
 impl Codegen {
     pub fn gen_serde_impl(&self) -> TokenStream {
-        join([
-            self.serde_helpers_impl(),
-            self.serde_floats_impl(),
-            self.serde_vectors_impl(),
-        ])
+        let storage_type_impls = self
+            .storage_type_names()
+            .iter()
+            .map(|ty| match &ty.kind {
+                StorageKind::Float(_) => self.serde_float_impl(&ty.name),
+                StorageKind::Half(_) => self.serde_half_impl(&ty.name),
+                StorageKind::Vector {
+                    float_type,
+                    num_dims,
+                } => self.serde_vector_impl(&ty.name, &float_type.name, *num_dims),
+                StorageKind::Measurement(float_type) => {
+                    self.serde_measurement_impl(&ty.name, &float_type.name)
+                }
+                _ => quote! {},
+            })
+            .collect();
+        join([self.serde_helpers_impl(), storage_type_impls])
     }
 
     fn serde_helpers_impl(&self) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
-        let all_units_storage = self.runtime_unit_storage(self.defs.units.iter());
+        let all_units_storage = self.runtime_unit_storage(
+            self.defs
+                .units
+                .iter()
+                .filter(|unit| unit.logarithmic.is_none()),
+        );
 
         quote! {
             use core::marker::PhantomData;
@@ -35,7 +54,9 @@ impl Codegen {
                 numerical_value: S,
                 dimension: #dimension_type,
             ) -> Result<#quantity_type<S, D>, E> {
-                if dimension == D {
+                // Kinds are not compared, since a string such as `1.0 N m`
+                // does not tell a torque apart from an energy.
+                if dimension.clone().sub(D).is_none() {
                     Ok(#quantity_type::<S, D>(numerical_value))
                 } else {
                     Err(E::custom(format!(
@@ -45,18 +66,31 @@ impl Codegen {
                 }
             }
 
-            fn read_unit_str<E: de::Error>(split: SplitWhitespace) -> Result<(#dimension_type, f64), E> {
+            /// Returns the dimension, the factor and the offset of the units
+            /// given in the string.
+            fn read_unit_str<E: de::Error>(split: SplitWhitespace) -> Result<(#dimension_type, f64, f64), E> {
+                let unit_strs: Vec<_> = split.collect();
                 let mut total_dimension = #dimension_type::none();
                 let mut total_factor = 1.0;
-                for unit in split {
-                    let (dimension, factor) = read_single_unit_str(unit)?;
+                let mut total_offset = 0.0;
+                for unit in unit_strs.iter() {
+                    let (dimension, factor, offset) = read_single_unit_str(unit)?;
+                    if offset != 0.0 {
+                        if unit_strs.len() != 1 {
+                            return Err(E::custom(format!(
+                                "unit with an offset cannot be combined with other units: {}",
+                                unit
+                            )));
+                        }
+                        total_offset = offset;
+                    }
                     total_dimension = total_dimension.add(dimension.clone());
                     total_factor *= factor;
                 }
-                Ok((total_dimension, total_factor))
+                Ok((total_dimension, total_factor, total_offset))
             }
 
-            fn read_single_unit_str<E>(unit_str: &str) -> Result<(#dimension_type, f64), E>
+            fn read_single_unit_str<E>(unit_str: &str) -> Result<(#dimension_type, f64, f64), E>
             where
                 E: de::Error,
             {
@@ -78,25 +112,24 @@ impl Codegen {
                 let unit = units
                     .get_unit_by_symbol(unit)
                     .ok_or_else(|| E::custom(format!("unknown unit: {}", &unit)))?;
+                if unit.offset != 0.0 && exponent != 1 {
+                    return Err(E::custom(format!(
+                        "unit with an offset cannot be raised to a power: {}",
+                        unit_str
+                    )));
+                }
                 Ok((
                     unit.dimension.clone().mul(exponent),
                     Exponent::float_pow(Magnitude::from_f64(unit.magnitude), Exponent::from_int(exponent)).into_f64(),
+                    unit.offset,
                 ))
             }
         }
     }
 
-    fn serde_floats_impl(&self) -> TokenStream {
-        self.float_types()
-            .iter()
-            .map(|float_type| self.serde_float_impl(float_type))
-            .collect()
-    }
-
-    fn serde_float_impl(&self, float_type: &FloatType) -> TokenStream {
+    fn serde_float_impl(&self, float_type: &Type) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
-        let float_type = &float_type.name;
         quote! {
             impl<'de, const D: #dimension_type> serde::Deserialize<'de> for #quantity_type<#float_type, D> {
                 fn deserialize<DE>(deserializer: DE) -> Result<#quantity_type<#float_type, D>, DE::Error>
@@ -118,7 +151,7 @@ impl Codegen {
                 where
                     E: de::Error,
                 {
-                    if D == #dimension_type::none() {
+                    if D.is_none() {
                         Ok(#quantity_type::<#float_type, D>(value as #float_type))
                     } else {
                         Err(E::custom(format!(
@@ -131,7 +164,7 @@ impl Codegen {
                 where
                     E: de::Error,
                 {
-                    if D == #dimension_type::none() {
+                    if D.is_none() {
                         Ok(#quantity_type::<#float_type, D>(value as #float_type))
                     } else {
                         Err(E::custom(format!(
@@ -145,7 +178,7 @@ impl Codegen {
                 where
                     E: de::Error,
                 {
-                    if D == #dimension_type::none() {
+                    if D.is_none() {
                         Ok(#quantity_type::<#float_type, D>(value as #float_type))
                     } else {
                         Err(E::custom(format!(
@@ -170,10 +203,10 @@ impl Codegen {
                             &numerical_value_str
                         ))
                     })?;
-                    let (total_dimension, total_factor) = read_unit_str(split)?;
+                    let (total_dimension, total_factor, total_offset) = read_unit_str(split)?;
                     get_quantity_if_dimensions_match::<#float_type, D, E>(
                         value,
-                        numerical_value * (total_factor as #float_type),
+                        numerical_value * (total_factor as #float_type) + (total_offset as #float_type),
                         total_dimension,
                     )
                 }
@@ -190,17 +223,98 @@ impl Codegen {
         }
     }
 
-    fn serde_vectors_impl(&self) -> TokenStream {
-        self.vector_types()
-            .iter()
-            .map(|vector_type| self.serde_vector_impl(vector_type))
-            .collect()
+    /// Half-precision floats are read in `f64` and only rounded once
+    /// the units have been applied, since the factors of the units
+    /// may be out of their range.
+    fn serde_half_impl(&self, half_type: &Type) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<'de, const D: #dimension_type> serde::Deserialize<'de> for #quantity_type<#half_type, D> {
+                fn deserialize<DE>(deserializer: DE) -> Result<#quantity_type<#half_type, D>, DE::Error>
+                where
+                    DE: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_string(QuantityVisitor::<#half_type, D>::default())
+                }
+            }
+
+            impl<'de, const D: #dimension_type> serde::de::Visitor<'de> for QuantityVisitor<#half_type, D> {
+                type Value = #quantity_type<#half_type, D>;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("a numerical value followed by a series of powers of units")
+                }
+
+                fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_f64(value as f64)
+                }
+
+                fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_f64(value as f64)
+                }
+
+                fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    if D.is_none() {
+                        Ok(#quantity_type::<#half_type, D>(<#half_type>::from_f64(value)))
+                    } else {
+                        Err(E::custom(format!(
+                            "dimensionless numerical value given for non-dimensionless quantity: {}",
+                            value
+                        )))
+                    }
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    let value = value.trim();
+                    let mut split = value.split_whitespace();
+                    let numerical_value_str = split
+                        .next()
+                        .ok_or_else(|| E::custom("unable to parse empty string"))?;
+                    let numerical_value = numerical_value_str.parse::<f64>().map_err(|_| {
+                        E::custom(format!(
+                            "unable to parse numerical value {}",
+                            &numerical_value_str
+                        ))
+                    })?;
+                    let (total_dimension, total_factor, total_offset) = read_unit_str(split)?;
+                    get_quantity_if_dimensions_match::<#half_type, D, E>(
+                        value,
+                        <#half_type>::from_f64(numerical_value * total_factor + total_offset),
+                        total_dimension,
+                    )
+                }
+            }
+
+            impl<const D: Dimension> serde::Serialize for #quantity_type<#half_type, D> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(&format!("{:?}", self))
+                }
+            }
+        }
     }
 
-    fn serde_vector_impl(&self, vector_type: &VectorType) -> TokenStream {
-        let float_type = &vector_type.float_type.name;
-        let num_dims = vector_type.num_dims;
-        let vector_type = &vector_type.name;
+    fn serde_vector_impl(
+        &self,
+        vector_type: &Type,
+        float_type: &Type,
+        num_dims: usize,
+    ) -> TokenStream {
         let dimension_type = &self.defs.dimension_type;
         let quantity_type = &self.defs.quantity_type;
         quote! {
@@ -220,6 +334,7 @@ impl Codegen {
                     let num_expected = match #num_dims {
                         2 => "two",
                         3 => "three",
+                        4 => "four",
                         _ => unimplemented!(),
                     };
                     formatter.write_str(&format!("{} numerical values surrounded by () followed by a series of powers of units, e.g. (1.0 2.0) m s^-2", num_expected))
@@ -251,7 +366,10 @@ impl Codegen {
 
                     }
                     let vector = <#vector_type>::from_array(array);
-                    let (total_dimension, total_factor) = read_unit_str(unit_part.split_whitespace())?;
+                    let (total_dimension, total_factor, total_offset) = read_unit_str(unit_part.split_whitespace())?;
+                    if total_offset != 0.0 {
+                        return Err(E::custom("units with an offset are not supported for vector quantities"));
+                    }
                     get_quantity_if_dimensions_match::<#vector_type, D, E>(
                         value,
                         (total_factor as #float_type) * vector,
@@ -273,4 +391,77 @@ impl Codegen {
             }
         }
     }
+
+    fn serde_measurement_impl(&self, measurement_type: &Type, float_type: &Type) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        quote! {
+            impl<'de, const D: #dimension_type> serde::Deserialize<'de> for #quantity_type<#measurement_type, D> {
+                fn deserialize<DE>(deserializer: DE) -> Result<#quantity_type<#measurement_type, D>, DE::Error>
+                where
+                    DE: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_string(QuantityVisitor::<#measurement_type, D>::default())
+                }
+            }
+
+            impl<'de, const D: #dimension_type> serde::de::Visitor<'de> for QuantityVisitor<#measurement_type, D> {
+                type Value = #quantity_type<#measurement_type, D>;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("a measured value followed by a series of powers of units, e.g. 9.81(2) m s^-2 or 9.81 ± 0.02 m s^-2")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    let value = value.trim();
+                    // The uncertainty in `9.81 ± 0.02 m` is separated from the value by
+                    // whitespace, so it is split off from the units manually.
+                    let separator = value.find('±').map(|i| (i, '±'.len_utf8())).or_else(|| value.find("+/-").map(|i| (i, 3)));
+                    let (measurement_str, unit_part) = match separator {
+                        Some((index, len)) => {
+                            let uncertainty_part = value[index + len..].trim_start();
+                            let uncertainty_end = uncertainty_part
+                                .find(char::is_whitespace)
+                                .unwrap_or(uncertainty_part.len());
+                            (
+                                format!("{}±{}", &value[..index], &uncertainty_part[..uncertainty_end]),
+                                &uncertainty_part[uncertainty_end..],
+                            )
+                        }
+                        None => {
+                            let value_end = value.find(char::is_whitespace).unwrap_or(value.len());
+                            (value[..value_end].to_string(), &value[value_end..])
+                        }
+                    };
+                    if measurement_str.is_empty() {
+                        return Err(E::custom("unable to parse empty string"));
+                    }
+                    let measurement = measurement_str.parse::<#measurement_type>().map_err(|e| {
+                        E::custom(format!("{}: {}", e, &measurement_str))
+                    })?;
+                    let (total_dimension, total_factor, total_offset) = read_unit_str(unit_part.split_whitespace())?;
+                    if total_offset != 0.0 {
+                        return Err(E::custom("units with an offset are not supported for measured quantities"));
+                    }
+                    get_quantity_if_dimensions_match::<#measurement_type, D, E>(
+                        value,
+                        measurement * (total_factor as #float_type),
+                        total_dimension,
+                    )
+                }
+            }
+
+            impl<const D: Dimension> serde::Serialize for #quantity_type<#measurement_type, D> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(&format!("{:?}", self))
+                }
+            }
+        }
+    }
 }
@@ -3,11 +3,132 @@ use quote::quote;
 use syn::Type;
 
 use super::Codegen;
+use crate::types::storage_type::VectorStorage;
 
-pub struct VectorType {
+/// The name of a storage type, together with the generic parameters
+/// which impls for it need to declare, such as the length of
+/// statically sized vectors.
+#[derive(Clone)]
+pub struct StorageTypeName {
     pub name: Type,
-    pub float_type: FloatType,
-    pub num_dims: usize,
+    pub generics: Vec<TokenStream>,
+    /// Operators on references are only implemented for storage
+    /// types which are `Copy`.
+    pub is_copy: bool,
+    /// Whether two values of the type can be multiplied with each
+    /// other (componentwise, for vectors), which is not the case
+    /// for `nalgebra` vectors.
+    pub has_product: bool,
+    pub kind: StorageKind,
+}
+
+impl StorageTypeName {
+    fn new(name: TokenStream, kind: StorageKind) -> Self {
+        Self::from_type(syn::parse2(name).unwrap(), kind)
+    }
+
+    fn from_type(name: Type, kind: StorageKind) -> Self {
+        Self {
+            name,
+            generics: vec![],
+            is_copy: true,
+            has_product: true,
+            kind,
+        }
+    }
+
+    /// The type of the components of storage types which are built
+    /// from floats, such as vectors or complex numbers. Quantities
+    /// of these types can be multiplied and divided by quantities of
+    /// the component type.
+    pub fn component_type(&self) -> Option<&Type> {
+        match &self.kind {
+            StorageKind::Vector { float_type, .. }
+            | StorageKind::Matrix { float_type, .. }
+            | StorageKind::NalgebraVector { float_type, .. }
+            | StorageKind::Complex(float_type)
+            | StorageKind::Measurement(float_type)
+            | StorageKind::Interval(float_type)
+            | StorageKind::Dual(float_type)
+            | StorageKind::Ndarray(float_type) => Some(&float_type.name),
+            StorageKind::Custom(vector) => vector.as_ref().map(|vector| &vector.float_type),
+            StorageKind::Float(_)
+            | StorageKind::Half(_)
+            | StorageKind::Integer { .. }
+            | StorageKind::Exact => None,
+        }
+    }
+
+    /// The float type into which the magnitude of a unit is
+    /// converted to scale values of this type. Other storage types
+    /// are scaled by the magnitude directly.
+    pub fn converted_float_type(&self) -> Option<&FloatType> {
+        match &self.kind {
+            StorageKind::Float(float_type)
+            | StorageKind::Vector { float_type, .. }
+            | StorageKind::Complex(float_type)
+            | StorageKind::Measurement(float_type) => Some(float_type),
+            _ => None,
+        }
+    }
+}
+
+/// The family of a storage type. Most methods are implemented
+/// generically over the traits in `diman_lib::storage`, but some
+/// impls have to name the storage type, for example due to the
+/// orphan rules, or depend on its shape.
+#[derive(Clone)]
+pub enum StorageKind {
+    Float(FloatType),
+    /// Half-precision floats of the `half` crate. Their arithmetic
+    /// goes through `f32`, so unlike `f32` and `f64`, they are scaled
+    /// by the magnitude of units directly. Converting the magnitude
+    /// into the type first would round the factors of units such as
+    /// nanometers to zero.
+    Half(HalfType),
+    /// Integer and fixed-point types. Unit conversions are done
+    /// exactly and rounded to the nearest representable value.
+    Integer {
+        signed: bool,
+    },
+    /// Exact decimal and rational types. Unit conversions are never
+    /// rounded and fail if the result is not representable.
+    Exact,
+    /// Vectors of `glam`.
+    Vector {
+        float_type: FloatType,
+        num_dims: usize,
+    },
+    /// Square matrices of `glam`, together with the vector type they
+    /// act on.
+    Matrix {
+        float_type: FloatType,
+        vector_type: Box<Type>,
+        num_dims: usize,
+    },
+    /// Vectors of `nalgebra`, which are either statically sized, with
+    /// the length as a const generic parameter `N`, or dynamically
+    /// sized.
+    NalgebraVector {
+        float_type: FloatType,
+        is_static: bool,
+    },
+    /// Complex numbers via `num-complex`.
+    Complex(FloatType),
+    /// Measured values with a standard uncertainty.
+    Measurement(FloatType),
+    /// Intervals with outward-rounded arithmetic. Unlike other
+    /// float-like types, they are scaled by the magnitude of units
+    /// directly, so that inexact unit factors widen the interval.
+    Interval(FloatType),
+    /// Dual numbers, with the type of the variable as a generic
+    /// parameter `X`.
+    Dual(FloatType),
+    /// Owned arrays of `ndarray`, with the dimensionality of the
+    /// array as a generic parameter `Ix`.
+    Ndarray(FloatType),
+    /// Storage types declared via `storage_type` in the unit system.
+    Custom(Option<VectorStorage>),
 }
 
 #[derive(Clone)]
@@ -20,82 +141,231 @@ pub struct FloatType {
     pub hdf5_type: TokenStream,
 }
 
-pub trait StorageType {
-    /// The name of the type
-    fn name(&self) -> &Type;
-
-    /// For vector types, this represents the underlying storage of a
-    /// single entry in the vector.
-    fn base_storage(&self) -> &FloatType;
-}
-
-impl StorageType for VectorType {
-    fn name(&self) -> &Type {
-        &self.name
-    }
-
-    fn base_storage(&self) -> &FloatType {
-        &self.float_type
-    }
-}
-
-impl StorageType for FloatType {
-    fn name(&self) -> &Type {
-        &self.name
-    }
-
-    fn base_storage(&self) -> &FloatType {
-        self
-    }
+#[derive(Clone)]
+pub struct HalfType {
+    #[cfg(feature = "hdf5")]
+    pub hdf5_type: TokenStream,
 }
 
 impl Codegen {
-    pub fn storage_types(&self) -> impl Iterator<Item = Box<dyn StorageType>> {
+    /// All storage types, those enabled via features as well as those
+    /// declared in the unit system.
+    pub fn storage_type_names(&self) -> Vec<StorageTypeName> {
+        let path_prefix = self.caller_type.path_prefix();
+        let _integer = |name: TokenStream, signed: bool| {
+            StorageTypeName::new(name, StorageKind::Integer { signed })
+        };
+        let _exact = |name: TokenStream| StorageTypeName::new(name, StorageKind::Exact);
+        let _half = |name: TokenStream, half_type: HalfType| {
+            StorageTypeName::new(name, StorageKind::Half(half_type))
+        };
+        let _complex = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName::new(
+                quote! { ::num_complex::Complex<#float> },
+                StorageKind::Complex(float_type),
+            )
+        };
+        let _measurement = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName::new(
+                quote! { #path_prefix::measurement::Measurement<#float> },
+                StorageKind::Measurement(float_type),
+            )
+        };
+        let _interval = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName::new(
+                quote! { #path_prefix::interval::Interval<#float> },
+                StorageKind::Interval(float_type),
+            )
+        };
+        let _svector = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName {
+                generics: vec![quote! { const N: usize }],
+                has_product: false,
+                ..StorageTypeName::new(
+                    quote! { ::nalgebra::SVector<#float, N> },
+                    StorageKind::NalgebraVector {
+                        float_type,
+                        is_static: true,
+                    },
+                )
+            }
+        };
+        let _dvector = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName {
+                is_copy: false,
+                has_product: false,
+                ..StorageTypeName::new(
+                    quote! { ::nalgebra::DVector<#float> },
+                    StorageKind::NalgebraVector {
+                        float_type,
+                        is_static: false,
+                    },
+                )
+            }
+        };
+        let _array = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName {
+                generics: vec![quote! { Ix: ::ndarray::Dimension }],
+                is_copy: false,
+                ..StorageTypeName::new(
+                    quote! { ::ndarray::Array<#float, Ix> },
+                    StorageKind::Ndarray(float_type),
+                )
+            }
+        };
+        let _dual = |float_type: FloatType| {
+            let float = &float_type.name;
+            StorageTypeName {
+                generics: vec![quote! { X }],
+                ..StorageTypeName::new(
+                    quote! { #path_prefix::dual::Dual<#float, X> },
+                    StorageKind::Dual(float_type),
+                )
+            }
+        };
+        let custom_types = self.defs.storage_types.iter().map(|ty| {
+            StorageTypeName::from_type(ty.name.clone(), StorageKind::Custom(ty.vector.clone()))
+        });
+        let types = vec![
+            #[cfg(feature = "i32")]
+            _integer(quote! { i32 }, true),
+            #[cfg(feature = "i64")]
+            _integer(quote! { i64 }, true),
+            #[cfg(feature = "u32")]
+            _integer(quote! { u32 }, false),
+            #[cfg(feature = "u64")]
+            _integer(quote! { u64 }, false),
+            #[cfg(feature = "fixed")]
+            _integer(quote! { ::fixed::types::I16F16 }, true),
+            #[cfg(feature = "fixed")]
+            _integer(quote! { ::fixed::types::I32F32 }, true),
+            #[cfg(feature = "fixed")]
+            _integer(quote! { ::fixed::types::U16F16 }, false),
+            #[cfg(feature = "fixed")]
+            _integer(quote! { ::fixed::types::U32F32 }, false),
+            #[cfg(feature = "rust_decimal")]
+            _exact(quote! { ::rust_decimal::Decimal }),
+            #[cfg(feature = "num-rational")]
+            _exact(quote! { ::num_rational::Ratio<i64> }),
+            #[cfg(feature = "half")]
+            _half(quote! { ::half::f16 }, self.f16_type()),
+            #[cfg(feature = "half")]
+            _half(quote! { ::half::bf16 }, self.bf16_type()),
+            #[cfg(all(feature = "num-complex", feature = "f32"))]
+            _complex(self.f32_type()),
+            #[cfg(all(feature = "num-complex", feature = "f64"))]
+            _complex(self.f64_type()),
+            #[cfg(all(feature = "measurement", feature = "f32"))]
+            _measurement(self.f32_type()),
+            #[cfg(all(feature = "measurement", feature = "f64"))]
+            _measurement(self.f64_type()),
+            #[cfg(all(feature = "interval", feature = "f32"))]
+            _interval(self.f32_type()),
+            #[cfg(all(feature = "interval", feature = "f64"))]
+            _interval(self.f64_type()),
+            #[cfg(all(feature = "nalgebra", feature = "f32"))]
+            _svector(self.f32_type()),
+            #[cfg(all(feature = "nalgebra", feature = "f64"))]
+            _svector(self.f64_type()),
+            #[cfg(all(feature = "nalgebra", feature = "f32"))]
+            _dvector(self.f32_type()),
+            #[cfg(all(feature = "nalgebra", feature = "f64"))]
+            _dvector(self.f64_type()),
+            #[cfg(all(feature = "ndarray", feature = "f32"))]
+            _array(self.f32_type()),
+            #[cfg(all(feature = "ndarray", feature = "f64"))]
+            _array(self.f64_type()),
+            #[cfg(all(feature = "dual", feature = "f32"))]
+            _dual(self.f32_type()),
+            #[cfg(all(feature = "dual", feature = "f64"))]
+            _dual(self.f64_type()),
+        ];
         self.float_types()
             .into_iter()
-            .map(|x| Box::new(x) as Box<dyn StorageType>)
-            .chain(
-                self.vector_types()
-                    .into_iter()
-                    .map(|x| Box::new(x) as Box<dyn StorageType>),
-            )
+            .map(|ty| StorageTypeName::from_type(ty.name.clone(), StorageKind::Float(ty)))
+            .chain(self.glam_types())
+            .chain(types)
+            .chain(custom_types)
+            .collect()
+    }
+
+    /// Whether a storage type of the given family is enabled.
+    pub fn has_storage_kind(&self, is_kind: impl Fn(&StorageKind) -> bool) -> bool {
+        self.storage_type_names().iter().any(|ty| is_kind(&ty.kind))
     }
 
-    pub fn storage_type_names(&self) -> impl Iterator<Item = Type> {
-        self.storage_types().map(|x| x.name().clone())
+    /// Whether generic impls over the trait of a family of storage
+    /// types are needed. This is the case if a storage type of the
+    /// family is enabled, or if storage types are declared in the
+    /// unit system, which may implement the trait.
+    pub fn needs_storage_trait_impls(&self, is_kind: impl Fn(&StorageKind) -> bool) -> bool {
+        self.has_storage_kind(|kind| is_kind(kind) || matches!(kind, StorageKind::Custom(_)))
     }
 
-    pub fn vector_types(&self) -> Vec<VectorType> {
-        // I don't know if this is really the way to construct types
-        let _vec2: Type = syn::parse2(quote! { ::glam::Vec2 }).unwrap();
-        let _dvec2: Type = syn::parse2(quote! { ::glam::DVec2 }).unwrap();
-        let _vec3: Type = syn::parse2(quote! { ::glam::Vec3 }).unwrap();
-        let _dvec3: Type = syn::parse2(quote! { ::glam::DVec3 }).unwrap();
+    fn glam_types(&self) -> Vec<StorageTypeName> {
+        let _vector = |name: TokenStream, float_type: FloatType, num_dims| {
+            StorageTypeName::new(
+                name,
+                StorageKind::Vector {
+                    float_type,
+                    num_dims,
+                },
+            )
+        };
+        let _matrix =
+            |name: TokenStream, vector_type: TokenStream, float_type: FloatType, num_dims| {
+                StorageTypeName {
+                    has_product: false,
+                    ..StorageTypeName::new(
+                        name,
+                        StorageKind::Matrix {
+                            float_type,
+                            vector_type: Box::new(syn::parse2(vector_type).unwrap()),
+                            num_dims,
+                        },
+                    )
+                }
+            };
         vec![
             #[cfg(feature = "glam-vec2")]
-            VectorType {
-                name: _vec2,
-                float_type: self.f32_type(),
-                num_dims: 2,
-            },
+            _vector(quote! { ::glam::Vec2 }, self.f32_type(), 2),
             #[cfg(feature = "glam-dvec2")]
-            VectorType {
-                name: _dvec2,
-                float_type: self.f64_type(),
-                num_dims: 2,
-            },
+            _vector(quote! { ::glam::DVec2 }, self.f64_type(), 2),
             #[cfg(feature = "glam-vec3")]
-            VectorType {
-                name: _vec3,
-                float_type: self.f32_type(),
-                num_dims: 3,
-            },
+            _vector(quote! { ::glam::Vec3 }, self.f32_type(), 3),
             #[cfg(feature = "glam-dvec3")]
-            VectorType {
-                name: _dvec3,
-                float_type: self.f64_type(),
-                num_dims: 3,
-            },
+            _vector(quote! { ::glam::DVec3 }, self.f64_type(), 3),
+            #[cfg(feature = "glam-vec4")]
+            _vector(quote! { ::glam::Vec4 }, self.f32_type(), 4),
+            #[cfg(feature = "glam-dvec4")]
+            _vector(quote! { ::glam::DVec4 }, self.f64_type(), 4),
+            #[cfg(feature = "glam-mat2")]
+            _matrix(
+                quote! { ::glam::Mat2 },
+                quote! { ::glam::Vec2 },
+                self.f32_type(),
+                2,
+            ),
+            #[cfg(feature = "glam-mat3")]
+            _matrix(
+                quote! { ::glam::Mat3 },
+                quote! { ::glam::Vec3 },
+                self.f32_type(),
+                3,
+            ),
+            #[cfg(feature = "glam-dmat3")]
+            _matrix(
+                quote! { ::glam::DMat3 },
+                quote! { ::glam::DVec3 },
+                self.f64_type(),
+                3,
+            ),
         ]
     }
 
@@ -125,7 +395,39 @@ impl Codegen {
         }
     }
 
-    pub fn float_types(&self) -> Vec<FloatType> {
+    #[cfg(feature = "half")]
+    fn f16_type(&self) -> HalfType {
+        HalfType {
+            #[cfg(feature = "hdf5")]
+            hdf5_type: quote! {
+                hdf5::types::TypeDescriptor::Float(hdf5::types::FloatSize::U2)
+            },
+        }
+    }
+
+    /// The `hdf5` crate only describes floats by their size, which
+    /// would store a `bf16` as an `f16`. Instead, it is stored as a
+    /// compound type with a single 16 bit field holding its bit
+    /// pattern.
+    #[cfg(feature = "half")]
+    fn bf16_type(&self) -> HalfType {
+        HalfType {
+            #[cfg(feature = "hdf5")]
+            hdf5_type: quote! {
+                hdf5::types::TypeDescriptor::Compound(hdf5::types::CompoundType {
+                    fields: vec![hdf5::types::CompoundField {
+                        name: "bf16".into(),
+                        ty: hdf5::types::TypeDescriptor::Unsigned(hdf5::types::IntSize::U2),
+                        offset: 0,
+                        index: 0,
+                    }],
+                    size: 2,
+                })
+            },
+        }
+    }
+
+    fn float_types(&self) -> Vec<FloatType> {
         vec![
             #[cfg(feature = "f32")]
             self.f32_type(),
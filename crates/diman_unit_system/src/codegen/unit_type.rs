@@ -2,7 +2,10 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Type;
 
-use super::Codegen;
+use super::{
+    storage_types::{StorageKind, StorageTypeName},
+    Codegen,
+};
 
 impl Codegen {
     pub fn gen_unit_type(&self) -> TokenStream {
@@ -11,7 +14,10 @@ impl Codegen {
         let storage_type_impls = self.gen_unit_trait_impls_for_storage_types();
         quote! {
             pub struct Unit<const D: #dimension_type, const F: Magnitude>;
-            pub struct RuntimeUnit<const D: #dimension_type>(Magnitude);
+            /// A unit whose zero point is shifted with respect to the
+            /// base units, such as degrees Celsius. The offset `O` is
+            /// given in base units.
+            pub struct AffineUnit<const D: #dimension_type, const F: Magnitude, const O: Magnitude>;
             #trait_impls
             #storage_type_impls
         }
@@ -19,26 +25,26 @@ impl Codegen {
 
     fn gen_unit_trait_impls(&self) -> TokenStream {
         quote! {
-            use core::ops::{Mul, Div};
-            // Unit * Unit = RuntimeUnit
+            use core::ops::{Add, Mul, Div, Sub};
+            // Unit * Unit = Unit
             impl<const DL: Dimension, const DR: Dimension, const FL: Magnitude, const FR: Magnitude>
                 Mul<Unit<DR, FR>> for Unit<DL, FL>
-            where RuntimeUnit<{ DL.add(DR) }>:
+            where Unit<{ DL.add(DR) }, { FL.mul(FR) }>:
             {
-                type Output = RuntimeUnit<{ DL.add(DR) }>;
+                type Output = Unit<{ DL.add(DR) }, { FL.mul(FR) }>;
                 fn mul(self, _: Unit<DR, FR>) -> Self::Output {
-                    RuntimeUnit( FL.mul(FR) )
+                    Unit
                 }
             }
 
-            // Unit / Unit = RuntimeUnit
+            // Unit / Unit = Unit
             impl<const DL: Dimension, const DR: Dimension, const FL: Magnitude, const FR: Magnitude>
                 Div<Unit<DR, FR>> for Unit<DL, FL>
-            where RuntimeUnit<{ DL.sub(DR) }>:
+            where Unit<{ DL.sub(DR) }, { FL.div(FR) }>:
             {
-                type Output = RuntimeUnit<{ DL.sub(DR) }>;
+                type Output = Unit<{ DL.sub(DR) }, { FL.div(FR) }>;
                 fn div(self, _: Unit<DR, FR>) -> Self::Output {
-                    RuntimeUnit( FL.div(FR) )
+                    Unit
                 }
             }
 
@@ -101,100 +107,224 @@ impl Codegen {
                 {
                     Quantity(val * F)
                 }
-            }
 
-            // RuntimeUnit * Quantity<S>
-            impl<const DL: Dimension, const DR: Dimension, S> Mul<Quantity<S, DR>>
-                for RuntimeUnit<DL>
-            where
-                S: Mul<Magnitude, Output = S>,
-                Quantity<(), { DL.add(DR) }>:,
-            {
-                type Output = Quantity<S, { DL.add(DR) }>;
-                fn mul(self, x: Quantity<S, DR>) -> Self::Output {
-                    Quantity(x.value_unchecked() * self.0)
+                pub fn new_point<S>(self, val: S) -> Point<S, D>
+                where
+                    S: Mul<Magnitude, Output = S>,
+                {
+                    Point(val * F)
+                }
+
+                /// Like `new`, but returns `None` if the value in base
+                /// units is out of range of the storage type or, for
+                /// integers, not an integer.
+                pub fn new_checked<S>(self, val: S) -> Option<Quantity<S, D>>
+                where
+                    S: ScaleByMagnitude,
+                {
+                    val.checked_scale(F).map(Quantity)
+                }
+
+                /// Like `new`, but wraps around at the boundary of the
+                /// storage type if the value in base units is out of range
+                /// and rounds it to the nearest representable value.
+                pub fn new_wrapping<S>(self, val: S) -> Quantity<S, D>
+                where
+                    S: ScaleByMagnitude,
+                {
+                    Quantity(val.wrapping_scale(F))
+                }
+
+                /// Like `new`, but clamps the value in base units to the
+                /// range of the storage type and rounds it to the nearest
+                /// representable value.
+                pub fn new_saturating<S>(self, val: S) -> Quantity<S, D>
+                where
+                    S: ScaleByMagnitude,
+                {
+                    Quantity(val.saturating_scale(F))
+                }
+
+                /// Like `new`, but returns an error instead of rounding
+                /// if the value in base units is not exactly
+                /// representable by the storage type.
+                pub fn new_exact<S>(self, val: S) -> Result<Quantity<S, D>, InexactConversion>
+                where
+                    S: ScaleExactly,
+                {
+                    val.exact_scale(F).map(Quantity)
+                }
+
+                /// Raises the unit to the integer power `I`.
+                pub fn powi<const I: i32>(self) -> Unit<{ D.mul(I) }, { F.powi(I) }>
+                where
+                    Unit<{ D.mul(I) }, { F.powi(I) }>:,
+                {
+                    Unit
                 }
             }
 
-            // Quantity<S> * RuntimeUnit
-            impl<const DL: Dimension, const DR: Dimension, S> Mul<RuntimeUnit<DR>>
-                for Quantity<S, DL>
-            where
-                S: Mul<Magnitude, Output = S>,
-                Quantity<(), { DL.add(DR) }>:,
-            {
-                type Output = Quantity<S, { DL.add(DR) }>;
-                fn mul(self, unit: RuntimeUnit<DR>) -> Self::Output {
-                    Quantity(self.value_unchecked() * unit.0)
+            impl<const D: Dimension, S: ScaleByMagnitude> Quantity<S, D> {
+                /// Like `value_in`, but returns `None` if the value is
+                /// out of range of the storage type or, for integers,
+                /// not an integer.
+                pub fn value_in_checked<const F: Magnitude>(self, _: Unit<D, F>) -> Option<S> {
+                    self.value_unchecked().checked_scale(F.inv())
+                }
+
+                /// Like `value_in`, but wraps around at the boundary of
+                /// the storage type if the value is out of range and
+                /// rounds it to the nearest representable value.
+                pub fn value_in_wrapping<const F: Magnitude>(self, _: Unit<D, F>) -> S {
+                    self.value_unchecked().wrapping_scale(F.inv())
+                }
+
+                /// Like `value_in`, but clamps the value to the range of
+                /// the storage type and rounds it to the nearest
+                /// representable value.
+                pub fn value_in_saturating<const F: Magnitude>(self, _: Unit<D, F>) -> S {
+                    self.value_unchecked().saturating_scale(F.inv())
                 }
             }
 
-            // RuntimeUnit / Quantity<S>
-            impl<const DL: Dimension, const DR: Dimension, S> Div<Quantity<S, DR>>
-                for RuntimeUnit<DL>
-            where
-                S: Div<Magnitude, Output = S>,
-                Quantity<(), { DL.sub(DR) }>:,
-            {
-                type Output = Quantity<S, { DL.sub(DR) }>;
-                fn div(self, x: Quantity<S, DR>) -> Self::Output {
-                    Quantity(x.value_unchecked() / self.0)
+            impl<const D: Dimension, S: ScaleExactly> Quantity<S, D> {
+                /// Like `value_in`, but returns an error instead of
+                /// rounding if the value is not exactly representable
+                /// by the storage type.
+                pub fn value_in_exact<const F: Magnitude>(
+                    self,
+                    _: Unit<D, F>,
+                ) -> Result<S, InexactConversion> {
+                    self.value_unchecked().exact_scale(F.inv())
                 }
             }
 
-            // Quantity<S> / RuntimeUnit
-            impl<const DL: Dimension, const DR: Dimension, S> Div<RuntimeUnit<DR>>
-                for Quantity<S, DL>
-            where
-                S: Div<Magnitude, Output = S>,
-                Quantity<(), { DL.sub(DR) }>:,
-            {
-                type Output = Quantity<S, { DL.sub(DR) }>;
-                fn div(self, unit: RuntimeUnit<DR>) -> Self::Output {
-                    Quantity(self.value_unchecked() / unit.0)
+            impl<const D: Dimension, const F: Magnitude> From<Unit<D, F>> for Magnitude {
+                fn from(_: Unit<D, F>) -> Magnitude {
+                    F
                 }
             }
 
-            impl<const D: Dimension> RuntimeUnit<D> {
+            impl<const D: Dimension, const F: Magnitude, const O: Magnitude> AffineUnit<D, F, O> {
                 pub fn new<S>(self, val: S) -> Quantity<S, D>
                 where
-                    S: Mul<Magnitude, Output = S>,
+                    S: Mul<Magnitude, Output = S> + Add<Magnitude, Output = S>,
                 {
-                    Quantity(val * self.0)
+                    Quantity(val * F + O)
+                }
+
+                pub fn new_point<S>(self, val: S) -> Point<S, D>
+                where
+                    S: Mul<Magnitude, Output = S> + Add<Magnitude, Output = S>,
+                {
+                    Point(val * F + O)
                 }
             }
 
-            impl<const D: Dimension, const F: Magnitude> From<Unit<D, F>> for Magnitude {
-                fn from(_: Unit<D, F>) -> Magnitude {
-                    F
+            /// Converts the value of a quantity in base units into
+            /// the value in a given unit.
+            pub trait UnitConversion<S> {
+                fn convert_from_base(self, val: S) -> S;
+            }
+
+            impl<S, A: Into<Magnitude>> UnitConversion<S> for A
+            where
+                S: Div<Magnitude, Output = S>,
+            {
+                fn convert_from_base(self, val: S) -> S {
+                    val / self.into()
                 }
             }
 
-            impl<const D: Dimension> From<RuntimeUnit<D>> for Magnitude {
-                fn from(unit: RuntimeUnit<D>) -> Magnitude {
-                    unit.0
+            impl<S, const D: Dimension, const F: Magnitude, const O: Magnitude> UnitConversion<S>
+                for AffineUnit<D, F, O>
+            where
+                S: Sub<Magnitude, Output = S> + Div<Magnitude, Output = S>,
+            {
+                fn convert_from_base(self, val: S) -> S {
+                    (val - O) / F
                 }
             }
         }
     }
 
     fn gen_unit_trait_impls_for_storage_types(&self) -> TokenStream {
-        self.storage_types()
-            .map(|ty| {
-                let name = &ty.name();
-                let conversion_method = &ty.base_storage().conversion_method;
-                self.gen_unit_numeric_traits_impls_for_type(name, conversion_method)
+        self.storage_type_names()
+            .iter()
+            .map(|ty| match (&ty.kind, ty.converted_float_type()) {
+                (StorageKind::Custom(_), _) => quote! {},
+                (_, Some(float_type)) => self.gen_unit_numeric_traits_impls_for_type(
+                    &ty.name,
+                    &float_type.conversion_method,
+                ),
+                (_, None) => self.gen_unit_numeric_traits_impls_via_magnitude(ty),
             })
             .collect()
     }
 
+    /// Storage types declared in the unit system may be given by
+    /// paths relative to the module of the unit system, so their
+    /// impls are generated there instead of in the `unit_type` module.
+    pub fn gen_unit_trait_impls_for_custom_storage_types(&self) -> TokenStream {
+        let impls: TokenStream = self
+            .storage_type_names()
+            .iter()
+            .filter(|ty| matches!(ty.kind, StorageKind::Custom(_)))
+            .map(|ty| self.gen_unit_numeric_traits_impls_via_magnitude(ty))
+            .collect();
+        quote! {
+            const _: () = {
+                use ::core::ops::{Div, Mul};
+                #impls
+            };
+        }
+    }
+
+    /// Scales by the magnitude through `Mul<Magnitude>` and
+    /// `Div<Magnitude>` instead of converting it into the storage
+    /// type first. This keeps integers, decimals and rationals exact
+    /// and lets each storage type handle the magnitude itself, e.g.
+    /// intervals widen around inexact magnitudes.
+    ///
+    /// `Unit / X` is not implemented, since dividing a magnitude by
+    /// a value has no meaning for most of these types. `x * unit`
+    /// followed by a division of the quantity can be used instead.
+    fn gen_unit_numeric_traits_impls_via_magnitude(&self, ty: &StorageTypeName) -> TokenStream {
+        let StorageTypeName { name, generics, .. } = ty;
+        quote! {
+            // X * Unit
+            impl<const D: Dimension, const F: Magnitude, #(#generics),*> Mul<Unit<D, F>> for #name {
+                type Output = Quantity<#name, D>;
+                fn mul(self, _: Unit<D, F>) -> Self::Output {
+                    Quantity(self * F)
+                }
+            }
+
+            // X / Unit
+            impl<const D: Dimension, const F: Magnitude, #(#generics),*> Div<Unit<D, F>> for #name {
+                type Output = Quantity<#name, D>;
+                fn div(self, _: Unit<D, F>) -> Self::Output {
+                    Quantity(self / F)
+                }
+            }
+
+            // Unit * X
+            impl<const D: Dimension, const F: Magnitude, #(#generics),*> Mul<#name> for Unit<D, F> {
+                type Output = Quantity<#name, D>;
+                fn mul(self, f: #name) -> Self::Output {
+                    Quantity(f * F)
+                }
+            }
+        }
+    }
+
     fn gen_unit_numeric_traits_impls_for_type(
         &self,
         name: &Type,
         conversion_to_float: &TokenStream,
     ) -> TokenStream {
         let into = quote! {
-            F.#conversion_to_float()
+            const { F.#conversion_to_float() }
         };
         let res = quote! {
             // X * Unit
@@ -228,38 +358,6 @@ impl Codegen {
                     Quantity(#into / f)
                 }
             }
-
-            // X * RuntimeUnit
-            impl<const D: Dimension> Mul<RuntimeUnit<D>> for #name {
-                type Output = Quantity<#name, D>;
-                fn mul(self, unit: RuntimeUnit<D>) -> Self::Output {
-                    Quantity(self * unit.0.#conversion_to_float())
-                }
-            }
-
-            // X / RuntimeUnit
-            impl<const D: Dimension> Div<RuntimeUnit<D>> for #name {
-                type Output = Quantity<#name, D>;
-                fn div(self, unit: RuntimeUnit<D>) -> Self::Output {
-                    Quantity(self / unit.0.#conversion_to_float())
-                }
-            }
-
-            // RuntimeUnit * X
-            impl<const D: Dimension> Mul<#name> for RuntimeUnit<D> {
-                type Output = Quantity<#name, D>;
-                fn mul(self, f: #name) -> Self::Output {
-                    Quantity(self.0.#conversion_to_float() * f)
-                }
-            }
-
-            // RuntimeUnit / X
-            impl<const D: Dimension> Div<#name> for RuntimeUnit<D> {
-                type Output = Quantity<#name, D>;
-                fn div(self, f: #name) -> Self::Output {
-                    Quantity(self.0.#conversion_to_float() / f)
-                }
-            }
         };
         res
     }
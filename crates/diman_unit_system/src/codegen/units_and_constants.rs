@@ -3,11 +3,30 @@ use quote::{quote, quote_spanned};
 
 use super::Codegen;
 use crate::types::{Constant, Unit};
-use diman_lib::magnitude::Magnitude;
+use diman_lib::{logarithmic_scale::LogarithmicScale, magnitude::Magnitude};
 
 impl Codegen {
-    pub fn gen_units_and_constants(&self) -> TokenStream {
+    pub fn gen_unit_types(&self) -> TokenStream {
         let def_unit_type = self.gen_unit_type();
+        let custom_storage_type_impls = self.gen_unit_trait_impls_for_custom_storage_types();
+        let path_prefix = self.caller_type.path_prefix();
+        quote! {
+            pub use #path_prefix::magnitude::Magnitude;
+            mod unit_type {
+                use super::Dimension;
+                use super::Magnitude;
+                use super::Quantity;
+                use super::Point;
+                use #path_prefix::scaling::{InexactConversion, ScaleByMagnitude, ScaleExactly};
+                #def_unit_type
+            }
+            #[allow(unused)]
+            pub use unit_type::{AffineUnit, Unit};
+            #custom_storage_type_impls
+        }
+    }
+
+    pub fn gen_units_and_constants(&self) -> TokenStream {
         let units: TokenStream = self
             .defs
             .units
@@ -30,21 +49,17 @@ impl Codegen {
                 }
             })
             .collect();
-        let path_prefix = self.caller_type.path_prefix();
+        let reexport_units = self.reexport_from_extended_system(quote! { units });
+        let reexport_constants = self.reexport_from_extended_system(quote! { constants });
         quote! {
-            pub use #path_prefix::magnitude::Magnitude;
-            mod unit_type {
-                use super::Dimension;
-                use super::Magnitude;
-                use super::Quantity;
-                #def_unit_type
-            }
-            #[allow(unused)]
-            pub use unit_type::Unit;
             #[allow(non_upper_case_globals)]
             #[allow(unused)]
             pub mod units {
+                #reexport_units
                 use super::Magnitude;
+                use super::AffineUnit;
+                use super::LogarithmicScale;
+                use super::LogarithmicUnit;
                 use super::Unit;
                 use super::Dimension;
                 use super::Exponent;
@@ -52,6 +67,7 @@ impl Codegen {
             }
             #[allow(unused)]
             pub mod constants {
+                #reexport_constants
                 use super::Magnitude;
                 use super::Unit;
                 use super::Dimension;
@@ -66,8 +82,22 @@ impl Codegen {
         let name = &unit.name;
         let magnitude = self.get_magnitude_expr(unit.magnitude);
         let span = self.defs.dimension_type.span();
-        quote_spanned! {span=>
-            pub const #name: Unit<{ #dimension }, { #magnitude }> = Unit;
+        if let Some(scale) = unit.logarithmic {
+            let scale = self.get_logarithmic_scale_expr(scale);
+            return quote_spanned! {span=>
+                pub const #name: LogarithmicUnit<{ #dimension }, { #scale }> = LogarithmicUnit;
+            };
+        }
+        match unit.offset {
+            Some(offset) => {
+                let offset = self.get_magnitude_expr(offset);
+                quote_spanned! {span=>
+                    pub const #name: AffineUnit<{ #dimension }, { #magnitude }, { #offset }> = AffineUnit;
+                }
+            }
+            None => quote_spanned! {span=>
+                pub const #name: Unit<{ #dimension }, { #magnitude }> = Unit;
+            },
         }
     }
 
@@ -81,13 +111,36 @@ impl Codegen {
         }
     }
 
+    pub fn get_logarithmic_scale_expr(&self, scale: LogarithmicScale) -> TokenStream {
+        let reference = self.get_magnitude_expr(scale.reference);
+        let factor = self.get_magnitude_expr(scale.factor);
+        let base = self.get_magnitude_expr(scale.base);
+        let root_power = scale.root_power;
+        quote! {
+            LogarithmicScale {
+                reference: #reference,
+                factor: #factor,
+                base: #base,
+                root_power: #root_power,
+            }
+        }
+    }
+
     pub fn get_magnitude_expr(&self, magnitude: Magnitude) -> TokenStream {
-        let (mantissa, exponent, sign) = (magnitude.mantissa, magnitude.exponent, magnitude.sign);
+        let Magnitude {
+            sign,
+            num,
+            denom,
+            pow2,
+            pow10,
+        } = magnitude;
         quote! {
             Magnitude {
-                mantissa: #mantissa,
-                exponent: #exponent,
                 sign: #sign,
+                num: #num,
+                denom: #denom,
+                pow2: #pow2,
+                pow10: #pow10,
             }
         }
     }
@@ -1,148 +1,132 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::Type;
 
-use super::{storage_types::VectorType, Codegen};
+use super::{storage_types::StorageKind, Codegen};
 
 impl Codegen {
     pub fn gen_vector_methods(&self) -> TokenStream {
-        self.vector_types()
+        let specific_methods: TokenStream = self
+            .storage_type_names()
             .iter()
-            .map(|vector_type| self.impl_vector_methods(vector_type))
-            .collect()
-    }
-
-    fn impl_vector_methods(&self, vector_type: &VectorType) -> TokenStream {
-        let dimension_type = &self.defs.dimension_type;
-        let quantity_type = &self.defs.quantity_type;
-        let VectorType {
-            name: vector_type_name,
-            float_type,
-            num_dims,
-            ..
-        } = vector_type;
-        let float_type = &float_type.name;
-        let new_z_impl = if *num_dims == 3 {
-            quote! {
-                pub fn new_z(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
-                    q * <#vector_type_name>::Z
-                }
-            }
+            .filter_map(|ty| match &ty.kind {
+                StorageKind::Vector {
+                    float_type,
+                    num_dims,
+                } => Some(self.impl_vector_methods(&ty.name, &float_type.name, *num_dims)),
+                StorageKind::Custom(Some(vector)) => {
+                    Some(self.impl_vector_methods(&ty.name, &vector.float_type, vector.num_dims))
+                }
+                _ => None,
+            })
+            .collect();
+        let generic_methods = if self.needs_storage_trait_impls(|kind| {
+            matches!(
+                kind,
+                StorageKind::Vector { .. } | StorageKind::NalgebraVector { .. }
+            )
+        }) {
+            self.generic_vector_methods()
         } else {
             quote! {}
         };
-        let z_impl = if *num_dims == 3 {
-            quote! {
-                pub fn z(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type(self.0.z)
-                }
-            }
-        } else {
-            quote! {}
-        };
-        let set_z_impl = if *num_dims == 3 {
-            quote! {
-                pub fn set_z(&mut self, new_z: #quantity_type<#float_type, D>) {
-                    self.0.z = new_z.value_unchecked();
-                }
-            }
-        } else {
-            quote! {}
-        };
-        let new_impl = if *num_dims == 3 {
-            quote! {
-                pub fn new(
-                    x: #quantity_type<#float_type, D>,
-                    y: #quantity_type<#float_type, D>,
-                    z: #quantity_type<#float_type, D>,
-                ) -> Self {
-                    Self(<#vector_type_name>::new(x.value_unchecked(), y.value_unchecked(), z.value_unchecked()))
-                }
-            }
-        } else {
-            quote! {
-                pub fn new(
-                    x: #quantity_type<#float_type, D>,
-                    y: #quantity_type<#float_type, D>,
-                ) -> Self {
-                    Self(<#vector_type_name>::new(x.value_unchecked(), y.value_unchecked()))
-                }
-            }
-        };
         quote! {
-            impl<const D: #dimension_type> #quantity_type<#vector_type_name, D> {
-                #new_impl
-
-                pub fn new_x(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
-                    q * <#vector_type_name>::X
-                }
-
-                pub fn new_y(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
-                    q * <#vector_type_name>::Y
-                }
-
-                #new_z_impl
-
-                pub fn x(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type(self.0.x)
-                }
-
-                pub fn y(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type(self.0.y)
-                }
-
-                #z_impl
-
-                pub fn set_x(&mut self, new_x: #quantity_type<#float_type, D>) {
-                    self.0.x = new_x.value_unchecked();
-                }
-
-                pub fn set_y(&mut self, new_y: #quantity_type<#float_type, D>) {
-                    self.0.y = new_y.value_unchecked();
-                }
-
-                #set_z_impl
-
-                pub fn zero() -> Self {
-                    Self(<#vector_type_name>::ZERO)
-                }
-
-                pub fn min(self, rhs: Self) -> Self {
-                    Self(self.0.min(rhs.0))
-                }
-
-                pub fn max(self, rhs: Self) -> Self {
-                    Self(self.0.max(rhs.0))
-                }
+            #generic_methods
+            #specific_methods
+        }
+    }
 
-                pub fn length(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type::<#float_type, D>(self.0.length())
+    /// Methods which do not depend on the number of dimensions
+    /// of the vector type.
+    fn generic_vector_methods(&self) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let path_prefix = self.caller_type.path_prefix();
+        let vector_storage = quote! { #path_prefix::storage::VectorStorage };
+        quote! {
+            impl<const D: #dimension_type, S: #vector_storage> #quantity_type<S, D> {
+                pub fn length(&self) -> #quantity_type<S::Scalar, D> {
+                    #quantity_type::<S::Scalar, D>(S::length(&self.0))
                 }
 
-                pub fn distance(&self, other: &Self) -> #quantity_type<#float_type, D> {
-                    #quantity_type::<#float_type, D>(self.0.distance(other.0))
+                pub fn distance(&self, other: &Self) -> #quantity_type<S::Scalar, D> {
+                    #quantity_type::<S::Scalar, D>(S::distance(&self.0, &other.0))
                 }
 
                 pub fn distance_squared(
                     &self,
                     other: &Self,
-                ) -> #quantity_type<#float_type, { D.mul(2) }>
+                ) -> #quantity_type<S::Scalar, { D.mul(2) }>
                 where
-                    #quantity_type<#float_type, { D.mul(2) }>:,
+                    #quantity_type<S::Scalar, { D.mul(2) }>:,
                 {
-                    #quantity_type::<#float_type, { D.mul(2) }>(self.0.distance_squared(other.0))
+                    #quantity_type::<S::Scalar, { D.mul(2) }>(S::distance_squared(&self.0, &other.0))
                 }
 
-                pub fn normalize(&self) -> #quantity_type<#vector_type_name, { #dimension_type::none() }> {
-                    #quantity_type::<#vector_type_name, { #dimension_type::none() }>(self.0.normalize())
+                pub fn normalize(&self) -> #quantity_type<S, { #dimension_type::none() }> {
+                    #quantity_type::<S, { #dimension_type::none() }>(S::normalize(&self.0))
                 }
 
-                pub fn dot<const DR: Dimension>(
+                pub fn dot<const DR: #dimension_type>(
                     self,
-                    rhs: Quantity<#vector_type_name, DR>,
-                ) -> #quantity_type<#float_type, { D.add(DR) }> {
-                    #quantity_type(self.0.dot(rhs.0))
+                    rhs: #quantity_type<S, DR>,
+                ) -> #quantity_type<S::Scalar, { D.add(DR) }> {
+                    #quantity_type(S::dot(&self.0, &rhs.0))
                 }
             }
         }
     }
+
+    fn impl_vector_methods(
+        &self,
+        vector_type_name: &Type,
+        float_type: &Type,
+        num_dims: usize,
+    ) -> TokenStream {
+        let dimension_type = &self.defs.dimension_type;
+        let quantity_type = &self.defs.quantity_type;
+        let components: Vec<Ident> = ["x", "y", "z", "w"][..num_dims]
+            .iter()
+            .map(|name| format_ident!("{}", name))
+            .collect();
+        let unit_vectors = components
+            .iter()
+            .map(|component| format_ident!("{}", component.to_string().to_uppercase()));
+        let new_components = components
+            .iter()
+            .map(|component| format_ident!("new_{}", component));
+        let set_components = components
+            .iter()
+            .map(|component| format_ident!("set_{}", component));
+        let new_values = components
+            .iter()
+            .map(|component| format_ident!("new_{}", component));
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#vector_type_name, D> {
+                pub fn new(
+                    #(#components: #quantity_type<#float_type, D>,)*
+                ) -> Self {
+                    Self(<#vector_type_name>::new(#(#components.value_unchecked()),*))
+                }
+
+                #(
+                    pub fn #new_components(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
+                        q * <#vector_type_name>::#unit_vectors
+                    }
+                )*
+
+                #(
+                    pub fn #components(&self) -> #quantity_type<#float_type, D> {
+                        #quantity_type(self.0.#components)
+                    }
+                )*
+
+                #(
+                    pub fn #set_components(&mut self, #new_values: #quantity_type<#float_type, D>) {
+                        self.0.#components = #new_values.value_unchecked();
+                    }
+                )*
+            }
+        }
+    }
 }
@@ -11,6 +11,10 @@ use crate::{
 #[derive(Clone)]
 pub struct BaseDimensions {
     fields: HashMap<BaseDimension, Exponent>,
+    /// The quantity kind, for dimensions declared via `kind`
+    /// and the units and constants annotated with them.
+    /// Products, quotients and powers never have a kind.
+    pub kind: Option<u32>,
 }
 
 #[derive(Clone)]
@@ -19,6 +23,7 @@ pub struct DimensionsAndMagnitude {
     pub magnitude: Magnitude,
 }
 
+/// Compares the base dimensions only, regardless of the kind.
 impl PartialEq for BaseDimensions {
     fn eq(&self, other: &Self) -> bool {
         self.fields.iter().all(|(dimension, value)| {
@@ -34,13 +39,14 @@ impl BaseDimensions {
     pub fn none() -> Self {
         Self {
             fields: HashMap::default(),
+            kind: None,
         }
     }
 
     pub fn for_base_dimension(base_dim: BaseDimension) -> Self {
         let mut fields = HashMap::new();
         fields.insert(base_dim, Exponent::one());
-        Self { fields }
+        Self { fields, kind: None }
     }
 
     pub(crate) fn fields(&self) -> impl Iterator<Item = (&Ident, &Exponent)> {
@@ -74,13 +80,13 @@ impl core::ops::Mul for BaseDimensions {
                 fields.insert(name_rhs, val_rhs);
             }
         }
-        Self { fields }
+        Self { fields, kind: None }
     }
 }
 
 impl BaseDimensions {
     fn inv(mut self) -> Self {
-        for (_, value) in self.fields.iter_mut() {
+        for value in self.fields.values_mut() {
             *value = -*value;
         }
         self
@@ -104,6 +110,7 @@ impl MulDiv for BaseDimensions {
                 .into_iter()
                 .map(|(ident, value)| (ident, value * pow))
                 .collect(),
+            kind: None,
         }
     }
 }
@@ -119,7 +126,7 @@ impl DimensionsAndMagnitude {
     pub(crate) fn dimensions(dimensions: BaseDimensions) -> Self {
         Self {
             dimensions,
-            magnitude: Magnitude::from_f64(1.0),
+            magnitude: Magnitude::one(),
         }
     }
 }
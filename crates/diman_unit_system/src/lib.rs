@@ -14,6 +14,9 @@ fn run_unit_system(
     item: proc_macro::TokenStream,
     caller_type: CallerType,
 ) -> proc_macro::TokenStream {
+    if let Ok(redirect) = syn::parse::<parse::ExtensionRedirect>(item.clone()) {
+        return redirect.expand().into();
+    }
     let defs = parse_macro_input!(item as types::UnresolvedTemplates);
     let expanded = defs.expand_templates();
     let resolved = expanded.resolve();
@@ -36,3 +39,121 @@ pub fn unit_system(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn unit_system_internal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     run_unit_system(input, CallerType::Internal)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        str::FromStr,
+        time::{Duration, Instant},
+    };
+
+    use proc_macro2::{TokenStream, TokenTree};
+    use quote::quote;
+
+    use crate::codegen::{CallerType, Codegen};
+
+    /// The statements of the SI system, as passed to `unit_system!`
+    /// by `diman::si`. They are read from its source, so that the
+    /// definitions stay in one place.
+    fn si_definitions() -> TokenStream {
+        let source = include_str!("../../../src/si.rs");
+        let start = source.find("$($extension)*").unwrap() + "$($extension)*".len();
+        let end = source[start..].find("\n        );").unwrap() + start;
+        TokenStream::from_str(&source[start..end]).unwrap()
+    }
+
+    fn count_tokens(tokens: TokenStream) -> usize {
+        tokens
+            .into_iter()
+            .map(|tree| match tree {
+                TokenTree::Group(group) => 1 + count_tokens(group.stream()),
+                _ => 1,
+            })
+            .sum()
+    }
+
+    /// Expands the given unit system and returns the number of tokens
+    /// in the expansion, as well as the time it took.
+    fn expand(input: TokenStream) -> (usize, Duration) {
+        let start = Instant::now();
+        let defs: crate::types::UnresolvedTemplates = syn::parse2(input).unwrap();
+        let expanded = Codegen {
+            defs: defs.expand_templates().resolve(),
+            caller_type: CallerType::External,
+        }
+        .code_gen();
+        (count_tokens(expanded), start.elapsed())
+    }
+
+    #[test]
+    fn si_expansion_size() {
+        let si = si_definitions();
+        let (num_tokens, _) = expand(si.clone());
+        // Before the operators and methods were implemented
+        // generically over the storage type, the SI expansion
+        // consisted of 92703 tokens with the default features
+        // (149233 with all storage types enabled) and every scalar
+        // storage type added 4813 tokens. Now it consists of about
+        // 92600 tokens with the default features and 320000 tokens
+        // with all features.
+        let default_features = cfg!(all(
+            feature = "f32",
+            feature = "f64",
+            not(any(
+                feature = "glam",
+                feature = "glam-vec2",
+                feature = "glam-dvec2",
+                feature = "glam-vec3",
+                feature = "glam-dvec3",
+                feature = "glam-vec4",
+                feature = "glam-dvec4",
+                feature = "glam-mat2",
+                feature = "glam-mat3",
+                feature = "glam-dmat3",
+                feature = "i32",
+                feature = "i64",
+                feature = "u32",
+                feature = "u64",
+                feature = "fixed",
+                feature = "num-complex",
+                feature = "nalgebra",
+                feature = "ndarray",
+                feature = "measurement",
+                feature = "interval",
+                feature = "dual",
+                feature = "half",
+                feature = "rust_decimal",
+                feature = "num-rational",
+                feature = "mpi",
+                feature = "serde",
+                feature = "rand",
+                feature = "hdf5",
+                feature = "rational-dimensions",
+                feature = "num-traits-libm",
+                feature = "std",
+            ))
+        ));
+        if default_features {
+            assert!(num_tokens < 92703);
+        }
+        assert!(num_tokens < 350000);
+        // The cost of an additional storage type, which is independent
+        // of the enabled features.
+        let (num_tokens_with_storage_type, _) = expand(quote! {
+            storage_type crate::MyFloat;
+            #si
+        });
+        let num_tokens_per_storage_type = num_tokens_with_storage_type - num_tokens;
+        assert!(num_tokens_per_storage_type < 4813);
+    }
+
+    /// Prints the time it takes to expand the SI system, which
+    /// depends too much on the machine to be asserted on. Run with
+    /// `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn si_expansion_time() {
+        let (num_tokens, duration) = expand(si_definitions());
+        println!("SI expansion: {num_tokens} tokens in {duration:?}");
+    }
+}
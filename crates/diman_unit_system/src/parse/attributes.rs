@@ -2,34 +2,51 @@ use proc_macro2::Span;
 use syn::{
     bracketed, parenthesized,
     parse::{Parse, ParseBuffer, ParseStream},
-    Error, Result, Token,
+    Error, Ident, LitStr, Result, Token,
 };
 
+use diman_lib::magnitude::Magnitude;
+
 use crate::{
     parse::tokens,
-    types::prefixes::{ExplicitPrefixes, MetricPrefixes, Prefix},
-    types::{Alias, BaseAttribute, Symbol},
+    types::prefixes::{
+        BinaryPrefixes, ExplicitPrefixes, MetricPrefixes, BINARY_PREFIXES, METRIC_PREFIXES,
+    },
+    types::{Alias, BaseAttribute, LogarithmicAttribute, Symbol},
 };
 
+use super::{tokens::SubtractionToken, Number};
+
 pub mod attribute_keywords {
     syn::custom_keyword!(base);
     syn::custom_keyword!(alias);
     syn::custom_keyword!(symbol);
     syn::custom_keyword!(metric_prefixes);
+    syn::custom_keyword!(binary_prefixes);
     syn::custom_keyword!(prefix);
+    syn::custom_keyword!(logarithmic);
 }
 
 pub mod prefix_attribute_keywords {
     syn::custom_keyword!(skip);
 }
 
+pub mod logarithmic_attribute_keywords {
+    syn::custom_keyword!(factor);
+    syn::custom_keyword!(base);
+    syn::custom_keyword!(root_power);
+    syn::custom_keyword!(e);
+}
+
 #[derive(PartialEq, Debug)]
 pub enum AttributeName {
     Base,
     Alias,
     Symbol,
     MetricPrefixes,
+    BinaryPrefixes,
     Prefix,
+    Logarithmic,
 }
 
 pub struct Attribute<'a> {
@@ -76,9 +93,15 @@ impl<'a> Attributes<'a> {
             } else if lookahead.peek(attr_kw::metric_prefixes) {
                 let _: attr_kw::metric_prefixes = content.parse()?;
                 AttributeName::MetricPrefixes
+            } else if lookahead.peek(attr_kw::binary_prefixes) {
+                let _: attr_kw::binary_prefixes = content.parse()?;
+                AttributeName::BinaryPrefixes
             } else if lookahead.peek(attr_kw::prefix) {
                 let _: attr_kw::prefix = content.parse()?;
                 AttributeName::Prefix
+            } else if lookahead.peek(attr_kw::logarithmic) {
+                let _: attr_kw::logarithmic = content.parse()?;
+                AttributeName::Logarithmic
             } else {
                 return Err(lookahead.error());
             };
@@ -135,7 +158,7 @@ impl Parse for Alias {
 }
 
 impl Attribute<'_> {
-    fn inner_or_err(&self) -> Result<&ParseBuffer> {
+    fn inner_or_err(&self) -> Result<&ParseBuffer<'_>> {
         self.inner
             .as_ref()
             .ok_or_else(|| Error::new(self.span, "Attribute expects arguments."))
@@ -157,15 +180,51 @@ impl FromAttribute for Vec<Alias> {
     }
 }
 
+/// Symbols are separated by whitespace and combined with
+/// exponents via `^` when parsing quantities, so they may
+/// not contain either.
+pub fn check_symbol(symbol: &str, span: Span) -> Result<()> {
+    if symbol.is_empty() {
+        Err(Error::new(span, "Symbols cannot be empty."))
+    } else if symbol.contains(|c: char| c.is_whitespace() || c == '^') {
+        Err(Error::new(
+            span,
+            "Symbols cannot contain whitespace or '^'.",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn parse_symbol(input: ParseStream) -> Result<String> {
+    let (symbol, span) = if input.peek(LitStr) {
+        let lit: LitStr = input.parse()?;
+        (lit.value(), lit.span())
+    } else {
+        let ident: Ident = input.parse()?;
+        (ident.to_string(), ident.span())
+    };
+    check_symbol(&symbol, span)?;
+    Ok(symbol)
+}
+
 impl FromAttribute for Symbol {
     fn correct_type() -> AttributeName {
         AttributeName::Symbol
     }
 
+    /// The first symbol is the one shown in the debug output, any
+    /// further ones are alternatives which are accepted when parsing.
     fn from_attribute(attribute: &Attribute) -> Result<Self> {
         let inner = attribute.inner_or_err()?;
-        let name = inner.parse()?;
-        Ok(Symbol(name))
+        let mut symbols = inner.parse_terminated(parse_symbol, Token![,])?.into_iter();
+        let name = symbols
+            .next()
+            .ok_or_else(|| Error::new(attribute.span, "Attribute expects arguments."))?;
+        Ok(Symbol {
+            name,
+            alternatives: symbols.collect(),
+        })
     }
 }
 
@@ -183,28 +242,52 @@ impl FromAttribute for BaseAttribute {
     }
 }
 
+/// Parses the optional `skip: ...` argument of the prefix attributes.
+fn parse_skipped_prefixes(attr: &Attribute, allowed: &[&str]) -> Result<Vec<Ident>> {
+    if let Some(inner) = &attr.inner {
+        let lookahead = inner.lookahead1();
+        if lookahead.peek(prefix_attribute_keywords::skip) {
+            let _: prefix_attribute_keywords::skip = inner.parse()?;
+            let _: Token![:] = inner.parse()?;
+            let skip: Vec<Ident> = inner
+                .parse_terminated(Ident::parse, Token![,])?
+                .into_iter()
+                .collect();
+            if let Some(unknown) = skip.iter().find(|name| !allowed.iter().any(|a| *name == a)) {
+                return Err(Error::new_spanned(
+                    unknown,
+                    format!("Unknown prefix {} in skip list.", unknown),
+                ));
+            }
+            return Ok(skip);
+        }
+    }
+    Ok(vec![])
+}
+
 impl FromAttribute for MetricPrefixes {
     fn correct_type() -> AttributeName {
         AttributeName::MetricPrefixes
     }
 
     fn from_attribute(attr: &Attribute) -> Result<Self> {
-        let skip = if let Some(inner) = &attr.inner {
-            let lookahead = inner.lookahead1();
-            if lookahead.peek(prefix_attribute_keywords::skip) {
-                let _: prefix_attribute_keywords::skip = inner.parse()?;
-                let _: Token![:] = inner.parse()?;
-                inner
-                    .parse_terminated(Prefix::parse, Token![,])?
-                    .into_iter()
-                    .collect()
-            } else {
-                vec![]
-            }
-        } else {
-            vec![]
-        };
-        Ok(Self { skip })
+        Ok(Self {
+            skip: parse_skipped_prefixes(attr, METRIC_PREFIXES)?,
+            span: attr.span,
+        })
+    }
+}
+
+impl FromAttribute for BinaryPrefixes {
+    fn correct_type() -> AttributeName {
+        AttributeName::BinaryPrefixes
+    }
+
+    fn from_attribute(attr: &Attribute) -> Result<Self> {
+        Ok(Self {
+            skip: parse_skipped_prefixes(attr, BINARY_PREFIXES)?,
+            span: attr.span,
+        })
     }
 }
 
@@ -216,9 +299,75 @@ impl FromAttribute for ExplicitPrefixes {
     fn from_attribute(attr: &Attribute) -> Result<Self> {
         let inner = attr.inner_or_err()?;
         let prefixes = inner
-            .parse_terminated(Prefix::parse, Token![,])?
+            .parse_terminated(Ident::parse, Token![,])?
             .into_iter()
             .collect();
         Ok(Self(prefixes))
     }
 }
+
+fn parse_signed_number(input: ParseStream) -> Result<f64> {
+    let sign = if input.peek(SubtractionToken) {
+        let _: SubtractionToken = input.parse()?;
+        -1.0
+    } else {
+        1.0
+    };
+    let number: Number = input.parse()?;
+    Ok(sign * number.float)
+}
+
+impl FromAttribute for LogarithmicAttribute {
+    fn correct_type() -> AttributeName {
+        AttributeName::Logarithmic
+    }
+
+    fn from_attribute(attr: &Attribute) -> Result<Self> {
+        use logarithmic_attribute_keywords as kw;
+        let inner = attr.inner_or_err()?;
+        let mut factor = None;
+        let mut base = None;
+        let mut root_power = false;
+        while !inner.is_empty() {
+            let lookahead = inner.lookahead1();
+            if lookahead.peek(kw::factor) {
+                let _: kw::factor = inner.parse()?;
+                let _: Token![:] = inner.parse()?;
+                factor = Some(parse_signed_number(inner)?);
+            } else if lookahead.peek(kw::base) {
+                let _: kw::base = inner.parse()?;
+                let _: Token![:] = inner.parse()?;
+                if inner.peek(kw::e) {
+                    let _: kw::e = inner.parse()?;
+                    base = Some(core::f64::consts::E);
+                } else {
+                    let number: Number = inner.parse()?;
+                    base = Some(number.float);
+                }
+            } else if lookahead.peek(kw::root_power) {
+                let _: kw::root_power = inner.parse()?;
+                root_power = true;
+            } else {
+                return Err(lookahead.error());
+            }
+            if !inner.is_empty() {
+                let _: Token![,] = inner.parse()?;
+            }
+        }
+        let factor =
+            factor.ok_or_else(|| Error::new(attr.span, "Logarithmic unit requires a factor."))?;
+        let base =
+            base.ok_or_else(|| Error::new(attr.span, "Logarithmic unit requires a base."))?;
+        if base <= 0.0 || base == 1.0 {
+            return Err(Error::new(
+                attr.span,
+                "The base of a logarithmic unit must be positive and different from 1.",
+            ));
+        }
+        Ok(Self {
+            factor: Magnitude::from_f64(factor),
+            base: Magnitude::from_f64(base),
+            root_power,
+        })
+    }
+}
@@ -1,25 +1,35 @@
 mod attributes;
 
 use diman_lib::magnitude::Magnitude;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::{
+    braced,
+    ext::IdentExt,
     parenthesized,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Brace,
     token::{self, Paren},
-    Error, Ident, Lit, Result,
+    Error, Ident, Lit, LitInt, LitStr, Path, PathSegment, Result, Token,
 };
 
 use crate::{
     parse::attributes::Attributes,
+    types::conversion::{BaseDimensionConversionEntry, ConversionEntry},
     types::expression::{BinaryOperator, Expr, Factor, Operator},
-    types::prefixes::{ExplicitPrefixes, MetricPrefixes},
-    types::{Alias, BaseAttribute, Definition, One, UnresolvedTemplates},
+    types::prefixes::{BinaryPrefixes, ExplicitPrefixes, MetricPrefixes, PrefixEntry},
+    types::storage_type::{StorageTypeEntry, VectorStorage},
+    types::{
+        Alias, BaseAttribute, Definition, Extension, LogarithmicAttribute, One, UnresolvedTemplates,
+    },
 };
 
 use self::{
     attributes::ParseWithAttributes,
     tokens::{
-        AssignmentToken, DivisionToken, ExponentiationToken, MultiplicationToken,
-        StatementSeparator, TypeAnnotationToken,
+        AdditionToken, AssignmentToken, DivisionToken, ExponentiationToken, MultiplicationToken,
+        StatementSeparator, SubtractionToken, TypeAnnotationToken,
     },
 };
 
@@ -29,8 +39,15 @@ mod keywords {
     syn::custom_keyword!(quantity_type);
     syn::custom_keyword!(dimension_type);
     syn::custom_keyword!(dimension);
+    syn::custom_keyword!(kind);
     syn::custom_keyword!(unit);
     syn::custom_keyword!(constant);
+    syn::custom_keyword!(prefix);
+    syn::custom_keyword!(symbol);
+    syn::custom_keyword!(extends);
+    syn::custom_keyword!(conversion);
+    syn::custom_keyword!(storage_type);
+    syn::custom_keyword!(vector);
 }
 
 mod tokens {
@@ -39,6 +56,8 @@ mod tokens {
     syn::custom_punctuation!(MultiplicationToken, *);
     syn::custom_punctuation!(DivisionToken, /);
     syn::custom_punctuation!(ExponentiationToken, ^);
+    syn::custom_punctuation!(AdditionToken, +);
+    syn::custom_punctuation!(SubtractionToken, -);
     syn::custom_punctuation!(StatementSeparator, ;);
     syn::custom_punctuation!(AttributeToken, #);
 }
@@ -46,6 +65,8 @@ mod tokens {
 struct Number {
     pub lit: Lit,
     pub float: f64,
+    /// The exact value of the literal.
+    pub magnitude: Magnitude,
 }
 
 struct Int {
@@ -67,6 +88,9 @@ enum Entry {
     Dimension(DimensionEntry),
     Unit(UnitTemplate),
     Constant(ConstantEntry),
+    Prefix(PrefixEntry),
+    Conversion(ConversionEntry),
+    StorageType(StorageTypeEntry),
 }
 
 impl Number {
@@ -82,18 +106,43 @@ impl Number {
     }
 }
 
+/// Reads a decimal literal such as `1.25e-3` exactly, if its
+/// significand fits into 64 bits.
+fn parse_decimal(digits: &str) -> Option<Magnitude> {
+    let (significand, exponent) = match digits.split_once(['e', 'E']) {
+        Some((significand, exponent)) => (significand, exponent.parse::<i16>().ok()?),
+        None => (digits, 0),
+    };
+    let (integer, fraction) = significand.split_once('.').unwrap_or((significand, ""));
+    let fraction_digits = i16::try_from(fraction.len()).ok()?;
+    let significand = format!("{}{}", integer, fraction).parse::<u64>().ok()?;
+    Some(Magnitude::from_decimal(
+        significand,
+        exponent.checked_sub(fraction_digits)?,
+    ))
+}
+
 impl Parse for Number {
     fn parse(input: ParseStream) -> Result<Self> {
         let lit = input.parse()?;
-        let float = match lit {
-            Lit::Int(ref int) => int.base10_parse::<i64>().map(|x| x as f64),
-            Lit::Float(ref float) => float.base10_parse::<f64>(),
+        let (float, digits) = match lit {
+            Lit::Int(ref int) => int
+                .base10_parse::<i64>()
+                .map(|x| (x as f64, int.base10_digits())),
+            Lit::Float(ref float) => float
+                .base10_parse::<f64>()
+                .map(|x| (x, float.base10_digits())),
             _ => Err(Error::new(
                 lit.span(),
                 "Unexpected literal, expected a numerical value".to_string(),
             )),
         }?;
-        Ok(Self { lit, float })
+        let magnitude = parse_decimal(digits).unwrap_or(Magnitude::from_f64(float));
+        Ok(Self {
+            lit,
+            float,
+            magnitude,
+        })
     }
 }
 
@@ -156,7 +205,7 @@ impl Parse for crate::types::Factor<Magnitude> {
             Ok(Self::Other(input.parse()?))
         } else if lookahead.peek(Lit) {
             let factor: Number = input.parse()?;
-            Ok(Self::Concrete(Magnitude::from_f64(factor.float)))
+            Ok(Self::Concrete(factor.magnitude))
         } else {
             Err(lookahead.error())
         }
@@ -204,6 +253,25 @@ impl Parse for Definition<(), One> {
     }
 }
 
+fn parse_offset(input: ParseStream) -> Result<Option<Magnitude>> {
+    let lookahead = input.lookahead1();
+    let negative = if lookahead.peek(AdditionToken) {
+        let _: AdditionToken = input.parse()?;
+        false
+    } else if lookahead.peek(SubtractionToken) {
+        let _: SubtractionToken = input.parse()?;
+        true
+    } else {
+        return Ok(None);
+    };
+    let offset: Number = input.parse()?;
+    if negative {
+        Ok(Some(offset.magnitude.neg()))
+    } else {
+        Ok(Some(offset.magnitude))
+    }
+}
+
 fn parse_annotation(input: ParseStream) -> Result<Option<Ident>> {
     let lookahead = input.lookahead1();
     let dimension_annotation = if lookahead.peek(TypeAnnotationToken) {
@@ -217,10 +285,22 @@ fn parse_annotation(input: ParseStream) -> Result<Option<Ident>> {
 
 impl Parse for DimensionEntry {
     fn parse(input: ParseStream) -> Result<Self> {
-        let _: keywords::dimension = input.parse()?;
-        let name = input.parse()?;
+        let is_kind = if input.peek(keywords::kind) {
+            let _: keywords::kind = input.parse()?;
+            true
+        } else {
+            let _: keywords::dimension = input.parse()?;
+            false
+        };
+        let name: Ident = input.parse()?;
         let rhs = input.parse()?;
-        Ok(Self { name, rhs })
+        if is_kind && matches!(rhs, Definition::Base(())) {
+            return Err(Error::new_spanned(
+                &name,
+                "Kinds cannot be base dimensions, since they need to be defined in terms of other dimensions.",
+            ));
+        }
+        Ok(Self { name, rhs, is_kind })
     }
 }
 
@@ -239,6 +319,118 @@ impl Parse for ConstantEntry {
     }
 }
 
+impl Parse for PrefixEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _: keywords::prefix = input.parse()?;
+        let name = input.parse()?;
+        let _: AssignmentToken = input.parse()?;
+        let factor: Number = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let _: keywords::symbol = input.parse()?;
+        let symbol: LitStr = input.parse()?;
+        attributes::check_symbol(&symbol.value(), symbol.span())?;
+        Ok(Self {
+            name,
+            symbol: symbol.value(),
+            factor: factor.magnitude,
+        })
+    }
+}
+
+impl Parse for BaseDimensionConversionEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let base_dimension = input.parse()?;
+        let _: AssignmentToken = input.parse()?;
+        let rhs = parse_int_exponent_expr(input)?;
+        Ok(Self {
+            base_dimension,
+            rhs,
+        })
+    }
+}
+
+impl Parse for ConversionEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _: keywords::conversion = input.parse()?;
+        // Parse the path manually, since `Path` does not
+        // accept the trailing `::{Quantity, Dimension}`.
+        let mut path = Path {
+            leading_colon: input.parse()?,
+            segments: Punctuated::new(),
+        };
+        while !input.peek(Brace) {
+            let segment = input.call(Ident::parse_any)?;
+            path.segments.push(PathSegment::from(segment));
+            let _: Token![::] = input.parse()?;
+        }
+        if path.segments.is_empty() {
+            return Err(input.error("Expected the path to the other unit system."));
+        }
+        let types;
+        let _ = braced!(types in input);
+        let quantity_type = types.parse()?;
+        let _: Token![,] = types.parse()?;
+        let dimension_type = types.parse()?;
+        let _: Option<Token![,]> = types.parse()?;
+        let content;
+        let _ = braced!(content in input);
+        let base_dimensions: Vec<BaseDimensionConversionEntry> = content
+            .parse_terminated(BaseDimensionConversionEntry::parse, StatementSeparator)?
+            .into_iter()
+            .collect();
+        for (i, entry) in base_dimensions.iter().enumerate() {
+            if base_dimensions[..i]
+                .iter()
+                .any(|previous| previous.base_dimension == entry.base_dimension)
+            {
+                return Err(Error::new_spanned(
+                    &entry.base_dimension,
+                    format!(
+                        "Conversion for base dimension {} is specified multiple times.",
+                        entry.base_dimension
+                    ),
+                ));
+            }
+        }
+        Ok(Self {
+            path,
+            quantity_type,
+            dimension_type,
+            base_dimensions,
+        })
+    }
+}
+
+impl Parse for StorageTypeEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _: keywords::storage_type = input.parse()?;
+        let name = input.parse()?;
+        let vector = if input.peek(TypeAnnotationToken) {
+            let _: TypeAnnotationToken = input.parse()?;
+            let _: keywords::vector = input.parse()?;
+            let content;
+            let _ = parenthesized!(content in input);
+            let float_type = content.parse()?;
+            let _: Token![,] = content.parse()?;
+            let num_dims_lit: LitInt = content.parse()?;
+            let num_dims = num_dims_lit.base10_parse()?;
+            if !(2..=4).contains(&num_dims) {
+                return Err(Error::new_spanned(
+                    num_dims_lit,
+                    "Only vectors with 2, 3 or 4 dimensions are supported.",
+                ));
+            }
+            Some(VectorStorage {
+                float_type,
+                num_dims,
+            })
+        } else {
+            None
+        };
+        Ok(Self { name, vector })
+    }
+}
+
 impl ParseWithAttributes for UnitTemplate {
     fn parse_with_attributes(input: ParseStream, mut attributes: Attributes) -> Result<Self> {
         let _ = input.parse::<keywords::unit>()?;
@@ -246,10 +438,13 @@ impl ParseWithAttributes for UnitTemplate {
         let dimension_annotation = parse_annotation(input)?;
         let lookahead = input.lookahead1();
         let base_attributes: Vec<BaseAttribute> = attributes.remove_all_of_type()?;
+        let mut offset = None;
         let definition = if lookahead.peek(AssignmentToken) {
             let _: AssignmentToken = input.parse()?;
             if base_attributes.is_empty() {
-                Ok(Definition::Expression(parse_int_exponent_expr(input)?))
+                let expr = parse_int_exponent_expr(input)?;
+                offset = parse_offset(input)?;
+                Ok(Definition::Expression(expr))
             } else {
                 Err(syn::Error::new(
                     base_attributes[0].attribute_span,
@@ -276,17 +471,48 @@ impl ParseWithAttributes for UnitTemplate {
             .collect();
         let symbol = attributes.remove_unique_of_type()?;
         let metric_prefixes: Option<MetricPrefixes> = attributes.remove_unique_of_type()?;
+        let binary_prefixes: Option<BinaryPrefixes> = attributes.remove_unique_of_type()?;
         let explicit_prefixes: Vec<ExplicitPrefixes> = attributes.remove_all_of_type()?;
         let mut prefixes = match metric_prefixes {
             Some(metric) => metric.into(),
             None => vec![],
         };
+        if let Some(binary) = binary_prefixes {
+            prefixes.extend(Vec::<Ident>::from(binary));
+        }
         prefixes.extend(
             explicit_prefixes
                 .into_iter()
                 .flat_map(|prefixes| prefixes.0.into_iter()),
         );
+        let logarithmic: Option<LogarithmicAttribute> = attributes.remove_unique_of_type()?;
         attributes.check_none_left_over()?;
+        if offset.is_some() && !prefixes.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &name,
+                "Prefixes are not supported for units with an offset.",
+            ));
+        }
+        if logarithmic.is_some() {
+            if !prefixes.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    "Prefixes are not supported for logarithmic units.",
+                ));
+            }
+            if offset.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    "Logarithmic units cannot have an offset.",
+                ));
+            }
+            if matches!(definition, Definition::Base(_)) {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    "Logarithmic units cannot be base units.",
+                ));
+            }
+        }
         Ok(Self {
             name,
             aliases,
@@ -294,6 +520,8 @@ impl ParseWithAttributes for UnitTemplate {
             definition,
             prefixes,
             symbol,
+            offset,
+            logarithmic,
         })
     }
 }
@@ -309,7 +537,7 @@ impl Parse for Entry {
         } else if lookahead.peek(kw::dimension_type) {
             let _: kw::dimension_type = input.parse()?;
             Ok(Self::DimensionType(input.parse()?))
-        } else if lookahead.peek(kw::dimension) {
+        } else if lookahead.peek(kw::dimension) || lookahead.peek(kw::kind) {
             Ok(Self::Dimension(input.parse()?))
         } else if lookahead.peek(kw::unit) {
             Ok(Self::Unit(UnitTemplate::parse_with_attributes(
@@ -317,6 +545,12 @@ impl Parse for Entry {
             )?))
         } else if lookahead.peek(kw::constant) {
             Ok(Self::Constant(input.parse()?))
+        } else if lookahead.peek(kw::prefix) {
+            Ok(Self::Prefix(input.parse()?))
+        } else if lookahead.peek(kw::conversion) {
+            Ok(Self::Conversion(input.parse()?))
+        } else if lookahead.peek(kw::storage_type) {
+            Ok(Self::StorageType(input.parse()?))
         } else {
             Err(lookahead.error())
         }
@@ -363,7 +597,10 @@ impl<T: Parse, E: Parse> Parse for Expr<T, E> {
         let mut lhs = Expr::Value(input.parse()?);
         while {
             let lookahead = input.lookahead1();
-            !(input.is_empty() || lookahead.peek(StatementSeparator))
+            !(input.is_empty()
+                || lookahead.peek(StatementSeparator)
+                || lookahead.peek(AdditionToken)
+                || lookahead.peek(SubtractionToken))
         } {
             let operator = input.parse()?;
             let rhs = input.parse()?;
@@ -377,21 +614,118 @@ impl<T: Parse, E: Parse> Parse for Expr<T, E> {
     }
 }
 
+impl Entry {
+    fn name(&self) -> Option<&Ident> {
+        match self {
+            Entry::QuantityType(_)
+            | Entry::DimensionType(_)
+            | Entry::Prefix(_)
+            | Entry::Conversion(_)
+            | Entry::StorageType(_) => None,
+            Entry::Dimension(dimension) => Some(&dimension.name),
+            Entry::Unit(unit) => Some(&unit.name),
+            Entry::Constant(constant) => Some(&constant.name),
+        }
+    }
+}
+
+/// A unit system that extends another one, i.e. the input
+/// `extends path::to::system; ...`. This is turned into an invocation
+/// of the `definitions` macro of the extended system, which calls
+/// `unit_system` with all of its definitions, preceded by
+/// `extends path::to::system { ... }`.
+pub struct ExtensionRedirect {
+    path: Path,
+    rest: TokenStream,
+}
+
+impl Parse for ExtensionRedirect {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _: keywords::extends = input.parse()?;
+        let path = input.call(Path::parse_mod_style)?;
+        let _: StatementSeparator = input.parse()?;
+        let rest = input.parse()?;
+        Ok(Self { path, rest })
+    }
+}
+
+impl ExtensionRedirect {
+    pub fn expand(self) -> TokenStream {
+        let Self { path, rest } = self;
+        quote::quote! {
+            #path::definitions!(extends #path { #rest });
+        }
+    }
+}
+
 impl Parse for UnresolvedTemplates {
     fn parse(input: ParseStream) -> Result<Self> {
+        let extension = if input.peek(keywords::extends) {
+            let _: keywords::extends = input.parse()?;
+            let path = input.call(Path::parse_mod_style)?;
+            let content;
+            let _ = braced!(content in input);
+            let entries = content.parse_terminated(Entry::parse, StatementSeparator)?;
+            Some((path, entries))
+        } else {
+            None
+        };
         let mut dimensions = vec![];
         let mut units = vec![];
         let mut constants = vec![];
         let mut quantity_types = vec![];
         let mut dimension_types = vec![];
-        let pt = input.parse_terminated(Entry::parse, StatementSeparator);
-        for item in pt?.into_iter() {
+        let mut prefixes = vec![];
+        let mut conversions = vec![];
+        let mut storage_types = vec![];
+        let pt = input.parse_terminated(Entry::parse, StatementSeparator)?;
+        let (extension, extension_entries) = match extension {
+            Some((path, entries)) => {
+                for entry in entries.iter() {
+                    if let Entry::QuantityType(ident) | Entry::DimensionType(ident) = entry {
+                        return Err(Error::new_spanned(
+                            ident,
+                            format!(
+                                "The quantity and dimension types are defined by {}.",
+                                path.to_token_stream()
+                            ),
+                        ));
+                    }
+                    if let Entry::Conversion(conversion) = entry {
+                        return Err(Error::new_spanned(
+                            &conversion.path,
+                            "Conversions cannot be declared in an extension of a unit system.",
+                        ));
+                    }
+                    if let Entry::StorageType(storage_type) = entry {
+                        return Err(Error::new_spanned(
+                            &storage_type.name,
+                            "Storage types cannot be declared in an extension of a unit system.",
+                        ));
+                    }
+                }
+                let names = entries
+                    .iter()
+                    .filter_map(|entry| entry.name())
+                    .map(|name| name.to_string())
+                    .collect();
+                (
+                    Some(Extension { path, names }),
+                    entries.into_iter().collect(),
+                )
+            }
+            None => (None, vec![]),
+        };
+        for item in pt.into_iter().chain(extension_entries) {
             match item {
                 Entry::Dimension(q) => dimensions.push(q),
                 Entry::Unit(u) => units.push(u),
                 Entry::Constant(c) => constants.push(c),
                 Entry::QuantityType(q) => quantity_types.push(q),
                 Entry::DimensionType(d) => dimension_types.push(d),
+                Entry::Prefix(p) => prefixes.push(p),
+                Entry::Conversion(c) => conversions.push(c),
+                Entry::StorageType(s) => storage_types.push(s),
             }
         }
         Ok(Self {
@@ -400,6 +734,10 @@ impl Parse for UnresolvedTemplates {
             dimensions,
             units,
             constants,
+            prefixes,
+            conversions,
+            storage_types,
+            extension,
         })
     }
 }
@@ -560,9 +898,246 @@ pub mod tests {
             assert_eq!(entry.name.to_string(), "bar");
             assert_eq!(entry.aliases.len(), 0);
             assert!(entry.symbol.is_some());
-            assert_eq!(entry.symbol.unwrap().0, "b");
+            assert_eq!(entry.symbol.unwrap().name, "b");
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn parse_unit_entry_with_offset() {
+        let entry = syn::parse2::<Entry>(quote! {
+            unit celsius: Temperature = kelvin + 273.15
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            assert_eq!(entry.name.to_string(), "celsius");
+            assert_eq!(entry.offset.unwrap().into_f64(), 273.15);
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            unit foo = 2.0 * kelvin - 10
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            assert_eq!(entry.offset.unwrap().into_f64(), -10.0);
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            unit bar = kelvin
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            assert!(entry.offset.is_none());
         } else {
             panic!()
         }
     }
+
+    #[test]
+    fn parse_logarithmic_unit_entry() {
+        let entry = syn::parse2::<Entry>(quote! {
+            #[logarithmic(factor: 20, base: 10, root_power)]
+            unit decibel_volt: Voltage = volt
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            let logarithmic = entry.logarithmic.unwrap();
+            assert_eq!(logarithmic.factor.into_f64(), 20.0);
+            assert_eq!(logarithmic.base.into_f64(), 10.0);
+            assert!(logarithmic.root_power);
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            #[logarithmic(factor: -1, base: e)]
+            unit foo = 1
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            let logarithmic = entry.logarithmic.unwrap();
+            assert_eq!(logarithmic.factor.into_f64(), -1.0);
+            assert_eq!(logarithmic.base.into_f64(), core::f64::consts::E);
+            assert!(!logarithmic.root_power);
+        } else {
+            panic!()
+        }
+        assert!(syn::parse2::<Entry>(quote! {
+            #[logarithmic(factor: 10, base: 10)]
+            #[metric_prefixes]
+            unit bel = 1
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn parse_unit_entry_with_binary_prefixes() {
+        let entry = syn::parse2::<Entry>(quote! {
+            #[binary_prefixes(skip: exbi, pebi)]
+            unit byte = bit
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            assert_eq!(entry.prefixes.len(), 4);
+            assert!(!entry.prefixes.iter().any(|prefix| prefix == "exbi"));
+            assert!(entry.prefixes.iter().any(|prefix| prefix == "kibi"));
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            #[prefix(kibi, mebi)]
+            unit byte = bit
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            assert_eq!(entry.prefixes.len(), 2);
+            assert_eq!(entry.prefixes[1], "mebi");
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn parse_prefix_entry() {
+        let entry = syn::parse2::<Entry>(quote! {
+            prefix myria = 1e4, symbol "my"
+        })
+        .unwrap();
+        if let Entry::Prefix(entry) = entry {
+            assert_eq!(entry.name.to_string(), "myria");
+            assert_eq!(entry.symbol, "my");
+            assert_eq!(entry.factor.into_f64(), 1e4);
+        } else {
+            panic!()
+        }
+        assert!(syn::parse2::<Entry>(quote! {
+            prefix foo = 2, symbol "a b"
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn parse_kind_entry() {
+        let entry = syn::parse2::<Entry>(quote! {
+            kind Torque = Length * Force
+        })
+        .unwrap();
+        if let Entry::Dimension(entry) = entry {
+            assert_eq!(entry.name.to_string(), "Torque");
+            assert!(entry.is_kind);
+        } else {
+            panic!()
+        }
+        assert!(syn::parse2::<Entry>(quote! {
+            kind Angle
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn parse_conversion_entry() {
+        let entry = syn::parse2::<Entry>(quote! {
+            conversion super::si::{Quantity, Dimension} {
+                Length = 100 centimeters;
+                Time = seconds
+            }
+        })
+        .unwrap();
+        if let Entry::Conversion(entry) = entry {
+            assert_eq!(entry.path.segments.len(), 2);
+            assert_eq!(entry.quantity_type.to_string(), "Quantity");
+            assert_eq!(entry.dimension_type.to_string(), "Dimension");
+            assert_eq!(entry.base_dimensions.len(), 2);
+            assert_eq!(
+                entry.base_dimensions[0].base_dimension.to_string(),
+                "Length"
+            );
+        } else {
+            panic!()
+        }
+        assert!(syn::parse2::<Entry>(quote! {
+            conversion si::{Quantity, Dimension} {
+                Length = 100 centimeters;
+                Length = meters
+            }
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn parse_storage_type_entry() {
+        let entry = syn::parse2::<Entry>(quote! {
+            storage_type crate::Float
+        })
+        .unwrap();
+        if let Entry::StorageType(entry) = entry {
+            assert!(entry.vector.is_none());
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            storage_type Vector: vector(f32, 3)
+        })
+        .unwrap();
+        if let Entry::StorageType(entry) = entry {
+            assert_eq!(entry.vector.unwrap().num_dims, 3);
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            storage_type Vector: vector(f32, 4)
+        })
+        .unwrap();
+        if let Entry::StorageType(entry) = entry {
+            assert_eq!(entry.vector.unwrap().num_dims, 4);
+        } else {
+            panic!()
+        }
+        assert!(syn::parse2::<Entry>(quote! {
+            storage_type Vector: vector(f32, 1)
+        })
+        .is_err());
+        assert!(syn::parse2::<Entry>(quote! {
+            storage_type Vector: vector(f32, 5)
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn parse_unit_entry_with_string_symbol() {
+        let entry = syn::parse2::<Entry>(quote! {
+            #[symbol("°C")]
+            unit celsius = kelvin + 273.15
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            assert_eq!(entry.symbol.unwrap().name, "°C");
+        } else {
+            panic!()
+        }
+        let entry = syn::parse2::<Entry>(quote! {
+            #[symbol("°", deg)]
+            unit degree = 0.017453292519943295 radian
+        })
+        .unwrap();
+        if let Entry::Unit(entry) = entry {
+            let symbol = entry.symbol.unwrap();
+            assert_eq!(symbol.name, "°");
+            assert_eq!(symbol.alternatives, ["deg"]);
+        } else {
+            panic!()
+        }
+        assert!(syn::parse2::<Entry>(quote! {
+            #[symbol("m s")]
+            unit foo = meter second
+        })
+        .is_err());
+        assert!(syn::parse2::<Entry>(quote! {
+            #[symbol("m^2")]
+            unit foo = meter^2
+        })
+        .is_err());
+    }
 }
@@ -0,0 +1,73 @@
+use syn::Ident;
+
+use crate::types::{
+    base_dimension::BaseDimension,
+    conversion::{BaseDimensionConversion, Conversion, ConversionEntry},
+    Constant, ConstantEntry,
+};
+
+/// The expressions on the right hand side of a conversion are
+/// resolved like constants. This is the name of the constant
+/// which holds the expression for the given base dimension.
+fn constant_name(conversion_index: usize, base_dimension: &Ident) -> Ident {
+    Ident::new(
+        &format!("__conversion_{}_{}", conversion_index, base_dimension),
+        base_dimension.span(),
+    )
+}
+
+/// Returns the constants which need to be resolved for the conversions.
+pub fn conversion_constants(conversions: &[ConversionEntry]) -> Vec<ConstantEntry> {
+    conversions
+        .iter()
+        .enumerate()
+        .flat_map(|(index, conversion)| {
+            conversion
+                .base_dimensions
+                .iter()
+                .map(move |entry| ConstantEntry {
+                    name: constant_name(index, &entry.base_dimension),
+                    rhs: entry.rhs.clone(),
+                    dimension_annotation: None,
+                })
+        })
+        .collect()
+}
+
+/// Removes the constants belonging to the conversions from the
+/// resolved constants and returns the resolved conversions. Base
+/// dimensions whose expression could not be resolved are skipped,
+/// since an error has already been emitted for them.
+pub fn take_conversions(
+    conversions: Vec<ConversionEntry>,
+    constants: &mut Vec<Constant>,
+) -> Vec<Conversion> {
+    conversions
+        .into_iter()
+        .enumerate()
+        .map(|(index, conversion)| {
+            let base_dimensions = conversion
+                .base_dimensions
+                .iter()
+                .filter_map(|entry| {
+                    let name = constant_name(index, &entry.base_dimension);
+                    let position = constants
+                        .iter()
+                        .position(|constant| constant.name == name)?;
+                    let constant = constants.remove(position);
+                    Some(BaseDimensionConversion {
+                        base_dimension: BaseDimension::from_dimension(&entry.base_dimension),
+                        dimensions: constant.dimensions,
+                        magnitude: constant.magnitude,
+                    })
+                })
+                .collect();
+            Conversion {
+                path: conversion.path,
+                quantity_type: conversion.quantity_type,
+                dimension_type: conversion.dimension_type,
+                base_dimensions,
+            }
+        })
+        .collect()
+}
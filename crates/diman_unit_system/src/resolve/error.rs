@@ -58,12 +58,32 @@ pub struct BaseUnitForNonBaseDimensionError<'a> {
 }
 
 pub struct SymbolDefinedMultipleTimes<'a> {
-    pub symbol: &'a Ident,
+    pub symbol: &'a str,
     pub units: Vec<&'a Ident>,
 }
 
+pub struct BaseDimensionInExtensionError<'a>(pub &'a Ident);
+
 pub struct NoSymbolForBaseUnitError<'a>(pub &'a Unit);
 
+pub struct OffsetUnitInDefinitionError<'a> {
+    pub lhs: &'a Ident,
+    pub rhs: &'a Ident,
+}
+
+pub struct MultiplePrefixDefinitionsError<'a> {
+    pub name: &'a Ident,
+    /// The previous definition, if the prefix is not a built-in prefix.
+    pub previous: Option<&'a Ident>,
+}
+
+pub struct UndefinedPrefixError<'a>(pub &'a Ident);
+
+pub struct LogarithmicUnitInDefinitionError<'a> {
+    pub lhs: &'a Ident,
+    pub rhs: &'a Ident,
+}
+
 pub trait Emit {
     fn emit(self);
 }
@@ -321,3 +341,78 @@ impl Emit for NoSymbolForBaseUnitError<'_> {
             .emit()
     }
 }
+
+impl Emit for OffsetUnitInDefinitionError<'_> {
+    fn emit(self) {
+        Diagnostic::spanned(
+            vec![self.lhs.span().unwrap(), self.rhs.span().unwrap()],
+            Level::Error,
+            format!(
+                "{} is defined in terms of the unit {}, which has an offset.",
+                self.lhs, self.rhs
+            ),
+        )
+        .note("Units with an offset cannot be used in the definition of other units or constants.")
+        .emit()
+    }
+}
+
+impl Emit for LogarithmicUnitInDefinitionError<'_> {
+    fn emit(self) {
+        Diagnostic::spanned(
+            vec![self.lhs.span().unwrap(), self.rhs.span().unwrap()],
+            Level::Error,
+            format!(
+                "{} is defined in terms of the unit {}, which is logarithmic.",
+                self.lhs, self.rhs
+            ),
+        )
+        .note("Logarithmic units cannot be used in the definition of other units or constants.")
+        .emit()
+    }
+}
+
+impl Emit for MultiplePrefixDefinitionsError<'_> {
+    fn emit(self) {
+        match self.previous {
+            Some(previous) => Diagnostic::spanned(
+                vec![previous.span().unwrap(), self.name.span().unwrap()],
+                Level::Error,
+                format!("Prefix {} defined multiple times.", self.name),
+            ),
+            None => Diagnostic::spanned(
+                self.name.span().unwrap(),
+                Level::Error,
+                format!(
+                    "Prefix {} is already defined as a built-in prefix.",
+                    self.name
+                ),
+            ),
+        }
+        .emit()
+    }
+}
+
+impl Emit for UndefinedPrefixError<'_> {
+    fn emit(self) {
+        self.0
+            .span()
+            .unwrap()
+            .error(format!("Undefined prefix {}.", self.0))
+            .emit()
+    }
+}
+
+impl Emit for BaseDimensionInExtensionError<'_> {
+    fn emit(self) {
+        self.0
+            .span()
+            .unwrap()
+            .error(format!(
+                "Base dimension {} cannot be defined in an extension of a unit system.",
+                self.0
+            ))
+            .note("The base dimensions are fixed by the extended unit system.")
+            .emit()
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 
+use diman_lib::logarithmic_scale::LogarithmicScale;
 use proc_macro2::Ident;
 
 use crate::{
@@ -281,6 +282,22 @@ impl IdentStorage {
         }
     }
 
+    /// Tags the dimensions declared as quantity kinds, as well as the units
+    /// and constants annotated with them, with their kind. This happens after
+    /// resolution, so that definitions in terms of kinds (such as
+    /// `dimension AngularVelocity = Angle / Time`) do not inherit the kind.
+    pub(crate) fn assign_quantity_kinds(&mut self, quantity_kinds: &HashMap<Ident, u32>) {
+        for resolved in self.resolved.values_mut() {
+            let kind_name = match resolved.item.kind() {
+                Kind::Dimension => Some(resolved.item.ident()),
+                _ => resolved.item.annotation(),
+            };
+            if let Some(kind) = kind_name.and_then(|name| quantity_kinds.get(name)) {
+                resolved.dimensions.dimensions.kind = Some(*kind);
+            }
+        }
+    }
+
     pub(crate) fn check_kinds_in_definitions(&self) {
         // TODO(minor): Having to collect into a HashMap here is annoying.
         let kinds: HashMap<_, _> = self
@@ -392,6 +409,15 @@ impl FromItem for Unit {
             dimensions: dimensions.dimensions,
             name: unit_entry.name,
             magnitude: dimensions.magnitude,
+            offset: unit_entry
+                .offset
+                .map(|offset| offset * dimensions.magnitude),
+            logarithmic: unit_entry.logarithmic.map(|log| LogarithmicScale {
+                reference: dimensions.magnitude,
+                factor: log.factor,
+                base: log.base,
+                root_power: log.root_power,
+            }),
             symbol: unit_entry.symbol,
             is_base_unit: matches!(unit_entry.definition, Definition::Base(_)),
         }
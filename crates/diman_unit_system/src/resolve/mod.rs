@@ -1,20 +1,24 @@
+mod conversions;
 mod error;
 mod ident_storage;
+pub mod prefixes;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use proc_macro2::Span;
 use syn::Ident;
 
 use crate::types::{
-    base_dimension::BaseDimension, Definition, Defs, DimensionEntry, Unit, UnitEntry,
-    UnresolvedDefs,
+    base_dimension::BaseDimension, Constant, ConstantEntry, Definition, Defs, Dimension,
+    DimensionEntry, Extension, Factor, Unit, UnitEntry, UnresolvedDefs,
 };
 
 use self::{
     error::{
-        BaseUnitForNonBaseDimensionError, Emit, MultipleBaseUnitsForDimensionError,
-        NoSymbolForBaseUnitError, SymbolDefinedMultipleTimes, TypeDefinitionsError,
+        BaseDimensionInExtensionError, BaseUnitForNonBaseDimensionError, Emit,
+        LogarithmicUnitInDefinitionError, MultipleBaseUnitsForDimensionError,
+        NoSymbolForBaseUnitError, OffsetUnitInDefinitionError, SymbolDefinedMultipleTimes,
+        TypeDefinitionsError,
     },
     ident_storage::IdentStorage,
 };
@@ -73,10 +77,20 @@ impl UnresolvedDefs {
         ));
         let mut idents = IdentStorage::default();
         let base_dimensions = get_base_dimensions(&self.dimensions, &self.units);
+        let quantity_kinds = get_quantity_kinds(&self.dimensions);
         check_multiply_defined_symbols(&self.units);
+        let constants: Vec<ConstantEntry> = self
+            .constants
+            .into_iter()
+            .chain(conversions::conversion_constants(&self.conversions))
+            .collect();
+        check_non_multiplicative_units_in_definitions(&self.units, &constants);
+        if let Some(ref extension) = self.extension {
+            check_no_base_dimensions_in_extension(&self.dimensions, extension);
+        }
         idents.add(self.dimensions);
         idents.add(self.units);
-        idents.add(self.constants);
+        idents.add(constants);
         idents.filter_undefined();
         idents.filter_autogenerated_invalid();
         idents.filter_multiply_defined();
@@ -85,21 +99,43 @@ impl UnresolvedDefs {
         idents.filter_autogenerated_invalid();
         idents.resolve();
         idents.check_type_annotations();
-        let dimensions = idents.get_items();
-        let units = idents.get_items();
-        let constants = idents.get_items();
+        idents.assign_quantity_kinds(&quantity_kinds);
+        let mut dimensions: Vec<Dimension> = idents.get_items();
+        let mut units: Vec<Unit> = idents.get_items();
+        let mut constants: Vec<Constant> = idents.get_items();
+        let conversions = conversions::take_conversions(self.conversions, &mut constants);
         check_for_base_units_without_symbol(&units);
+        // The extended system already contains its own definitions,
+        // so only the ones of the extension need to be generated.
+        if let Some(ref extension) = self.extension {
+            dimensions.retain(|dimension| extension.defines(&dimension.name));
+            units.retain(|unit| extension.defines(&unit.name));
+            constants.retain(|constant| extension.defines(&constant.name));
+        }
         Defs {
+            extends: self.extension.map(|extension| extension.path),
             dimension_type,
             quantity_type,
             dimensions,
             units,
             constants,
+            conversions,
+            storage_types: self.storage_types,
             base_dimensions,
         }
     }
 }
 
+/// The dimension type of the extended system is fixed, so
+/// extensions cannot add new base dimensions.
+fn check_no_base_dimensions_in_extension(dimensions: &[DimensionEntry], extension: &Extension) {
+    for dimension in dimensions {
+        if dimension.is_base_dimension() && extension.defines(&dimension.name) {
+            BaseDimensionInExtensionError(&dimension.name).emit();
+        }
+    }
+}
+
 fn check_for_base_units_without_symbol(units: &[Unit]) {
     for unit in units {
         if unit.is_base_unit && unit.symbol.is_none() {
@@ -109,13 +145,12 @@ fn check_for_base_units_without_symbol(units: &[Unit]) {
 }
 
 fn check_multiply_defined_symbols(units: &[UnitEntry]) {
-    let mut units_by_symbol: HashMap<&Ident, Vec<&Ident>> = HashMap::new();
+    let mut units_by_symbol: HashMap<&str, Vec<&Ident>> = HashMap::new();
     for unit in units {
         if let Some(ref symbol) = unit.symbol {
-            units_by_symbol
-                .entry(&symbol.0)
-                .or_default()
-                .push(&unit.name);
+            for symbol in symbol.all() {
+                units_by_symbol.entry(symbol).or_default().push(&unit.name);
+            }
         }
     }
     for (symbol, units) in units_by_symbol {
@@ -125,6 +160,57 @@ fn check_multiply_defined_symbols(units: &[UnitEntry]) {
     }
 }
 
+/// Units with an offset and logarithmic units are not
+/// multiplicative, so they cannot be used to define other units
+/// or constants. The aliases of such a unit inherit its offset
+/// or scale instead.
+fn check_non_multiplicative_units_in_definitions(units: &[UnitEntry], constants: &[ConstantEntry]) {
+    let units_with_offset: HashSet<&Ident> = units
+        .iter()
+        .filter(|unit| unit.has_offset())
+        .map(|unit| &unit.name)
+        .collect();
+    let logarithmic_units: HashSet<&Ident> = units
+        .iter()
+        .filter(|unit| unit.is_logarithmic())
+        .map(|unit| &unit.name)
+        .collect();
+    let unit_definitions = units
+        .iter()
+        .filter(|unit| unit.autogenerated_from.is_none())
+        .filter_map(|unit| match unit.definition {
+            Definition::Expression(ref expr) => Some((&unit.name, expr)),
+            Definition::Base(_) => None,
+        });
+    let constant_definitions = constants
+        .iter()
+        .map(|constant| (&constant.name, &constant.rhs));
+    for (lhs, expr) in unit_definitions.chain(constant_definitions) {
+        for factor in expr.iter_vals() {
+            if let Factor::Other(rhs) = factor {
+                if units_with_offset.contains(rhs) {
+                    OffsetUnitInDefinitionError { lhs, rhs }.emit();
+                } else if logarithmic_units.contains(rhs) {
+                    LogarithmicUnitInDefinitionError { lhs, rhs }.emit();
+                }
+            }
+        }
+    }
+}
+
+/// Numbers the quantity kinds in the order of their declaration,
+/// starting at 1, since 0 denotes the absence of a kind. The order
+/// of declaration ensures that an extension of a unit system assigns
+/// the same numbers to the kinds of the extended system.
+fn get_quantity_kinds(dimensions: &[DimensionEntry]) -> HashMap<Ident, u32> {
+    dimensions
+        .iter()
+        .filter(|dimension| dimension.is_kind)
+        .zip(1..)
+        .map(|(dimension, kind)| (dimension.name.clone(), kind))
+        .collect()
+}
+
 pub fn get_base_dimensions(
     dimensions: &[DimensionEntry],
     units: &[UnitEntry],
@@ -0,0 +1,55 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use syn::Ident;
+
+use crate::types::prefixes::{builtin_prefixes, Prefix, PrefixEntry};
+
+use super::error::{Emit, MultiplePrefixDefinitionsError, UndefinedPrefixError};
+
+/// All prefixes available in a unit system, i.e. the built-in
+/// prefixes and the ones declared via `prefix` statements.
+pub struct PrefixTable {
+    prefixes: HashMap<String, Prefix>,
+}
+
+impl PrefixTable {
+    pub fn new(entries: &[PrefixEntry]) -> Self {
+        let mut prefixes: HashMap<String, Prefix> = builtin_prefixes()
+            .map(|prefix| (prefix.name().to_string(), prefix))
+            .collect();
+        let mut declared: HashMap<String, &Ident> = HashMap::new();
+        for entry in entries {
+            let name = entry.name.to_string();
+            if let Some(previous) = declared.get(&name) {
+                MultiplePrefixDefinitionsError {
+                    name: &entry.name,
+                    previous: Some(previous),
+                }
+                .emit();
+                continue;
+            }
+            match prefixes.entry(name.clone()) {
+                Entry::Occupied(_) => MultiplePrefixDefinitionsError {
+                    name: &entry.name,
+                    previous: None,
+                }
+                .emit(),
+                Entry::Vacant(vacant) => {
+                    declared.insert(name, &entry.name);
+                    vacant.insert(entry.into());
+                }
+            }
+        }
+        Self { prefixes }
+    }
+
+    /// Look up the prefix with the given name and emit an
+    /// error if it does not exist.
+    pub fn get(&self, name: &Ident) -> Option<Prefix> {
+        let prefix = self.prefixes.get(&name.to_string()).cloned();
+        if prefix.is_none() {
+            UndefinedPrefixError(name).emit();
+        }
+        prefix
+    }
+}
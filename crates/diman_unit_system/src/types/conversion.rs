@@ -0,0 +1,42 @@
+use diman_lib::magnitude::Magnitude;
+use syn::{Ident, Path};
+
+use crate::dimension_math::BaseDimensions;
+
+use super::{base_dimension::BaseDimension, expression::Expr, Exponent, Factor};
+
+/// A conversion between this unit system and another one,
+/// declared via
+/// `conversion path::to::system::{Quantity, Dimension} { ... }`.
+pub struct ConversionEntry {
+    /// The path to the module of the other system.
+    pub path: Path,
+    pub quantity_type: Ident,
+    pub dimension_type: Ident,
+    pub base_dimensions: Vec<BaseDimensionConversionEntry>,
+}
+
+/// The correspondence between a base dimension of the other
+/// system and a quantity of this system, such as
+/// `Length = 100 centimeters`, which states that the base unit of
+/// length of the other system equals 100 centimeters.
+pub struct BaseDimensionConversionEntry {
+    pub base_dimension: Ident,
+    pub rhs: Expr<Factor<Magnitude>, Exponent>,
+}
+
+pub struct Conversion {
+    pub path: Path,
+    pub quantity_type: Ident,
+    pub dimension_type: Ident,
+    pub base_dimensions: Vec<BaseDimensionConversion>,
+}
+
+pub struct BaseDimensionConversion {
+    /// The base dimension of the other system.
+    pub base_dimension: BaseDimension,
+    /// The dimensions in this system which correspond to the base dimension.
+    pub dimensions: BaseDimensions,
+    /// The base unit of the other system, in base units of this system.
+    pub magnitude: Magnitude,
+}
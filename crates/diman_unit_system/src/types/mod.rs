@@ -1,17 +1,23 @@
 pub mod base_dimension;
+pub mod conversion;
 pub mod expression;
 pub mod prefixes;
+pub mod storage_type;
 
-use diman_lib::magnitude::Magnitude;
+use std::collections::HashSet;
+
+use diman_lib::{logarithmic_scale::LogarithmicScale, magnitude::Magnitude};
 use proc_macro2::Span;
 use syn::*;
 
 use self::{
     base_dimension::BaseDimension,
+    conversion::{Conversion, ConversionEntry},
     expression::{BinaryOperator, Expr, Operator},
-    prefixes::Prefix,
+    prefixes::{Prefix, PrefixEntry},
+    storage_type::StorageTypeEntry,
 };
-use crate::dimension_math::BaseDimensions;
+use crate::{dimension_math::BaseDimensions, resolve::prefixes::PrefixTable};
 
 #[cfg(feature = "rational-dimensions")]
 pub use diman_lib::ratio::Ratio as Exponent;
@@ -46,6 +52,10 @@ pub type DimensionFactor = Factor<One>;
 pub struct DimensionEntry {
     pub name: Ident,
     pub rhs: Definition<(), One>,
+    /// Whether the dimension is declared as a quantity kind via
+    /// `kind Name = ...`, which makes it distinct from other dimensions
+    /// with the same base dimensions.
+    pub is_kind: bool,
 }
 
 impl DimensionEntry {
@@ -67,8 +77,30 @@ pub struct Alias {
 #[derive(Clone)]
 pub struct One;
 
+/// The scale of a logarithmic unit, as given in the
+/// `#[logarithmic(...)]` attribute.
+#[derive(Clone, Copy)]
+pub struct LogarithmicAttribute {
+    pub factor: Magnitude,
+    pub base: Magnitude,
+    pub root_power: bool,
+}
+
+/// The symbol of a unit, which is used in the debug output
+/// and for parsing quantities via serde. The alternative symbols
+/// are only accepted when parsing.
 #[derive(Clone)]
-pub struct Symbol(pub Ident);
+pub struct Symbol {
+    pub name: String,
+    pub alternatives: Vec<String>,
+}
+
+impl Symbol {
+    /// The symbol and its alternatives.
+    pub fn all(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(self.name.as_str()).chain(self.alternatives.iter().map(String::as_str))
+    }
+}
 
 #[derive(Clone)]
 pub struct ConstantEntry {
@@ -82,9 +114,16 @@ pub struct UnitTemplate {
     pub name: Ident,
     pub symbol: Option<Symbol>,
     pub aliases: Vec<Alias>,
-    pub prefixes: Vec<Prefix>,
+    /// The names of the prefixes, which are resolved when
+    /// expanding the template.
+    pub prefixes: Vec<Ident>,
     pub dimension_annotation: Option<Ident>,
     pub definition: Definition<Ident, Magnitude>,
+    /// The offset of an affine unit, in units of the defining expression.
+    pub offset: Option<Magnitude>,
+    /// For logarithmic units, the defining expression is the
+    /// reference quantity.
+    pub logarithmic: Option<LogarithmicAttribute>,
 }
 
 #[derive(Clone)]
@@ -93,9 +132,21 @@ pub struct UnitEntry {
     pub symbol: Option<Symbol>,
     pub dimension_annotation: Option<Ident>,
     pub definition: Definition<Ident, Magnitude>,
+    pub offset: Option<Magnitude>,
+    pub logarithmic: Option<LogarithmicAttribute>,
     pub autogenerated_from: Option<Ident>,
 }
 
+impl UnitEntry {
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    pub fn is_logarithmic(&self) -> bool {
+        self.logarithmic.is_some()
+    }
+}
+
 impl UnitTemplate {
     fn format_name(&self, prefix: Option<&Prefix>, alias: Option<&Alias>) -> Ident {
         let name = match alias {
@@ -115,11 +166,13 @@ impl UnitTemplate {
         } else {
             match prefix {
                 None => self.symbol.clone(),
-                Some(prefix) => self.symbol.as_ref().map(|symbol| {
-                    Symbol(Ident::new(
-                        &format!("{}{}", prefix.short(), symbol.0),
-                        self.name.span(),
-                    ))
+                Some(prefix) => self.symbol.as_ref().map(|symbol| Symbol {
+                    name: format!("{}{}", prefix.short(), symbol.name),
+                    alternatives: symbol
+                        .alternatives
+                        .iter()
+                        .map(|alternative| format!("{}{}", prefix.short(), alternative))
+                        .collect(),
                 }),
             }
         }
@@ -132,7 +185,7 @@ impl UnitTemplate {
     ) -> Definition<Ident, Magnitude> {
         let factor = prefix
             .map(|prefix| prefix.factor())
-            .unwrap_or(Magnitude::from_f64(1.0));
+            .unwrap_or(Magnitude::one());
         if alias.is_none() && prefix.is_none() {
             self.definition.clone()
         } else {
@@ -160,12 +213,19 @@ impl UnitTemplate {
             symbol,
             definition,
             dimension_annotation: self.dimension_annotation.clone(),
+            offset: self.offset,
+            logarithmic: self.logarithmic,
             autogenerated_from,
         }
     }
 
-    fn expand(mut self) -> Vec<UnitEntry> {
-        let mut prefixes: Vec<_> = self.prefixes.drain(..).map(Some).collect();
+    fn expand(mut self, prefix_table: &PrefixTable) -> Vec<UnitEntry> {
+        let mut prefixes: Vec<_> = self
+            .prefixes
+            .drain(..)
+            .filter_map(|name| prefix_table.get(&name))
+            .map(Some)
+            .collect();
         prefixes.push(None);
         let mut aliases: Vec<_> = self.aliases.drain(..).map(Some).collect();
         aliases.push(None);
@@ -180,12 +240,32 @@ impl UnitTemplate {
     }
 }
 
+/// An extension of an existing unit system, declared
+/// via `extends path::to::system;`.
+pub struct Extension {
+    /// The path to the module of the extended system.
+    pub path: Path,
+    /// The names of the dimensions, units, constants and unit templates
+    /// which are defined in the extension (as opposed to the extended system).
+    pub names: HashSet<String>,
+}
+
+impl Extension {
+    pub fn defines(&self, name: &Ident) -> bool {
+        self.names.contains(&name.to_string())
+    }
+}
+
 pub struct Unresolved<U> {
     pub dimension_types: Vec<Ident>,
     pub quantity_types: Vec<Ident>,
     pub dimensions: Vec<DimensionEntry>,
     pub units: Vec<U>,
     pub constants: Vec<ConstantEntry>,
+    pub prefixes: Vec<PrefixEntry>,
+    pub conversions: Vec<ConversionEntry>,
+    pub storage_types: Vec<StorageTypeEntry>,
+    pub extension: Option<Extension>,
 }
 
 pub type UnresolvedTemplates = Unresolved<UnitTemplate>;
@@ -193,17 +273,31 @@ pub type UnresolvedDefs = Unresolved<UnitEntry>;
 
 impl UnresolvedTemplates {
     pub fn expand_templates(self) -> UnresolvedDefs {
-        let units = self
+        let prefix_table = PrefixTable::new(&self.prefixes);
+        let units: Vec<UnitEntry> = self
             .units
             .into_iter()
-            .flat_map(|template| template.expand())
+            .flat_map(|template| template.expand(&prefix_table))
             .collect();
+        let extension = self.extension.map(|mut extension| {
+            let expanded_names: Vec<_> = units
+                .iter()
+                .filter(|unit| extension.defines(unit.template_name()))
+                .map(|unit| unit.name.to_string())
+                .collect();
+            extension.names.extend(expanded_names);
+            extension
+        });
         UnresolvedDefs {
             dimension_types: self.dimension_types,
             quantity_types: self.quantity_types,
             dimensions: self.dimensions,
             units,
             constants: self.constants,
+            prefixes: self.prefixes,
+            conversions: self.conversions,
+            storage_types: self.storage_types,
+            extension,
         }
     }
 }
@@ -218,6 +312,9 @@ pub struct Unit {
     pub name: Ident,
     pub dimensions: BaseDimensions,
     pub magnitude: Magnitude,
+    /// The offset of an affine unit, in base units.
+    pub offset: Option<Magnitude>,
+    pub logarithmic: Option<LogarithmicScale>,
     pub symbol: Option<Symbol>,
     pub is_base_unit: bool,
 }
@@ -229,14 +326,27 @@ pub struct Constant {
 }
 
 pub struct Defs {
+    /// If set, only the definitions of the extension are
+    /// contained here and the remaining types are taken from
+    /// the extended system at this path.
+    pub extends: Option<Path>,
     pub dimension_type: Ident,
     pub quantity_type: Ident,
     pub dimensions: Vec<Dimension>,
     pub units: Vec<Unit>,
     pub constants: Vec<Constant>,
+    pub conversions: Vec<Conversion>,
+    pub storage_types: Vec<StorageTypeEntry>,
     pub base_dimensions: Vec<BaseDimension>,
 }
 
+impl UnitEntry {
+    /// The name of the template this unit entry was generated from.
+    pub fn template_name(&self) -> &Ident {
+        self.autogenerated_from.as_ref().unwrap_or(&self.name)
+    }
+}
+
 impl Defs {
     pub(crate) fn base_dimensions(&self) -> impl Iterator<Item = &Ident> {
         self.base_dimensions.iter().map(|x| &x.0)
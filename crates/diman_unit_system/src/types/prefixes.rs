@@ -1,108 +1,125 @@
 use diman_lib::magnitude::Magnitude;
+use proc_macro2::Span;
+use syn::Ident;
 
-macro_rules! make_prefix_enum {
-    ($enum_name: ident, $(($variant_name: ident, $lowercase_name: ident, $name: literal, $short: literal, $factor: literal)),*) => {
-        #[derive(Clone, Copy, PartialEq)]
-        pub enum $enum_name {
-            $(
-                $variant_name,
-            )*
-        }
+/// A prefix which generates a scaled version of a unit,
+/// such as `kilo` for `kilometers`.
+#[derive(Clone, PartialEq)]
+pub struct Prefix {
+    name: String,
+    short: String,
+    factor: Magnitude,
+}
 
-        impl $enum_name {
-            pub fn name(self) -> &'static str {
-                match self {
-                    $(
-                        Self::$variant_name => $name,
-                    )*
-                }
-            }
-
-            pub fn short(self) -> &'static str {
-                match self {
-                    $(
-                        Self::$variant_name => $short,
-                    )*
-                }
-            }
-
-            pub fn factor(self) -> Magnitude {
-                match self {
-                    $(
-                        Self::$variant_name => Magnitude::from_f64($factor),
-                    )*
-                }
-            }
+impl Prefix {
+    pub fn new(name: &str, short: &str, factor: Magnitude) -> Self {
+        Self {
+            name: name.to_string(),
+            short: short.to_string(),
+            factor,
         }
+    }
 
-        impl ::syn::parse::Parse for $enum_name {
-            fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-                mod kws {
-                    $(
-                        syn::custom_keyword!($lowercase_name);
-                    )*
-                }
-                let lookahead = input.lookahead1();
-                $(
-                    if lookahead.peek(kws::$lowercase_name) {
-                        let _: kws::$lowercase_name = input.parse()?;
-                        return Ok(Self::$variant_name);
-                    }
-                )*
-                Err(lookahead.error())
-            }
-        }
+    pub fn name(&self) -> &str {
+        &self.name
     }
+
+    pub fn short(&self) -> &str {
+        &self.short
+    }
+
+    pub fn factor(&self) -> Magnitude {
+        self.factor
+    }
+}
+
+/// The prefixes that are available without being declared
+/// in the unit system, given by their name, symbol and factor.
+const BUILTIN_PREFIXES: &[(&str, &str, Magnitude)] = &[
+    ("quetta", "Q", Magnitude::from_decimal(1, 30)),
+    ("ronna", "R", Magnitude::from_decimal(1, 27)),
+    ("exa", "E", Magnitude::from_decimal(1, 18)),
+    ("peta", "P", Magnitude::from_decimal(1, 15)),
+    ("tera", "T", Magnitude::from_decimal(1, 12)),
+    ("giga", "G", Magnitude::from_decimal(1, 9)),
+    ("mega", "M", Magnitude::from_decimal(1, 6)),
+    ("kilo", "k", Magnitude::from_decimal(1, 3)),
+    ("hecto", "h", Magnitude::from_decimal(1, 2)),
+    ("deca", "da", Magnitude::from_decimal(1, 1)),
+    ("deci", "d", Magnitude::from_decimal(1, -1)),
+    ("centi", "c", Magnitude::from_decimal(1, -2)),
+    ("milli", "m", Magnitude::from_decimal(1, -3)),
+    ("micro", "μ", Magnitude::from_decimal(1, -6)),
+    ("nano", "n", Magnitude::from_decimal(1, -9)),
+    ("pico", "p", Magnitude::from_decimal(1, -12)),
+    ("femto", "f", Magnitude::from_decimal(1, -15)),
+    ("atto", "a", Magnitude::from_decimal(1, -18)),
+    ("ronto", "r", Magnitude::from_decimal(1, -27)),
+    ("quecto", "q", Magnitude::from_decimal(1, -30)),
+    ("exbi", "Ei", Magnitude::from_power_of_two(60)),
+    ("pebi", "Pi", Magnitude::from_power_of_two(50)),
+    ("tebi", "Ti", Magnitude::from_power_of_two(40)),
+    ("gibi", "Gi", Magnitude::from_power_of_two(30)),
+    ("mebi", "Mi", Magnitude::from_power_of_two(20)),
+    ("kibi", "Ki", Magnitude::from_power_of_two(10)),
+];
+
+pub const METRIC_PREFIXES: &[&str] = &[
+    "exa", "peta", "tera", "giga", "mega", "kilo", "hecto", "deca", "deci", "centi", "milli",
+    "micro", "nano", "pico", "femto", "atto",
+];
+
+pub const BINARY_PREFIXES: &[&str] = &["exbi", "pebi", "tebi", "gibi", "mebi", "kibi"];
+
+pub fn builtin_prefixes() -> impl Iterator<Item = Prefix> {
+    BUILTIN_PREFIXES
+        .iter()
+        .map(|(name, short, factor)| Prefix::new(name, short, *factor))
 }
 
-make_prefix_enum! {
-    Prefix,
-    (Exa, exa, "exa",  "E",  1e18),
-    (Peta, peta, "peta",  "P",  1e15),
-    (Tera, tera, "tera",  "T",  1e12),
-    (Giga, giga, "giga",  "G",  1e9),
-    (Mega, mega, "mega",  "M",  1e6),
-    (Kilo, kilo, "kilo",  "k",  1e3),
-    (Hecto, hecto, "hecto",  "h",  1e2),
-    (Deca, deca, "deca",  "da",  1e1),
-    (Deci, deci, "deci",  "d",  1e-1),
-    (Centi, centi, "centi",  "c",  1e-2),
-    (Milli, milli, "milli",  "m",  1e-3),
-    (Micro, micro, "micro",  "μ",  1e-6),
-    (Nano, nano, "nano",  "n",  1e-9),
-    (Pico, pico, "pico",  "p",  1e-12),
-    (Femto, femto, "femto",  "f",  1e-15),
-    (Atto, atto, "atto",  "a",  1e-18)
+/// A prefix declared in the unit system via
+/// `prefix myria = 1e4, symbol "my";`
+#[derive(Clone)]
+pub struct PrefixEntry {
+    pub name: Ident,
+    pub symbol: String,
+    pub factor: Magnitude,
+}
+
+impl From<&PrefixEntry> for Prefix {
+    fn from(entry: &PrefixEntry) -> Self {
+        Prefix::new(&entry.name.to_string(), &entry.symbol, entry.factor)
+    }
+}
+
+fn prefix_names_without(names: &[&str], skip: &[Ident], span: Span) -> Vec<Ident> {
+    names
+        .iter()
+        .filter(|name| !skip.iter().any(|skipped| skipped == *name))
+        .map(|name| Ident::new(name, span))
+        .collect()
 }
 
 pub struct MetricPrefixes {
-    pub skip: Vec<Prefix>,
+    pub skip: Vec<Ident>,
+    pub span: Span,
 }
 
-impl From<MetricPrefixes> for Vec<Prefix> {
+impl From<MetricPrefixes> for Vec<Ident> {
     fn from(def: MetricPrefixes) -> Self {
-        vec![
-            Prefix::Exa,
-            Prefix::Peta,
-            Prefix::Tera,
-            Prefix::Giga,
-            Prefix::Mega,
-            Prefix::Kilo,
-            Prefix::Hecto,
-            Prefix::Deca,
-            Prefix::Deci,
-            Prefix::Centi,
-            Prefix::Milli,
-            Prefix::Micro,
-            Prefix::Nano,
-            Prefix::Pico,
-            Prefix::Femto,
-            Prefix::Atto,
-        ]
-        .into_iter()
-        .filter(|prefix| !def.skip.contains(prefix))
-        .collect()
+        prefix_names_without(METRIC_PREFIXES, &def.skip, def.span)
+    }
+}
+
+pub struct BinaryPrefixes {
+    pub skip: Vec<Ident>,
+    pub span: Span,
+}
+
+impl From<BinaryPrefixes> for Vec<Ident> {
+    fn from(def: BinaryPrefixes) -> Self {
+        prefix_names_without(BINARY_PREFIXES, &def.skip, def.span)
     }
 }
 
-pub struct ExplicitPrefixes(pub Vec<Prefix>);
+pub struct ExplicitPrefixes(pub Vec<Ident>);
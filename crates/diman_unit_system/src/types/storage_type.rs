@@ -0,0 +1,18 @@
+use syn::Type;
+
+/// A storage type which is owned by the user, declared via
+/// `storage_type MyFloat;` or `storage_type MyVec3: vector(f32, 3);`.
+/// Impls are generated for it just like for the storage types
+/// enabled via features.
+#[derive(Clone)]
+pub struct StorageTypeEntry {
+    pub name: Type,
+    pub vector: Option<VectorStorage>,
+}
+
+/// The components of a vector storage type.
+#[derive(Clone)]
+pub struct VectorStorage {
+    pub float_type: Type,
+    pub num_dims: usize,
+}
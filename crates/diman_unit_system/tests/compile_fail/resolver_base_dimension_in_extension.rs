@@ -0,0 +1,18 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+
+use diman_unit_system::unit_system_internal;
+
+unit_system_internal!(
+    extends base_system {
+        dimension Information;
+    }
+    quantity_type Quantity;
+    dimension_type Dimension;
+    dimension Length;
+    #[base(Length)]
+    #[symbol(m)]
+    unit meters;
+);
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+
+use diman_unit_system::unit_system_internal;
+
+unit_system_internal!(
+    quantity_type Quantity;
+    dimension_type Dimension;
+    dimension Length;
+    prefix myria = 1e4, symbol "my";
+    prefix myria = 1e4, symbol "ma";
+    prefix kilo = 1e3, symbol "k";
+    #[base(Length)]
+    #[prefix(myria)]
+    #[symbol(m)]
+    unit meters;
+);
+
+fn main() {}
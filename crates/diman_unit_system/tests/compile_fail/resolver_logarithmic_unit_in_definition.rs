@@ -0,0 +1,19 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+
+use diman_unit_system::unit_system_internal;
+
+unit_system_internal!(
+    quantity_type Quantity;
+    dimension_type Dimension;
+    dimension Power;
+    #[base(Power)]
+    #[symbol(W)]
+    unit watt: Power;
+    #[logarithmic(factor: 10, base: 10)]
+    unit decibel_watt: Power = watt;
+    unit milli_decibel_watt: Power = 1e-3 * decibel_watt;
+    constant REFERENCE = 0 * decibel_watt;
+);
+
+fn main() {}
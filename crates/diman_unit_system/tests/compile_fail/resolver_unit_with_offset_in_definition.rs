@@ -0,0 +1,18 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+
+use diman_unit_system::unit_system_internal;
+
+unit_system_internal!(
+    quantity_type Quantity;
+    dimension_type Dimension;
+    dimension Temperature;
+    #[base(Temperature)]
+    #[symbol(K)]
+    unit kelvin: Temperature;
+    unit celsius: Temperature = kelvin + 273.15;
+    unit millicelsius: Temperature = 1e-3 * celsius;
+    constant FREEZING = 0 * celsius;
+);
+
+fn main() {}
@@ -0,0 +1,35 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+
+use diman_unit_system::unit_system_internal;
+
+unit_system_internal!(
+    quantity_type Quantity;
+    dimension_type Dimension;
+    dimension Length;
+    dimension Mass;
+    dimension Time;
+    dimension Energy = Mass * Length^2 / Time^2;
+    kind Torque = Mass * Length^2 / Time^2;
+    #[base(Length)]
+    #[symbol(m)]
+    unit meters;
+    #[base(Mass)]
+    #[symbol(kg)]
+    unit kilograms;
+    #[base(Time)]
+    #[symbol(s)]
+    unit seconds;
+    #[symbol(J)]
+    unit joules: Energy = kilograms meters^2 / seconds^2;
+    #[symbol(Nm)]
+    unit newton_meters: Torque = kilograms meters^2 / seconds^2;
+);
+
+fn main() {
+    use crate::dimensions::{Energy, Torque};
+    use crate::units::{joules, newton_meters};
+    let energy: Energy<f64> = newton_meters.new(1.0);
+    let torque: Torque<f64> = joules.new(1.0);
+    let sum = joules.new(1.0) + newton_meters.new(1.0);
+}
@@ -0,0 +1,8 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+pub mod example_system;
+use example_system::units::meters;
+
+fn main() {
+    let x = meters.new_point(1.0) + meters.new_point(2.0);
+}
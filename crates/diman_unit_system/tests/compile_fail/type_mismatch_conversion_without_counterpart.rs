@@ -0,0 +1,42 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+
+use diman_unit_system::unit_system_internal;
+
+mod si {
+    use super::unit_system_internal;
+
+    unit_system_internal!(
+        quantity_type Quantity;
+        dimension_type Dimension;
+        dimension Length;
+        dimension Current;
+        #[base(Length)]
+        #[symbol(m)]
+        unit meters;
+        #[base(Current)]
+        #[symbol(A)]
+        unit amperes;
+    );
+}
+
+mod cgs {
+    use super::unit_system_internal;
+
+    unit_system_internal!(
+        quantity_type Quantity;
+        dimension_type Dimension;
+        dimension Length;
+        #[base(Length)]
+        #[symbol(cm)]
+        unit centimeters;
+        conversion super::si::{Quantity, Dimension} {
+            Length = 100 centimeters;
+        };
+    );
+}
+
+fn main() {
+    let length: cgs::dimensions::Length<f64> = si::units::meters.new(1.0f64).into();
+    let current = cgs::Quantity::<f64, { cgs::Dimension::none() }>::from(si::units::amperes.new(1.0f64));
+}
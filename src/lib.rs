@@ -81,9 +81,18 @@
 //! * Systems of dimensions and units can be user defined via the `unit_system!` macro. This gives the user complete freedom over the choice of dimensions and makes them part of the user's library, so that arbitrary new methods can be implemented on them.
 //! * The `rational-dimensions` features allows the usage of quantities and units with rational exponents.
 //! * `f32` and `f64` float storage types (behind the `f32` and `f64` feature gate respectively).
+//! * `i32`, `i64`, `u32` and `u64` integer storage types and fixed-point storage types via [`fixed`](https://crates.io/crates/fixed) (behind the `i32`, `i64`, `u32`, `u64` and `fixed` feature gates respectively).
 //! * The `std` feature is enabled by default. If disabled, Diman will be a `no_std` crate, thus suitable for use on embedded devices such as GPU device kernels.
 //! * The `num-traits-libm` feature uses [libm](https://crates.io/crates/libm) to provide math functions in `no_std` environments. While one can use libm in `std`, the libm implementations are generally slower so this is unlikely to be desirable.
-//! * Vector storage types via [`glam`](https://crates.io/crates/glam/) (behind the `glam-vec2`, `glam-vec3`, `glam-dvec2` and `glam-dvec3` features).
+//! * Vector storage types via [`glam`](https://crates.io/crates/glam/) (behind the `glam-vec2`, `glam-vec3`, `glam-vec4`, `glam-dvec2`, `glam-dvec3` and `glam-dvec4` features) and matrix storage types (behind the `glam-mat2`, `glam-mat3` and `glam-dmat3` features).
+//! * Exact decimal and rational storage types via [`rust_decimal`](https://crates.io/crates/rust_decimal) and [`num-rational`](https://crates.io/crates/num-rational) (behind the `rust_decimal` and `num-rational` feature gates respectively).
+//! * Half-precision float storage types `f16` and `bf16` via [`half`](https://crates.io/crates/half) (behind the `half` feature gate).
+//! * Complex storage types via [`num-complex`](https://crates.io/crates/num-complex) (behind the `num-complex` feature gate).
+//! * Static and dynamic vector storage types via [`nalgebra`](https://crates.io/crates/nalgebra) (behind the `nalgebra` feature gate).
+//! * Array storage types via [`ndarray`](https://crates.io/crates/ndarray) (behind the `ndarray` feature gate).
+//! * Measured values with a standard uncertainty as storage types (behind the `measurement` feature gate).
+//! * Intervals with guaranteed bounds as storage types (behind the `interval` feature gate).
+//! * Dual numbers for forward-mode automatic differentiation as storage types (behind the `dual` feature gate).
 //! * Serialization and Deserialization via [`serde`](https://crates.io/crates/serde) (behind the `serde` feature gate, see the official documentation for more info).
 //! * HDF5 support using [`hdf5-rs`](https://crates.io/crates/hdf5-rs/) (behind the `hdf5` feature gate).
 //! * Quantities implement the `Equivalence` trait so that they can be sent via MPI using [`mpi`](https://crates.io/crates/mpi) (behind the `mpi` feature gate).
@@ -160,6 +169,35 @@
 //! let l2 = 5.0f64 * kilometers;
 //! let x = (l1 / l2).powf(2.71);
 //! ```
+//! For `f32` and `f64` quantities, `hypot`, `mul_add`, `recip`, `signum`, `copysign`, `rem_euclid` (and `%`), `is_finite`, `is_infinite` and `total_cmp` work as for the float types and take the dimensions into account. `atan2` returns an `Angle` in the SI system, and `Angle` provides `to_degrees` and `to_radians`. The constants `NAN`, `INFINITY`, `EPSILON`, `MIN` and `MAX` are available for every dimension:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+//! # {
+//! # use diman::si::dimensions::{Angle, Energy, Length};
+//! # use diman::si::units::{joules, meters, newtons};
+//! let x = 3.0f64 * meters;
+//! let y = 4.0f64 * meters;
+//! assert_eq!(x.hypot(y), 5.0 * meters);
+//! let energy: Energy<f64> = (2.0 * newtons).mul_add(x, 1.0 * joules);
+//! assert_eq!(energy, 7.0 * joules);
+//! let angle: Angle<f64> = x.atan2(x);
+//! assert_eq!(angle.to_degrees(), 45.0);
+//! assert_eq!((10.0 * meters) % x, 1.0 * meters);
+//! assert!(Length::<f64>::NAN.is_nan());
+//! # }
+//! ```
+//! Like the float types, float quantities are only partially ordered. To sort them or to use them as keys of a `BTreeMap` or a `HashSet`, they can be wrapped in `diman::ordered::Ordered`, which compares them via `total_cmp`. Quantities with totally ordered storage types, such as integers, implement `Ord` and `Hash` directly:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # use diman::si::units::{milliseconds, seconds};
+//! use diman::ordered::Ordered;
+//! let mut events = [(2.0 * seconds, "b"), (500.0 * milliseconds, "a")];
+//! events.sort_by_key(|(time, _)| Ordered(*time));
+//! assert_eq!(events[0].1, "a");
+//! ```
 //! ## Creation and conversion
 //! New quantities can be created either by multiplying with a unit, or by calling the `.new` function on the unit:
 //! ```
@@ -179,8 +217,14 @@
 //! assert_eq!(v1, 1.0 * meters_per_second);
 //! assert_eq!(v2, 1.0 * meters_per_second);
 //! ```
-//! Note that at the moment, the creation of quantities via units defined in this composite way incurs
-//! a small performance overhead compared to creation from just a single unit (which is just a single multiplication). This will be fixed once [const_fn_floating_point_arithmetic](https://github.com/rust-lang/rust/issues/57241) or a similar feature is stabilized.
+//! The factors of composite units are computed at compile time, so creating a quantity via a composite unit is just a single multiplication, as it is for any other unit. Units can also be raised to integer powers via `powi`:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # use diman::si::units::{meters, square_meters};
+//! let area = meters.powi::<2>().new(4.0);
+//! assert_eq!(area, 4.0 * square_meters);
+//! ```
 //!
 //! Conversion into the underlying storage type can be done using the `value_in` function:
 //! ```
@@ -252,6 +296,202 @@
 //!        .map(|x| Length::new_unchecked(x))
 //!        .collect();
 //! ```
+//! ## Integer and fixed-point storage types
+//! The `i32`, `i64`, `u32` and `u64` features enable integer storage types and the `fixed` feature enables the fixed-point types `I16F16`, `I32F32`, `U16F16` and `U32F32` of the [`fixed`](https://crates.io/crates/fixed) crate. Since quantities are stored in base units, an integer quantity cannot hold values such as 2500 millivolts, which are not a whole number of volts. `new` and `value_in` panic if the result is not an integer or out of range of the storage type. `new_checked` and `value_in_checked` return `None` instead, and `new_exact` and `value_in_exact` return an `InexactConversion` error. The `_wrapping` and `_saturating` variants wrap around or clamp out of range results and round to the nearest integer (with ties rounded away from zero). Fixed-point conversions always round to the nearest multiple of the resolution, as float conversions do:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "i32")]
+//! # {
+//! # use diman::si::dimensions::Voltage;
+//! # use diman::si::units::{kilovolts, millivolts, volts};
+//! let voltage: Voltage<i32> = millivolts.new(3000);
+//! assert_eq!(voltage.value_in(volts), 3);
+//! assert_eq!(millivolts.new_checked(2500), None);
+//! assert_eq!(millivolts.new_saturating(2500).value_in(volts), 3);
+//! assert_eq!(kilovolts.new_checked(3_000_000), None);
+//! assert_eq!(kilovolts.new_saturating(3_000_000).value_in(volts), i32::MAX);
+//! let voltage = volts.new(3_000_000);
+//! assert_eq!(voltage.value_in_checked(millivolts), None);
+//! # }
+//! ```
+//! Float-specific methods such as `sqrt` or `sin` are not available for these types.
+//!
+//! ## Exact storage types
+//! The `rust_decimal` and `num-rational` features enable `Decimal` of the [`rust_decimal`](https://crates.io/crates/rust_decimal) crate and `Ratio<i64>` of the [`num-rational`](https://crates.io/crates/num-rational) crate as storage types, for example for billing, where rounding errors must not accumulate. Unit conversions are never rounded: decimals convert exactly whenever the unit factor is a finite decimal fraction, such as the `3.6e6` joules of a kilowatt hour, and rationals whenever the result fits into 64 bits. `new_exact` and `value_in_exact` return an `InexactConversion` error otherwise, while `new` and `value_in` panic:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "rust_decimal")]
+//! # {
+//! # use diman::si::dimensions::{Energy, Time};
+//! # use diman::si::units::{hours, joules, kilowatts, minutes, seconds};
+//! use rust_decimal::Decimal;
+//! use diman::scaling::InexactConversion;
+//! let kilowatt_hours = kilowatts * hours;
+//! let energy: Energy<Decimal> = kilowatt_hours.new(Decimal::new(15, 1));
+//! assert_eq!(energy.value_in(joules), Decimal::new(5_400_000, 0));
+//! let time: Time<Decimal> = seconds.new(Decimal::new(100, 0));
+//! assert_eq!(time.value_in_exact(minutes), Err(InexactConversion));
+//! # }
+//! ```
+//!
+//! ## Half-precision storage types
+//! The `half` feature enables the half-precision floats `f16` and `bf16` of the [`half`](https://crates.io/crates/half) crate as storage types, for example to store large fields of quantities compactly. Unit conversions and float methods such as `sqrt` are computed in `f32` and rounded back, so that units whose factor is too small for an `f16`, such as micrometers, still convert accurately:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "half")]
+//! # {
+//! # use diman::si::dimensions::Length;
+//! # use diman::si::units::micrometers;
+//! use half::f16;
+//! let x: Length<f16> = micrometers.new(f16::from_f32(250.0));
+//! assert!((x.value_in(micrometers).to_f32() - 250.0).abs() <= 0.125);
+//! # }
+//! ```
+//!
+//! ## Complex storage types
+//! The `num-complex` feature enables `Complex<f32>` and `Complex<f64>` of the [`num-complex`](https://crates.io/crates/num-complex) crate as storage types, for example for impedances or phasors. Complex quantities can be combined with real quantities of the same precision. `norm` returns a real quantity of the same dimension, while the angle returned by `arg` is dimensionless:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "num-complex")]
+//! # {
+//! # use diman::si::dimensions::{Current, ElectricResistance};
+//! # use diman::si::units::{amperes, ohms, volts};
+//! use num_complex::Complex;
+//! let impedance: ElectricResistance<Complex<f64>> = Complex::new(3.0, 4.0) * ohms;
+//! let current: Current<f64> = 2.0 * amperes;
+//! let voltage = impedance * current;
+//! assert_eq!(voltage.value_in(volts), Complex::new(6.0, 8.0));
+//! assert_eq!(voltage.norm().value_in(volts), 10.0);
+//! assert_eq!(impedance.conj().im().value_in(ohms), -4.0);
+//! # }
+//! ```
+//!
+//! ## nalgebra vectors
+//! The `nalgebra` feature enables the statically sized `SVector<f32, N>`/`SVector<f64, N>` and the dynamically sized `DVector<f32>`/`DVector<f64>` of [`nalgebra`](https://crates.io/crates/nalgebra) as storage types. Vector quantities can be constructed from arrays of scalar quantities, their components are accessed via `get`, `set` and `iter`, and `norm`, `dot` and `cross` return quantities of the appropriate dimension:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "nalgebra")]
+//! # {
+//! # use diman::si::dimensions::{Area, Length};
+//! # use diman::si::units::{kilometers, meters, square_meters};
+//! use nalgebra::Vector3;
+//! let x: Length<Vector3<f64>> = [meters.new(3.0), meters.new(0.0), kilometers.new(0.004)].into();
+//! let y: Length<Vector3<f64>> = Vector3::new(0.0, 2.0, 0.0) * meters;
+//! assert_eq!(x.get(2), Some(meters.new(4.0)));
+//! assert_eq!(x.norm(), meters.new(5.0));
+//! let area: Area<Vector3<f64>> = x.cross(&y);
+//! assert_eq!(area.value_in(square_meters), Vector3::new(-8.0, 0.0, 6.0));
+//! # }
+//! ```
+//! Since `DVector` is not `Copy`, operators are only implemented for `DVector` quantities by value.
+//!
+//! ## glam matrices
+//! The `glam-mat2`, `glam-mat3` and `glam-dmat3` features enable the square matrices `Mat2`, `Mat3` and `DMat3` of [`glam`](https://crates.io/crates/glam/) as storage types. Multiplying a matrix quantity by a vector quantity results in a vector quantity whose dimension is the product of both dimensions. `transpose`, `determinant` and `inverse` are available, where the determinant of an `n × n` matrix has the dimension of its entries to the power of `n` and the inverse has the inverse dimension:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "glam-dmat3")]
+//! # {
+//! # use diman::si::dimensions::{Area, Length, Volume};
+//! # use diman::si::units::{litre, meters, square_meters};
+//! use glam::{DMat3, DVec3};
+//! let m: Length<DMat3> = DMat3::from_diagonal(DVec3::new(1.0, 2.0, 4.0)) * meters;
+//! let x: Length<DVec3> = DVec3::new(1.0, 1.0, 1.0) * meters;
+//! let y: Area<DVec3> = m * x;
+//! assert_eq!(y.value_in(square_meters), DVec3::new(1.0, 2.0, 4.0));
+//! let volume: Volume<f64> = m.determinant();
+//! assert_eq!(volume.value_in(litre), 8000.0);
+//! assert_eq!(m.inverse() * y, x);
+//! # }
+//! ```
+//! Matrix quantities can be multiplied by scalar quantities, but not divided by them, since `glam` does not implement dividing a matrix by a scalar.
+//!
+//! ## ndarray arrays
+//! The `ndarray` feature enables the owned arrays `Array<f32, Ix>` and `Array<f64, Ix>` of [`ndarray`](https://crates.io/crates/ndarray) of any dimensionality as storage types. Arithmetic on array quantities is elementwise, scalar quantities are broadcast, and `get`, `sum` and `mean` return scalar quantities. Converting an array into or out of a unit is a single pass over the array:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "ndarray")]
+//! # {
+//! # use diman::si::dimensions::{Mass, MassDensity, Volume};
+//! # use diman::si::units::{cubic_meters, grams, kilograms};
+//! use ndarray::{array, Array2};
+//! let density: MassDensity<Array2<f64>> = array![[1.0, 2.0], [3.0, 4.0]] * (kilograms / cubic_meters);
+//! let volume: Volume<f64> = 2.0 * cubic_meters;
+//! let mass: Mass<Array2<f64>> = density * volume;
+//! assert_eq!(mass.get([1, 0]), Some(kilograms.new(6.0)));
+//! assert_eq!(mass.sum(), kilograms.new(20.0));
+//! assert_eq!(mass.mean(), Some(kilograms.new(5.0)));
+//! assert_eq!(mass.value_in(grams), array![[2000.0, 4000.0], [6000.0, 8000.0]]);
+//! # }
+//! ```
+//! Since arrays are not `Copy`, operators are only implemented for array quantities by value.
+//!
+//! ## Measurements
+//! The `measurement` feature enables `Measurement<f32>` and `Measurement<f64>`, which store a value together with its standard uncertainty. The uncertainty is propagated to first order through arithmetic, `powi`, `sqrt`, `cbrt` and the dimensionless float functions such as `sin` or `ln`, assuming that the uncertainties of different operands are uncorrelated:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "measurement")]
+//! # {
+//! # use diman::si::dimensions::{Length, Time};
+//! # use diman::si::units::{meters, meters_per_second, seconds};
+//! use diman::measurement::Measurement;
+//! let length = Length::<Measurement<f64>>::new(meters.new(2.0), meters.new(0.03));
+//! let time: Time<Measurement<f64>> = Measurement::new(4.0, 0.04) * seconds;
+//! let velocity = length / time;
+//! assert_eq!(velocity.nominal(), meters_per_second.new(0.5));
+//! assert_eq!(format!("{:.3?}", velocity), "0.500 ± 0.009 m s^-1");
+//! # }
+//! ```
+//! With the `serde` feature, measured quantities can be read from strings such as `9.81(2) m s^-2` or `9.81 ± 0.02 m s^-2`, where the digits in parentheses are the uncertainty in the last digits of the value.
+//!
+//! ## Intervals
+//! The `interval` feature enables `Interval<f32>` and `Interval<f64>`, which store guaranteed lower and upper bounds. Arithmetic rounds the lower bound down and the upper bound up, so the exact result always lies within the interval, and unit conversions scale both bounds, widening them if the unit factor is not exactly representable. Comparisons via `compare` tell whether an interval lies definitely below or above another one, or whether they overlap:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "interval")]
+//! # {
+//! # use diman::si::dimensions::{Acceleration, Length, Velocity};
+//! # use diman::si::units::{meters, meters_per_second, seconds};
+//! use diman::interval::{Interval, IntervalOrdering};
+//! let speed = Velocity::<Interval<f64>>::new(meters_per_second.new(20.0), meters_per_second.new(25.0));
+//! let deceleration: Acceleration<Interval<f64>> = Interval::new(6.0, 8.0) * (meters / seconds.powi::<2>());
+//! let stopping_distance: Length<Interval<f64>> = speed.powi::<2>() / (2.0 * deceleration);
+//! assert_eq!(stopping_distance.lower(), meters.new(25.0));
+//! let limit: Length<Interval<f64>> = Interval::point(60.0) * meters;
+//! assert_eq!(stopping_distance.compare(&limit), IntervalOrdering::Less);
+//! # }
+//! ```
+//! The `<` and `>` operators on interval quantities only hold if the intervals do not overlap.
+//!
+//! ## Automatic differentiation
+//! The `dual` feature enables the dual numbers `Dual<f32, X>` and `Dual<f64, X>`, which carry the derivative of a value with respect to a variable of type `X` through arithmetic and the float methods. The derivative is a quantity of its own, whose dimension is the dimension of the value divided by the dimension of the variable:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # #[cfg(feature = "dual")]
+//! # {
+//! # use diman::si::dimensions::{Energy, Force, Length};
+//! # use diman::si::units::{joules, meters, newtons};
+//! use diman::dual::Dual;
+//! type WrtLength = Dual<f64, Length<f64>>;
+//! let x = Length::<WrtLength>::variable(meters.new(0.5));
+//! let stiffness = 8.0 * (newtons / meters);
+//! let energy: Energy<WrtLength> = 0.5 * stiffness * x.powi::<2>();
+//! assert_eq!(energy.real(), joules.new(1.0));
+//! let force: Force<f64> = energy.derivative();
+//! assert_eq!(force, newtons.new(4.0));
+//! # }
+//! ```
+//! Quantities which do not depend on the variable are created via `constant` or by combining them with plain quantities.
+//!
 //! ## Debug
 //! `Debug` is implemented and will print the quantity in its base representation.
 //! ```
@@ -321,6 +561,26 @@
 //! 4. `unit` defines a new units, which are methods on the corresponding quantities and `constant` defines constants. Units without a right-hand side are the base units to one specific base dimension, meaning that they are the unit that will internally be represented with a conversion factor of 1. Base units require the `#[base(...)]` attribute in order to specify which dimension they are the base unit of. Units with a right hand side are derived from other units.
 //! 5. `constant` defines a new constant.
 //!
+//! ## Symbols
+//! The symbol of a unit is used in the debug output and for parsing quantities via `serde`. Symbols are given via `#[symbol(...)]`, either as an identifier or as a string literal, which allows for symbols such as `°C`, `Å` or `Ω·m`:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! # diman_unit_system::unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//! # dimension Length;
+//! # #[symbol(m)]
+//! # #[base(Length)]
+//! # unit meters;
+//! #[symbol("Å")]
+//! unit angstrom: Length = 1e-10 meters;
+//! # );
+//! # }
+//! ```
+//! Symbols cannot contain whitespace or `^`. Further symbols after the first one, as in `#[symbol("°", deg)]`, are accepted when parsing but not used in the debug output.
+//!
 //! ## SI Prefixes
 //! Unit prefixes can automatically be generated with the `#[prefix(...)]` attribute for unit statements.
 //! For example
@@ -341,6 +601,24 @@
 //! ```
 //! will automatically generate the unit `meters` with symbol `m`, as well as `kilometers` and `millimeters` with symbols `km` and `mm` corresponding to `1e3 m` and `1e-3 m`.
 //! For simplicity, the attribute `#[metric_prefixes]` is provided, which will generate all metric prefixes from `atto-` to `exa-` automatically.
+//! Similarly, `#[binary_prefixes]` generates the binary prefixes from `kibi-` (`Ki`, 1024) to `exbi-` (`Ei`, 1024^6), which can also be used individually in `#[prefix(...)]`. Individual prefixes can be left out via `#[metric_prefixes(skip: deca, hecto)]` or `#[binary_prefixes(skip: exbi)]`.
+//! The prefixes `ronna-`, `quetta-`, `ronto-` and `quecto-` introduced in 2022 are available via `#[prefix(...)]`. Additional prefixes can be declared with the `prefix` statement and are then used like the built-in ones:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! # diman_unit_system::unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//! # dimension Length;
+//! prefix myria = 1e4, symbol "my";
+//! #[base(Length)]
+//! #[prefix(kilo, myria)]
+//! #[symbol(m)]
+//! unit meters;
+//! # );
+//! # }
+//! ```
 //!
 //! ## Aliases
 //! Unit aliases can automatically be generated with the `#[alias(...)]` macro. For example
@@ -361,6 +639,214 @@
 //! ```
 //! will automatically generate a unit `metres` that has exactly the same definition as `meters`. This works with prefixes as expected (i.e. an alias is generated for every prefixed unit).
 //!
+//! ## Units with an offset
+//! Units whose zero point is shifted with respect to the base units, such as degrees Celsius, are defined by adding an offset to the right-hand side of a unit definition. The offset is given in terms of the expression on the right-hand side, so the definitions below mean that `x °C` corresponds to `(x + 273.15) K` and `x °F` corresponds to `(x + 459.67) * 5/9 K`:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! # diman_unit_system::unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//! # dimension Temperature;
+//! # #[symbol(K)]
+//! # #[base(Temperature)]
+//! # unit kelvin;
+//! #[symbol(degC)]
+//! unit celsius: Temperature = kelvin + 273.15;
+//! unit fahrenheit: Temperature = 5 / 9 * kelvin + 459.67;
+//! # );
+//! # }
+//! ```
+//! Quantities are created from such units via `.new`, and `value_in` as well as parsing via `serde` take the offset into account:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # use diman::si::dimensions::Temperature;
+//! # use diman::si::units::{degree_celsius, kelvin};
+//! let t: Temperature<f64> = degree_celsius.new(25.0);
+//! assert_eq!(t.value_in(kelvin), 298.15);
+//! assert_eq!(t.value_in(degree_celsius), 25.0);
+//! ```
+//! Since they are not multiplicative, units with an offset cannot be used in the definitions of other units or constants, cannot be prefixed and cannot be used to create quantities via multiplication.
+//!
+//! ## Points
+//! Absolute temperatures, positions and timestamps are represented by the `Point` type which is generated alongside the quantity type. The difference of two points is a quantity, and quantities can be added to or subtracted from points, but adding two points does not compile:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # use diman::si::units::{degree_celsius, kelvin};
+//! let t1 = degree_celsius.new_point(20.0f64);
+//! let t2 = degree_celsius.new_point(30.0f64);
+//! assert_eq!(t2 - t1, 10.0 * kelvin);
+//! assert_eq!(t1 + 10.0 * kelvin, t2);
+//! assert_eq!(t2.value_in(degree_celsius), 30.0);
+//! ```
+//! ```compile_fail
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # use diman::si::units::degree_celsius;
+//! let t1 = degree_celsius.new_point(20.0f64);
+//! let t2 = degree_celsius.new_point(30.0f64);
+//! let meaningless = t1 + t2;
+//! ```
+//! Points can be converted from and to their displacement from the origin of the base units via `Point::from_origin` and `displacement_from_origin`.
+//!
+//! ## Logarithmic units
+//! Logarithmic units such as the decibel are defined via the `#[logarithmic(...)]` attribute. The right-hand side of the definition is the reference quantity, `factor` and `base` define the scale, so that the level of a quantity `q` is `factor * log_base(q / reference)`. The `base` can be a number or `e`. Root-power quantities, such as voltages or sound pressures, are marked with `root_power`:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! # diman_unit_system::unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//! # dimension Power;
+//! # dimension Voltage;
+//! # #[symbol(W)]
+//! # #[base(Power)]
+//! # #[metric_prefixes]
+//! # unit watt;
+//! # #[symbol(V)]
+//! # #[base(Voltage)]
+//! # unit volt;
+//! #[logarithmic(factor: 10, base: 10)]
+//! #[symbol(dBm)]
+//! unit decibel_milliwatt: Power = milliwatt;
+//! #[logarithmic(factor: 20, base: 10, root_power)]
+//! #[symbol(dBV)]
+//! unit decibel_volt: Voltage = volt;
+//! # );
+//! # }
+//! ```
+//! A logarithmic unit creates a `Level`, which can only be converted to and from quantities of the matching dimension. Adding two levels adds the underlying powers instead of the values of the levels:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # use diman::si::units::{decibel_milliwatt, watt};
+//! let level = decibel_milliwatt.new(30.0f64);
+//! assert!((level.into_quantity().value_in(watt) - 1.0).abs() < 1e-10);
+//! assert!((decibel_milliwatt.level_of(0.01f64 * watt).value() - 10.0).abs() < 1e-10);
+//! let total = decibel_milliwatt.new(10.0f64) + decibel_milliwatt.new(10.0);
+//! assert!((total.value() - 13.0103).abs() < 1e-4);
+//! ```
+//! The SI module contains the bel, decibel and neper as well as `decibel_watt`, `decibel_milliwatt`, `decibel_volt`, `decibel_sound_pressure_level` and `ph`. Logarithmic units cannot be used in the definition of other units.
+//!
+//! ## Extending unit systems
+//! An existing unit system can be extended with additional dimensions, units and constants from another crate via `extends`:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! diman::unit_system!(
+//!     extends diman::si;
+//!     dimension Yank = Force / Time;
+//!     #[symbol("N/s")]
+//!     unit newtons_per_second: Yank = newton / second;
+//!     #[symbol(ftm)]
+//!     unit fathoms: Length = 1.8288 * meter;
+//! );
+//! # }
+//! # use surround::dimensions::Yank;
+//! # use surround::units::{fathoms, newtons_per_second};
+//! use diman::si::dimensions::Length;
+//! use diman::si::units::{meters, newtons, seconds};
+//! let yank: Yank<f64> = newtons.new(10.0) / seconds.new(2.0);
+//! assert_eq!(yank.value_in(newtons_per_second), 5.0);
+//! let length: Length<f64> = fathoms.new(1.0);
+//! assert_eq!(length.value_in(meters), 1.8288);
+//! ```
+//! The extension re-exports all dimensions, units and constants of the extended system, and its quantities are the same types as those of the extended system. New base dimensions cannot be added. Note that the `Debug` and `serde` implementations only know about the units of the extended system.
+//!
+//! ## Conversions between unit systems
+//! Quantities of different unit systems are different types. A `conversion` statement generates `From` implementations in both directions between the quantity types of two systems. For every base dimension of the other system, it states the corresponding quantity in this system:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! # diman_unit_system::unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//! # dimension Length;
+//! # dimension Mass;
+//! # dimension Time;
+//! # #[base(Length)]
+//! # #[symbol(cm)]
+//! # unit centimeters;
+//! # #[base(Mass)]
+//! # #[symbol(g)]
+//! # unit grams;
+//! # #[base(Time)]
+//! # #[symbol(s)]
+//! # unit seconds;
+//! conversion diman::si::{Quantity, Dimension} {
+//!     Length = 100 centimeters;
+//!     Mass = 1000 grams;
+//!     Time = seconds;
+//! };
+//! # );
+//! # }
+//! # use surround::dimensions::Length;
+//! # use surround::units::centimeters;
+//! use diman::si::units::meters;
+//! let length: Length<f64> = meters.new(2.0).into();
+//! assert_eq!(length.value_in(centimeters), 200.0);
+//! let length: diman::si::dimensions::Length<f64> = length.into();
+//! assert_eq!(length.value_in(meters), 2.0);
+//! ```
+//! Converting a quantity whose dimension involves a base dimension without a counterpart in the other system, such as an electric current in the example above, results in a compile error.
+//! Conversions into the other system only use the base dimensions which correspond to exactly one base dimension of this system.
+//!
+//! ## Quantity kinds
+//! Some dimensions share the same base dimensions but describe different things, such as torque and energy. Declaring a dimension with `kind` instead of `dimension` makes it a distinct type:
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs, adt_const_params)]
+//! # mod surround {
+//! # diman_unit_system::unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//! # dimension Length;
+//! # dimension Force;
+//! # #[base(Length)]
+//! # #[symbol(m)]
+//! # unit meters;
+//! # #[base(Force)]
+//! # #[symbol(N)]
+//! # unit newtons;
+//! dimension Energy = Force * Length;
+//! kind Torque = Force * Length;
+//! #[symbol(J)]
+//! unit joules: Energy = newtons * meters;
+//! #[symbol(Nm)]
+//! unit newton_meters: Torque = newtons * meters;
+//! # );
+//! # }
+//! # use surround::dimensions::{Energy, Torque};
+//! # use surround::units::{joules, newton_meters};
+//! let torque: Torque<f64> = newton_meters.new(2.0);
+//! let energy: Energy<f64> = torque.into_kind();
+//! assert_eq!(energy.value_in(joules), 2.0);
+//! ```
+//! Adding a torque to an energy or assigning one to the other does not compile. `into_kind` converts explicitly between dimensions with the same base dimensions. Products and quotients of kinds are plain dimensions. The SI module defines `Angle`, `SolidAngle`, `Torque` and `Entropy` as kinds. Since angles are dimensionless, they provide the same methods as dimensionless quantities.
+//!
+//! ## Custom storage types
+//! Besides the storage types enabled via features, a unit system can declare storage types of its own via `storage_type`. Vector types additionally specify the type and number of their components:
+//! ```ignore
+//! # mod surround {
+//! # use diman_unit_system::unit_system;
+//! unit_system!(
+//! # quantity_type Quantity;
+//! # dimension_type Dimension;
+//!     // ...
+//!     storage_type crate::MyFloat;
+//!     storage_type crate::MyVec3: vector(f32, 3);
+//!     // ...
+//! );
+//! # }
+//! ```
+//! Most methods and operators on quantities are implemented generically over the traits in `diman::storage`, so the types need to implement `QuantityStorage` and, depending on the type, `RealStorage`, `FloatStorage` or `VectorStorage` (which provide methods such as `sqrt` or `length`). The remaining operator impls and unit multiplications are generated for these types just like for `f32`/`f64` and the `glam` vectors, which requires the types to provide the same operators as the built-in types. Unit conversions are done via `Mul<Magnitude>` and `Div<Magnitude>`, which the types need to implement.
+//!
 //! # Quantity products and quotients
 //! Sometimes, intermediate types in computations are quantities that don't really have a nice name and are also
 //! not needed too many times. Having to add a definition to the unit system for this case can be cumbersome.
@@ -593,6 +1079,29 @@ pub type Product<Q1, Q2> = <Q1 as ::core::ops::Mul<Q2>>::Output;
 /// ```
 pub type Quotient<Q1, Q2> = <Q1 as core::ops::Div<Q2>>::Output;
 
+/// Traits for the storage types of quantities, which need to be
+/// implemented by storage types declared via `storage_type`.
+pub use diman_lib::storage;
+
+/// Unit conversions of integer, fixed-point and exact storage types,
+/// including the error returned by exact conversions.
+pub use diman_lib::scaling;
+
+/// Total ordering and hashing of quantities with float storage types.
+pub use diman_lib::ordered;
+
+/// Storage type for measured values with a standard uncertainty.
+#[cfg(feature = "measurement")]
+pub use diman_lib::measurement;
+
+/// Storage type for intervals with guaranteed bounds.
+#[cfg(feature = "interval")]
+pub use diman_lib::interval;
+
+/// Storage type for forward-mode automatic differentiation.
+#[cfg(feature = "dual")]
+pub use diman_lib::dual;
+
 pub mod internal {
     pub use diman_lib::*;
 }
@@ -0,0 +1,58 @@
+use diman::si::dimensions::{Current, Dimensionless, ElectricResistance, Voltage};
+use diman::si::units::{amperes, millivolts, ohms, volts};
+use num_complex::Complex;
+
+crate::gen_new_and_value_in_test!(complex_new_and_value_in, Complex<f64>, |x| {
+    Complex::new(x, -x)
+});
+
+#[test]
+fn complex_new_from_real_and_imaginary_parts() {
+    let voltage = Voltage::<Complex<f32>>::new(volts.new(1.5f32), millivolts.new(-500.0));
+    assert_eq!(voltage.re().value_in(volts), 1.5);
+    assert_eq!(voltage.im().value_in(volts), -0.5);
+}
+
+#[test]
+fn complex_norm_arg_conj() {
+    let impedance: ElectricResistance<Complex<f64>> = Complex::new(3.0, 4.0) * ohms;
+    assert_eq!(impedance.norm(), 5.0 * ohms);
+    let arg: Dimensionless<f64> = impedance.arg();
+    assert_eq!(arg.value(), 4.0f64.atan2(3.0));
+    assert_eq!(impedance.conj().value_in(ohms), Complex::new(3.0, -4.0));
+}
+
+#[test]
+fn complex_mixed_with_real_quantities() {
+    let impedance: ElectricResistance<Complex<f64>> = Complex::new(3.0, 4.0) * ohms;
+    let current: Current<f64> = 2.0 * amperes;
+    let voltage = impedance * current;
+    assert_eq!(voltage.value_in(volts), Complex::new(6.0, 8.0));
+    let voltage = current * impedance;
+    assert_eq!(voltage.value_in(volts), Complex::new(6.0, 8.0));
+    assert_eq!((voltage / current).value_in(ohms), impedance.value_in(ohms));
+    assert_eq!(
+        (impedance + 1.0f64 * ohms).value_in(ohms),
+        Complex::new(4.0, 4.0)
+    );
+    assert_eq!((impedance * 2.0).value_in(ohms), Complex::new(6.0, 8.0));
+    assert_eq!((2.0 * impedance).value_in(ohms), Complex::new(6.0, 8.0));
+    assert_eq!((impedance / 2.0).value_in(ohms), Complex::new(1.5, 2.0));
+    let resistance: ElectricResistance<f64> = 2.0 * ohms;
+    let scaled = resistance * Complex::new(0.0, 1.0);
+    assert_eq!(scaled.value_in(ohms), Complex::new(0.0, 2.0));
+}
+
+#[test]
+fn complex_arithmetic() {
+    let a = Complex::new(1.0f32, 2.0) * ohms;
+    let b = Complex::new(3.0f32, -1.0) * ohms;
+    assert_eq!((a + b).value_in(ohms), Complex::new(4.0, 1.0));
+    assert_eq!((a - b).value_in(ohms), Complex::new(-2.0, 3.0));
+    let ratio: Dimensionless<Complex<f32>> = a / b;
+    assert_eq!(
+        ratio.value(),
+        Complex::new(1.0, 2.0) / Complex::new(3.0, -1.0)
+    );
+    assert_eq!(format!("{:?}", a), "1+2i Ω");
+}
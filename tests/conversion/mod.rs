@@ -0,0 +1,65 @@
+//! Example showing conversions between the SI system and a
+//! CGS system.
+
+mod cgs {
+    diman::unit_system!(
+        quantity_type Quantity;
+        dimension_type Dimension;
+
+        dimension Length;
+        dimension Mass;
+        dimension Time;
+        dimension Velocity = Length / Time;
+        dimension Force = Mass * Length / Time^2;
+        dimension Energy = Force * Length;
+
+        #[base(Length)]
+        #[symbol(cm)]
+        unit centimeters;
+
+        #[base(Mass)]
+        #[symbol(g)]
+        unit grams;
+
+        #[base(Time)]
+        #[symbol(s)]
+        unit seconds;
+
+        #[symbol("dyn")]
+        unit dynes = grams * centimeters / seconds^2;
+
+        #[symbol(erg)]
+        unit ergs = dynes * centimeters;
+
+        conversion diman::si::{Quantity, Dimension} {
+            Length = 100 centimeters;
+            Mass = 1000 grams;
+            Time = seconds;
+        };
+    );
+}
+
+use crate::utils::assert_is_close_float_f64;
+
+#[test]
+fn si_to_cgs() {
+    use diman::si::units::{joules, meters, meters_per_second, newtons};
+    let length: cgs::dimensions::Length<f64> = meters.new(2.0).into();
+    assert_is_close_float_f64(length.value_in(cgs::units::centimeters), 200.0);
+    let velocity: cgs::dimensions::Velocity<f64> = meters_per_second.new(3.0).into();
+    assert_is_close_float_f64(velocity.value_unchecked(), 300.0);
+    let force: cgs::dimensions::Force<f64> = newtons.new(1.0).into();
+    assert_is_close_float_f64(force.value_in(cgs::units::dynes), 1e5);
+    let energy: cgs::dimensions::Energy<f64> = joules.new(1.0).into();
+    assert_is_close_float_f64(energy.value_in(cgs::units::ergs), 1e7);
+}
+
+#[test]
+fn cgs_to_si() {
+    use diman::si::dimensions::{Energy, Length};
+    use diman::si::units::{joules, meters};
+    let length: Length<f64> = cgs::units::centimeters.new(50.0).into();
+    assert_is_close_float_f64(length.value_in(meters), 0.5);
+    let energy: Energy<f64> = cgs::units::ergs.new(1e7).into();
+    assert_is_close_float_f64(energy.value_in(joules), 1.0);
+}
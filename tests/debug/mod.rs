@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::example_system::units::{joules, meters, square_meters};
+    use crate::example_system::units::{
+        decibel_meters, decibel_milliwatts, joules, meters, square_meters,
+    };
 
     #[test]
     fn debug() {
@@ -11,4 +13,39 @@ mod tests {
         assert_eq!(format!("{:?}", x), "50 m^3 s^-2 kg");
         assert_eq!(format!("{:?}", 50.0 * joules), "50 J");
     }
+
+    #[test]
+    fn debug_level() {
+        assert_eq!(format!("{:?}", decibel_milliwatts.new(10.0)), "10 dBm");
+        // Logarithmic unit without symbol
+        assert_eq!(format!("{:?}", decibel_meters.new(10.0)), "10");
+    }
+}
+
+mod string_symbols {
+    diman::unit_system!(
+        quantity_type Quantity;
+        dimension_type Dimension;
+        dimension Length;
+        dimension Resistance;
+        dimension Resistivity = Resistance * Length;
+        #[base(Length)]
+        #[symbol("Å")]
+        unit angstrom;
+        #[base(Resistance)]
+        #[prefix(micro)]
+        #[symbol("Ω")]
+        unit ohm;
+        #[symbol("Ω·Å")]
+        unit ohm_angstrom = ohm * angstrom;
+    );
+
+    #[test]
+    fn debug_string_symbols() {
+        use units::{angstrom, microohm, ohm_angstrom};
+        assert_eq!(format!("{:?}", 2.0 * angstrom), "2 Å");
+        assert_eq!(format!("{:?}", 2.0 * ohm_angstrom), "2 Ω·Å");
+        assert_eq!(format!("{:?}", 2.0 * angstrom * angstrom), "2 Å^2");
+        assert_eq!(format!("{:?}", 1e6 * microohm), "1 Ω");
+    }
 }
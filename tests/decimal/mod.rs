@@ -0,0 +1,46 @@
+use diman::scaling::InexactConversion;
+use diman::si::dimensions::{Energy, Time};
+use diman::si::units::{hours, joules, kilowatts, meters, minutes, seconds};
+use rust_decimal::Decimal;
+
+crate::gen_new_and_value_in_test!(decimal_new_and_value_in, Decimal, |x| {
+    Decimal::new(x as i64, 0)
+});
+
+#[test]
+fn decimal_conversions_are_exact() {
+    let kilowatt_hours = kilowatts * hours;
+    let energy: Energy<Decimal> = kilowatt_hours.new(Decimal::new(15, 1));
+    assert_eq!(energy, joules.new(Decimal::new(5_400_000, 0)));
+    assert_eq!(energy.value_in(kilowatt_hours), Decimal::new(15, 1));
+    let total = (0..10)
+        .map(|_| kilowatt_hours.new(Decimal::new(1, 1)))
+        .fold(Energy::<Decimal>::zero(), |total, energy| total + energy);
+    assert_eq!(total.value_in(kilowatt_hours), Decimal::ONE);
+}
+
+#[test]
+fn decimal_exact_conversions() {
+    let t: Time<Decimal> = seconds.new(Decimal::new(120, 0));
+    assert_eq!(t.value_in_exact(minutes), Ok(Decimal::new(2, 0)));
+    assert_eq!(
+        hours.new_exact(Decimal::new(25, 2)),
+        Ok(seconds.new(Decimal::new(900, 0)))
+    );
+}
+
+#[test]
+fn decimal_inexact_conversions_are_errors() {
+    let t: Time<Decimal> = seconds.new(Decimal::new(100, 0));
+    assert_eq!(t.value_in_exact(minutes), Err(InexactConversion));
+    assert_eq!(
+        (meters / minutes).new_exact(Decimal::ONE),
+        Err(InexactConversion)
+    );
+}
+
+#[test]
+#[should_panic(expected = "inexactly")]
+fn decimal_value_in_panics_if_inexact() {
+    let _ = seconds.new(Decimal::ONE).value_in(minutes);
+}
@@ -0,0 +1,54 @@
+use diman::dual::Dual;
+use diman::si::dimensions::{Dimensionless, Energy, Force, Length, Time, Velocity};
+use diman::si::units::{joules, meters, meters_per_second, newtons, seconds};
+
+type WrtLength = Dual<f64, Length<f64>>;
+type WrtTime = Dual<f64, Time<f64>>;
+
+#[test]
+fn dual_derivative_of_energy_with_respect_to_length_is_force() {
+    let x = Length::<WrtLength>::variable(meters.new(0.5));
+    let stiffness = 8.0 * (newtons / meters);
+    let energy: Energy<WrtLength> = 0.5 * stiffness * x.powi::<2>();
+    assert_eq!(energy.real(), joules.new(1.0));
+    let force: Force<f64> = energy.derivative();
+    assert_eq!(force, newtons.new(4.0));
+}
+
+#[test]
+fn dual_new_constant_and_variable() {
+    let t = Time::<WrtTime>::variable(seconds.new(2.0));
+    assert_eq!(t.real(), seconds.new(2.0));
+    let dt_dt: Dimensionless<f64> = t.derivative();
+    assert_eq!(dt_dt.value(), 1.0);
+    let x0 = Length::<WrtTime>::constant(meters.new(3.0));
+    assert_eq!(x0.derivative(), meters_per_second.new(0.0));
+    let v = Length::<WrtTime>::new(meters.new(1.0), meters_per_second.new(5.0));
+    let x: Length<WrtTime> = x0 + v;
+    let velocity: Velocity<f64> = x.derivative();
+    assert_eq!(velocity, meters_per_second.new(5.0));
+}
+
+#[test]
+fn dual_product_and_quotient_rules() {
+    let t = Time::<WrtTime>::variable(seconds.new(2.0));
+    let v: Velocity<f64> = meters_per_second.new(3.0);
+    let x: Length<WrtTime> = v * t * t;
+    assert_eq!(x.real(), meters.new(12.0));
+    assert_eq!(x.derivative(), meters_per_second.new(12.0));
+    let speed: Velocity<WrtTime> = x / t;
+    assert_eq!(speed.real(), meters_per_second.new(6.0));
+    assert_eq!(speed.derivative().value_unchecked(), 3.0);
+}
+
+#[test]
+fn dual_float_methods_propagate_derivatives() {
+    let t = Time::<WrtTime>::variable(seconds.new(0.0));
+    let omega = 2.0 / seconds.new(1.0);
+    let phase: Dimensionless<WrtTime> = omega * t;
+    assert_eq!(phase.sin().derivative().value_unchecked(), 2.0);
+    assert_eq!(phase.cos().derivative().value_unchecked(), 0.0);
+    assert_eq!(phase.exp().derivative().value_unchecked(), 2.0);
+    let t_squared = Time::<WrtTime>::variable(seconds.new(4.0)).powi::<2>();
+    assert_eq!(t_squared.sqrt().derivative().value_unchecked(), 1.0);
+}
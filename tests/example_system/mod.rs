@@ -25,6 +25,8 @@ unit_system!(
     #[symbol(kg)]
     unit kilograms: Mass;
     unit grams = 1e-3 * kilograms;
+    #[symbol("Å")]
+    unit angstroms: Length = 1e-10 * meters;
     dimension Area = Length^2;
     unit square_meters = meters^2;
     dimension Volume = Length^3;
@@ -33,8 +35,22 @@ unit_system!(
     #[base(Temperature)]
     #[symbol(K)]
     unit kelvins: Temperature;
+    #[symbol(degC)]
+    unit celsius: Temperature = kelvins + 273.15;
+    unit fahrenheit: Temperature = 5 / 9 * kelvins + 459.67;
     dimension InverseTemperature = 1 / Temperature;
     unit newtons = joules / meters;
+    dimension Power = Energy / Time;
+    #[symbol(W)]
+    unit watts = joules / seconds;
+    #[logarithmic(factor: 10, base: 10)]
+    #[symbol(dBW)]
+    unit decibel_watts: Power = watts;
+    #[logarithmic(factor: 10, base: 10)]
+    #[symbol(dBm)]
+    unit decibel_milliwatts: Power = 1e-3 watts;
+    #[logarithmic(factor: 20, base: 10, root_power)]
+    unit decibel_meters: Length = meters;
     constant SOLAR_MASS = 1.988477e30 * kilograms;
     constant SOLAR_MASS_GRAMS = 1.988477e33 * grams;
     constant SOLAR_MASS_AWKWARD = 1.988477e30 * kilograms / (seconds / seconds);
@@ -0,0 +1,57 @@
+//! Example showing how to extend the SI system with additional
+//! dimensions, units and constants.
+
+mod si_extension {
+    diman::unit_system!(
+        extends diman::si;
+
+        dimension Yank = Force / Time;
+
+        #[prefix(kilo)]
+        #[symbol("N/s")]
+        unit newtons_per_second: Yank = newton / second;
+
+        #[symbol(ftm)]
+        unit fathoms: Length = 1.8288 * meter;
+
+        constant STANDARD_YANK = 9.81 newtons_per_second;
+    );
+}
+
+use diman::si::dimensions::{Force, Length, Time};
+use diman::si::units::{meters, newtons, seconds};
+
+use si_extension::constants::STANDARD_YANK;
+use si_extension::dimensions::Yank;
+use si_extension::units::{fathoms, kilonewtons_per_second, newtons_per_second};
+
+use crate::utils::assert_is_close_float_f64;
+
+#[test]
+fn new_dimension_interoperates_with_base_system() {
+    let force: Force<f64> = newtons.new(10.0);
+    let time: Time<f64> = seconds.new(2.0);
+    let yank: Yank<f64> = force / time;
+    assert_is_close_float_f64(yank.value_in(newtons_per_second), 5.0);
+    assert_is_close_float_f64(yank.value_in(kilonewtons_per_second), 5e-3);
+    let force: Force<f64> = yank * time;
+    assert_is_close_float_f64(force.value_in(newtons), 10.0);
+}
+
+#[test]
+fn new_unit_for_existing_dimension() {
+    let length: Length<f64> = fathoms.new(1.0);
+    assert_is_close_float_f64(length.value_in(meters), 1.8288);
+}
+
+#[test]
+fn base_system_is_reexported() {
+    let length: si_extension::dimensions::Length<f64> = si_extension::units::meters.new(2.0);
+    let length: diman::si::dimensions::Length<f64> = length;
+    assert_is_close_float_f64(length.value_in(si_extension::units::kilometers), 2e-3);
+}
+
+#[test]
+fn new_constant() {
+    assert_is_close_float_f64((1.0f64 * STANDARD_YANK).value_in(newtons_per_second), 9.81);
+}
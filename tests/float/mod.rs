@@ -5,7 +5,7 @@ macro_rules! gen_tests_for_float {
                 SOLAR_MASS, SOLAR_MASS_AWKWARD, SOLAR_MASS_GRAMS,
             };
             use crate::example_system::dimensions::{
-                Dimensionless, Energy, Force, Length, Mass, Time, Velocity,
+                Dimensionless, Energy, Force, Length, Mass, Temperature, Time, Velocity,
             };
             use crate::example_system::units;
             use crate::make_annotated_unit_constructor;
@@ -535,6 +535,85 @@ macro_rules! gen_tests_for_float {
                 assert_is_close(x.log2(), dimensionless(7.0));
             }
 
+            #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+            #[test]
+            fn hypot() {
+                assert_is_close(meters(3.0).hypot(meters(4.0)), meters(5.0));
+            }
+
+            #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+            #[test]
+            fn mul_add() {
+                let energy = newtons(2.0).mul_add(meters(3.0), joules(1.0));
+                assert_is_close(energy, joules(7.0));
+            }
+
+            #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+            #[test]
+            fn atan2() {
+                let x = meters(1.0).atan2(kilometers(0.001));
+                assert_is_close(x, dimensionless(core::$float_name::consts::FRAC_PI_4));
+            }
+
+            #[cfg(any(feature = "std", feature = "num-traits-libm"))]
+            #[test]
+            fn copysign() {
+                assert_is_close(meters(3.0).copysign(seconds(-1.0)), meters(-3.0));
+            }
+
+            #[test]
+            fn recip() {
+                let x = seconds(2.0).recip() * meters(3.0);
+                assert_is_close(x, meters_per_second(1.5));
+            }
+
+            #[test]
+            fn signum() {
+                assert_is_close(meters(-3.0).signum(), dimensionless(-1.0));
+                assert_is_close(meters(3.0).signum(), dimensionless(1.0));
+            }
+
+            #[test]
+            fn rem() {
+                assert_is_close(meters(7.0) % meters(2.0), meters(1.0));
+                assert_is_close(meters(-7.0) % meters(2.0), meters(-1.0));
+                let mut x = kilometers(1.0);
+                x %= meters(300.0);
+                assert_is_close(x, meters(100.0));
+            }
+
+            #[test]
+            fn rem_euclid() {
+                assert_is_close(meters(7.0).rem_euclid(meters(2.0)), meters(1.0));
+                assert_is_close(meters(-7.0).rem_euclid(meters(2.0)), meters(1.0));
+                assert_is_close(meters(-7.0).rem_euclid(meters(-2.0)), meters(1.0));
+            }
+
+            #[test]
+            fn float_constants() {
+                assert!(Length::<$float_name>::NAN.is_nan());
+                assert!(Length::<$float_name>::INFINITY.is_infinite());
+                assert!(!Length::<$float_name>::INFINITY.is_finite());
+                assert!(Length::<$float_name>::MAX.is_finite());
+                assert!(Length::<$float_name>::MIN < meters(0.0));
+                assert!(meters(1.0) + Length::<$float_name>::EPSILON > meters(1.0));
+            }
+
+            #[test]
+            fn total_cmp() {
+                let mut lengths = [
+                    meters(2.0),
+                    Length::<$float_name>::NAN,
+                    kilometers(-1.0),
+                    meters(1.0),
+                ];
+                lengths.sort_by(|a, b| a.total_cmp(b));
+                assert!(lengths[0] == kilometers(-1.0));
+                assert!(lengths[1] == meters(1.0));
+                assert!(lengths[2] == meters(2.0));
+                assert!(lengths[3].is_nan());
+            }
+
             #[test]
             fn deref_dimensionless() {
                 let x = dimensionless(128.3);
@@ -649,6 +728,31 @@ macro_rules! gen_tests_for_float {
                 assert!(dimensionless(5.0).min(x) == dimensionless(5.0));
                 assert!(dimensionless(15.0).min(x) == x);
             }
+
+            #[test]
+            fn new_unit_with_offset() {
+                let x: Temperature<$float_name> = units::celsius.new(0.0);
+                assert_is_close(x, units::kelvins.new(273.15));
+                let x: Temperature<$float_name> = units::celsius.new(-273.15);
+                assert_is_close(x, units::kelvins.new(0.0));
+            }
+
+            #[test]
+            fn value_in_unit_with_offset() {
+                let x: Temperature<$float_name> = units::kelvins.new(273.15);
+                assert_is_close_float(x.value_in(units::celsius), 0.0);
+                let x: Temperature<$float_name> = units::celsius.new(25.0);
+                assert_is_close_float(x.value_in(units::celsius), 25.0);
+                assert_is_close_float(x.value_in(units::kelvins), 298.15);
+            }
+
+            #[test]
+            fn unit_with_offset_and_scale() {
+                let x: Temperature<$float_name> = units::fahrenheit.new(212.0);
+                assert!((x.value_in(units::celsius) - 100.0).abs() < 1e-3);
+                let x: Temperature<$float_name> = units::celsius.new(-40.0);
+                assert!((x.value_in(units::fahrenheit) + 40.0).abs() < 1e-3);
+            }
         }
     };
 }
@@ -107,6 +107,123 @@ macro_rules! gen_tests_for_vector_3 {
     };
 }
 
+#[cfg(any(feature = "glam-vec4", feature = "glam-dvec4"))]
+macro_rules! gen_tests_for_vector_4 {
+    ($float_name: ident, $mod_name: ident, $vec_name: ty, $assert_is_close: path) => {
+        mod $mod_name {
+            use crate::example_system::dimensions::Length;
+            use crate::example_system::units;
+            use crate::make_annotated_unit_constructor;
+            use $assert_is_close as assert_is_close;
+
+            make_annotated_unit_constructor!(meters, Length<$float_name>, $float_name);
+
+            use $vec_name as Vec;
+            #[test]
+            fn debug_vector_4() {
+                assert_eq!(
+                    format!("{:?}", Vec::new(1.0, 5.0, 10.0, 20.0) * meters(1.0)),
+                    "[1, 5, 10, 20] m"
+                );
+            }
+
+            #[test]
+            fn new_vec4() {
+                let mut vec: Length<Vec> =
+                    Length::<Vec>::new(meters(1.0), meters(2.0), meters(3.0), meters(4.0));
+                assert_is_close(vec.w(), meters(4.0));
+                vec.set_w(meters(5.0));
+                assert_is_close(vec.w(), meters(5.0));
+                assert_is_close(Length::<Vec>::new_w(meters(2.0)).w(), meters(2.0));
+                assert_is_close(vec.length(), meters(39.0f64.sqrt() as $float_name));
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "glam-mat3", feature = "glam-dmat3"))]
+macro_rules! gen_tests_for_matrix_3 {
+    ($float_name: ident, $mod_name: ident, $mat_name: ty, $vec_name: ty, $assert_is_close: path) => {
+        mod $mod_name {
+            use crate::example_system::dimensions::{Area, Dimensionless, Length, Volume};
+            use crate::example_system::units;
+            use crate::make_annotated_unit_constructor;
+            use $assert_is_close as assert_is_close;
+
+            make_annotated_unit_constructor!(meters, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(square_meters, Area<$float_name>, $float_name);
+            make_annotated_unit_constructor!(cubic_meters, Volume<$float_name>, $float_name);
+            make_annotated_unit_constructor!(
+                dimensionless,
+                Dimensionless<$float_name>,
+                $float_name
+            );
+
+            use $mat_name as Mat;
+            use $vec_name as Vec;
+
+            fn matrix() -> Length<Mat> {
+                Length::<Mat>::from_cols(
+                    Vec::new(1.0, 0.0, 0.0) * units::meters,
+                    Vec::new(2.0, 2.0, 0.0) * units::meters,
+                    Vec::new(0.0, 0.0, 3.0) * units::meters,
+                )
+            }
+
+            #[test]
+            fn mul_matrix_vector() {
+                let vec = Vec::new(1.0, 1.0, 1.0) * units::meters;
+                let product: Area<Vec> = matrix() * vec;
+                assert_is_close(product.x(), square_meters(3.0));
+                assert_is_close(product.y(), square_meters(2.0));
+                assert_is_close(product.z(), square_meters(3.0));
+            }
+
+            #[test]
+            fn mul_matrix_scalar() {
+                let doubled = matrix() * meters(2.0);
+                assert_is_close(doubled.col(1).x(), square_meters(4.0));
+                let doubled = 2.0 * matrix();
+                assert_is_close(doubled.col(2).z(), meters(6.0));
+                let doubled = matrix() * 2.0;
+                assert_is_close(doubled.col(2).z(), meters(6.0));
+            }
+
+            #[test]
+            fn transpose() {
+                let transposed = matrix().transpose();
+                assert_is_close(transposed.col(0).y(), meters(2.0));
+                assert_is_close(transposed.row(1).x(), meters(2.0));
+                assert_is_close(transposed.col(1).x(), meters(0.0));
+            }
+
+            #[test]
+            fn determinant() {
+                assert_is_close(matrix().determinant(), cubic_meters(6.0));
+            }
+
+            #[test]
+            fn inverse() {
+                let identity: Dimensionless<Mat> = matrix().inverse() * matrix();
+                assert_is_close(identity.col(0).x(), dimensionless(1.0));
+                assert_is_close(identity.col(1).x(), dimensionless(0.0));
+                assert_is_close(identity.col(2).z(), dimensionless(1.0));
+            }
+
+            #[test]
+            fn unit_conversion() {
+                let matrix = Mat::from_diagonal(Vec::new(1.0, 2.0, 3.0)) * units::angstroms;
+                let value = matrix.value_in(units::meters);
+                assert!((value.col(2).z - 3e-10).abs() < <$float_name>::EPSILON);
+                let matrix = matrix / units::seconds;
+                assert!(
+                    (matrix.col(1).y().value_unchecked() - 2e-10).abs() < <$float_name>::EPSILON
+                );
+            }
+        }
+    };
+}
+
 #[cfg(all(feature = "f32", feature = "glam-vec2"))]
 gen_tests_for_vector_2!(f32, vec2, glam::Vec2, crate::utils::assert_is_close_f32);
 
@@ -118,3 +235,47 @@ gen_tests_for_vector_3!(f32, vec3, glam::Vec3, crate::utils::assert_is_close_f32
 
 #[cfg(all(feature = "f64", feature = "glam-dvec3"))]
 gen_tests_for_vector_3!(f64, dvec3, glam::DVec3, crate::utils::assert_is_close_f64);
+
+#[cfg(all(feature = "f32", feature = "glam-vec4"))]
+gen_tests_for_vector_4!(f32, vec4, glam::Vec4, crate::utils::assert_is_close_f32);
+
+#[cfg(all(feature = "f64", feature = "glam-dvec4"))]
+gen_tests_for_vector_4!(f64, dvec4, glam::DVec4, crate::utils::assert_is_close_f64);
+
+#[cfg(all(feature = "f32", feature = "glam-mat3"))]
+gen_tests_for_matrix_3!(
+    f32,
+    mat3,
+    glam::Mat3,
+    glam::Vec3,
+    crate::utils::assert_is_close_f32
+);
+
+#[cfg(all(feature = "f64", feature = "glam-dmat3"))]
+gen_tests_for_matrix_3!(
+    f64,
+    dmat3,
+    glam::DMat3,
+    glam::DVec3,
+    crate::utils::assert_is_close_f64
+);
+
+#[cfg(all(feature = "f32", feature = "glam-mat2"))]
+mod mat2 {
+    use crate::example_system::dimensions::{Area, Length};
+    use crate::example_system::units;
+    use crate::utils::assert_is_close_f32 as assert_is_close;
+    use glam::{Mat2, Vec2};
+
+    #[test]
+    fn mul_matrix_vector() {
+        let matrix = Length::<Mat2>::from_cols(
+            Vec2::new(1.0, 2.0) * units::meters,
+            Vec2::new(3.0, 4.0) * units::meters,
+        );
+        let product: Area<Vec2> = matrix * (Vec2::new(1.0, 1.0) * units::meters);
+        assert_is_close(product.x(), 4.0f32 * units::square_meters);
+        assert_is_close(product.y(), 6.0f32 * units::square_meters);
+        assert_is_close(matrix.determinant(), -2.0f32 * units::square_meters);
+    }
+}
@@ -0,0 +1,36 @@
+use diman::si::dimensions::{Area, Time, Velocity};
+use diman::si::units::{
+    degrees_celsius, kelvins, meters, meters_per_second, micrometers, seconds, square_meters,
+};
+use half::{bf16, f16};
+
+crate::gen_new_and_value_in_test!(f16_new_and_value_in, f16, f16::from_f64);
+
+crate::gen_new_and_value_in_test!(bf16_new_and_value_in, bf16, bf16::from_f64);
+
+#[test]
+fn half_small_unit_factors_are_not_rounded() {
+    // One micrometer is not representable as a normal `f16`.
+    let x = micrometers.new(f16::from_f32(250.0));
+    let value = x.value_in(micrometers).to_f32();
+    assert!((value - 250.0).abs() <= 0.125);
+}
+
+#[test]
+fn half_arithmetic() {
+    let x = meters.new(f16::from_f32(6.0));
+    let t = seconds.new(f16::from_f32(2.0));
+    let v: Velocity<f16> = x / t;
+    assert_eq!(v, meters_per_second.new(f16::from_f32(3.0)));
+    let t: Time<f16> = x / v;
+    assert_eq!(t, seconds.new(f16::from_f32(2.0)));
+    assert_eq!((-x).abs(), x);
+    let area: Area<bf16> = square_meters.new(bf16::from_f32(16.0));
+    assert_eq!(area.sqrt(), meters.new(bf16::from_f32(4.0)));
+}
+
+#[test]
+fn half_unit_with_offset() {
+    let t = degrees_celsius.new(f16::from_f32(20.0));
+    assert_eq!(t.value_in(kelvins), f16::from_f32(293.25));
+}
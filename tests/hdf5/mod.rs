@@ -0,0 +1,39 @@
+use diman::si::dimensions::Length;
+use diman::si::units::{kilometers, meters};
+
+/// Writes the values into a dataset of a temporary file and reads
+/// them back.
+fn round_trip<T: hdf5::H5Type + Clone>(name: &str, values: &[T]) -> Vec<T> {
+    let path = std::env::temp_dir().join(format!("diman_{}_{}.h5", name, std::process::id()));
+    let file = hdf5::File::create(&path).unwrap();
+    file.new_dataset_builder()
+        .with_data(values)
+        .create(name)
+        .unwrap();
+    let read = file.dataset(name).unwrap().read_raw::<T>().unwrap();
+    drop(file);
+    std::fs::remove_file(path).unwrap();
+    read
+}
+
+#[test]
+fn hdf5_float_round_trip() {
+    let values: [Length<f64>; 2] = [meters.new(1.5), kilometers.new(-2.0)];
+    assert_eq!(round_trip("f64", &values), values);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn hdf5_half_round_trip() {
+    use half::{bf16, f16};
+    let values: [Length<f16>; 2] = [
+        meters.new(f16::from_f32(1.5)),
+        meters.new(f16::from_f32(-2000.0)),
+    ];
+    assert_eq!(round_trip("f16", &values), values);
+    let values: [Length<bf16>; 2] = [
+        meters.new(bf16::from_f32(1.5)),
+        meters.new(bf16::from_f32(-2000.0)),
+    ];
+    assert_eq!(round_trip("bf16", &values), values);
+}
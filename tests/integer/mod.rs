@@ -0,0 +1,127 @@
+#[cfg(feature = "i32")]
+crate::gen_new_and_value_in_test!(integer_new_and_value_in, i32, |x| x as i32);
+
+#[cfg(feature = "i32")]
+#[test]
+fn integer_negative_values() {
+    use diman::si::units::{meters, millimeters};
+    assert_eq!(millimeters.new(-1000).value_in(meters), -1);
+    assert_eq!((-5i32 * meters).value_in(millimeters), -5000);
+}
+
+#[cfg(feature = "i32")]
+#[test]
+#[should_panic(expected = "inexactly")]
+fn integer_new_panics_if_inexact() {
+    use diman::si::units::millimeters;
+    let _ = millimeters.new(1500i32);
+}
+
+#[cfg(feature = "i32")]
+#[test]
+fn integer_inexact_conversions() {
+    use diman::scaling::InexactConversion;
+    use diman::si::units::{millimeters, millivolts, volts};
+    assert_eq!(millimeters.new_checked(1500i32), None);
+    assert_eq!(millivolts.new_exact(2500i32), Err(InexactConversion));
+    assert_eq!(
+        millivolts
+            .new_exact(3000i32)
+            .map(|voltage| voltage.value_in(volts)),
+        Ok(3)
+    );
+    assert_eq!(millimeters.new_saturating(1499i32), millimeters.new(1000));
+    assert_eq!(millimeters.new_wrapping(-1500i32), millimeters.new(-2000));
+}
+
+#[cfg(feature = "i32")]
+#[test]
+fn integer_arithmetic() {
+    use diman::si::dimensions::{Area, Length};
+    use diman::si::units::{meters, square_meters};
+    let x: Length<i32> = meters.new(3);
+    let y: Length<i32> = meters.new(-4);
+    assert_eq!((x + y).value_in(meters), -1);
+    assert_eq!((x + y).abs().value_in(meters), 1);
+    let area: Area<i32> = x * y;
+    assert_eq!(area.value_in(square_meters), -12);
+    assert_eq!((x * 2).value_in(meters), 6);
+}
+
+#[cfg(feature = "i32")]
+#[test]
+#[should_panic]
+fn integer_new_panics_on_overflow() {
+    use diman::si::units::kilovolts;
+    let _ = kilovolts.new(i32::MAX);
+}
+
+#[cfg(feature = "i32")]
+#[test]
+fn integer_checked_wrapping_saturating() {
+    use diman::si::units::{kilovolts, millivolts, volts};
+    assert_eq!(kilovolts.new_checked(3_000_000i32), None);
+    assert_eq!(kilovolts.new_checked(3i32).unwrap().value_in(volts), 3000);
+    assert_eq!(
+        kilovolts.new_saturating(3_000_000i32).value_in(volts),
+        i32::MAX
+    );
+    assert_eq!(
+        kilovolts.new_saturating(-3_000_000i32).value_in(volts),
+        i32::MIN
+    );
+    assert_eq!(
+        kilovolts.new_wrapping(3_000_000i32).value_in(volts),
+        3_000_000_000u32 as i32
+    );
+    let voltage = volts.new(3000i32);
+    assert_eq!(voltage.value_in_checked(millivolts), Some(3_000_000));
+    let voltage = volts.new(3_000_000i32);
+    assert_eq!(voltage.value_in_checked(millivolts), None);
+    assert_eq!(voltage.value_in_saturating(millivolts), i32::MAX);
+    assert_eq!(
+        voltage.value_in_wrapping(millivolts),
+        3_000_000_000u32 as i32
+    );
+}
+
+#[cfg(feature = "u64")]
+#[test]
+fn unsigned_integers() {
+    use diman::si::units::{nanoseconds, seconds};
+    // Quantities are stored in base units, which cannot hold 2.5 seconds.
+    assert_eq!(nanoseconds.new_checked(2_500_000_000u64), None);
+    let time = nanoseconds.new_checked(3_000_000_000u64).unwrap();
+    assert_eq!(time.value_in(seconds), 3);
+    assert_eq!(time.value_in_checked(nanoseconds), Some(3_000_000_000));
+    assert_eq!(
+        seconds.new(u64::MAX).value_in_saturating(nanoseconds),
+        u64::MAX
+    );
+    assert_eq!(
+        seconds.new(u64::MAX).value_in_checked(seconds),
+        Some(u64::MAX)
+    );
+}
+
+#[cfg(feature = "fixed")]
+#[test]
+fn fixed_point() {
+    use diman::si::dimensions::Length;
+    use diman::si::units::{kilometers, meters};
+    use fixed::types::{I16F16, U16F16};
+    let length: Length<I16F16> = kilometers.new(I16F16::from_num(1.5));
+    assert_eq!(length.value_in(meters), I16F16::from_num(1500));
+    assert_eq!((-length).abs(), length);
+    assert_eq!(
+        meters.new(I16F16::from_num(1)).value_in(kilometers),
+        I16F16::from_num(0.001)
+    );
+    assert_eq!(kilometers.new_checked(I16F16::from_num(100)), None);
+    assert_eq!(
+        kilometers
+            .new_saturating(U16F16::from_num(100))
+            .value_in(meters),
+        U16F16::MAX
+    );
+}
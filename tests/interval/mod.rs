@@ -0,0 +1,60 @@
+use diman::interval::{Interval, IntervalOrdering};
+use diman::si::dimensions::{Acceleration, Length, Time, Velocity};
+use diman::si::units::{hours, kilometers, meters, meters_per_second, seconds};
+
+crate::gen_new_and_value_in_test!(interval_new_and_value_in, Interval<f64>, |x| {
+    Interval::new(x, 2.0 * x)
+});
+
+#[test]
+fn interval_new_from_bounds() {
+    let x = Length::<Interval<f64>>::new(kilometers.new(1.5), kilometers.new(2.0));
+    assert_eq!(x.value_in(meters), Interval::new(1500.0, 2000.0));
+    assert_eq!(x.lower(), meters.new(1500.0));
+    assert_eq!(x.upper(), meters.new(2000.0));
+    assert_eq!(x.midpoint(), meters.new(1750.0));
+    assert_eq!(x.width(), meters.new(500.0));
+}
+
+#[test]
+fn interval_inexact_conversion_is_widened() {
+    let v: Velocity<Interval<f64>> = Interval::point(36.0) * (kilometers / hours);
+    assert!(v.lower() < v.upper());
+    assert!(v.contains(meters_per_second.new(10.0)));
+    assert!(v.value_in(kilometers / hours).contains(&36.0));
+}
+
+#[test]
+fn interval_stopping_distance() {
+    let v =
+        Velocity::<Interval<f64>>::new(meters_per_second.new(20.0), meters_per_second.new(25.0));
+    let a: Acceleration<Interval<f64>> = Interval::new(6.0, 8.0) * (meters / seconds.powi::<2>());
+    let distance: Length<Interval<f64>> = v.powi::<2>() / (2.0 * a);
+    assert_eq!(distance.lower(), meters.new(25.0));
+    assert!(distance.contains(meters.new(625.0 / 12.0)));
+    assert!(distance.upper().value_in(meters) <= (625.0f64 / 12.0).next_up());
+    let limit: Length<Interval<f64>> = Interval::point(60.0) * meters;
+    assert_eq!(distance.compare(&limit), IntervalOrdering::Less);
+    assert!(distance < limit);
+}
+
+#[test]
+fn interval_comparison_is_tri_state() {
+    let x = Time::<Interval<f64>>::new(seconds.new(1.0), seconds.new(2.0));
+    let y = Time::<Interval<f64>>::new(seconds.new(1.5), seconds.new(3.0));
+    let z = Time::<Interval<f64>>::new(seconds.new(2.5), seconds.new(3.0));
+    assert_eq!(x.compare(&y), IntervalOrdering::Overlapping);
+    assert_eq!(x.compare(&z), IntervalOrdering::Less);
+    assert_eq!(z.compare(&x), IntervalOrdering::Greater);
+    assert!(!(x < y) && !(x > y));
+}
+
+#[test]
+fn interval_mixed_with_exact_quantities() {
+    let x: Length<Interval<f64>> = Interval::new(2.0, 4.0) * meters;
+    let t: Time<f64> = seconds.new(2.0);
+    assert_eq!((x / t).value_in(meters_per_second), Interval::new(1.0, 2.0));
+    assert_eq!((x * 2.0).value_in(meters), Interval::new(4.0, 8.0));
+    assert_eq!((-x).value_in(meters), Interval::new(-4.0, -2.0));
+    assert_eq!((x - x).value_in(meters), Interval::new(-2.0, 2.0));
+}
@@ -0,0 +1,49 @@
+use diman::si::dimensions::{Angle, Dimensionless, Energy, Entropy, HeatCapacity, Length, Torque};
+use diman::si::units::{degrees, joules, kelvin, meters, newtons, radians};
+
+use crate::utils::assert_is_close_float_f64;
+
+#[test]
+fn kinds_are_distinct_from_plain_dimensions() {
+    let torque: Torque<f64> = (meters.new(2.0) * newtons.new(3.0) / radians.new(1.0)).into_kind();
+    let energy: Energy<f64> = torque.into_kind();
+    assert_is_close_float_f64(energy.value_in(joules), 6.0);
+    let torque: Torque<f64> = energy.into_kind();
+    let energy: Energy<f64> = torque * radians.new(0.5);
+    assert_is_close_float_f64(energy.value_in(joules), 3.0);
+}
+
+#[test]
+fn entropy_is_distinct_from_heat_capacity() {
+    let heat_capacity: HeatCapacity<f64> = joules.new(6.0) / kelvin.new(2.0);
+    let entropy: Entropy<f64> = heat_capacity.into_kind();
+    assert_is_close_float_f64(entropy.value_in(joules / kelvin), 3.0);
+}
+
+#[test]
+fn products_of_kinds_are_plain_dimensions() {
+    let angle: Angle<f64> = radians.new(2.0);
+    let ratio: Dimensionless<f64> = angle / radians.new(1.0);
+    assert_is_close_float_f64(ratio.value(), 2.0);
+    let length: Length<f64> = meters.new(3.0) * angle;
+    assert_is_close_float_f64(length.value_in(meters), 6.0);
+}
+
+#[test]
+fn angle_is_dimensionless() {
+    let angle: Angle<f64> = degrees.new(90.0);
+    assert_is_close_float_f64(angle.value(), std::f64::consts::FRAC_PI_2);
+    assert_is_close_float_f64(angle.sin().value(), 1.0);
+    let ratio: Dimensionless<f64> = meters.new(1.0) / meters.new(2.0);
+    let angle: Angle<f64> = ratio.into_kind();
+    assert_is_close_float_f64(angle.value_in(radians), 0.5);
+}
+
+#[test]
+fn atan2_returns_angle() {
+    let angle: Angle<f64> = meters.new(1.0).atan2(meters.new(-1.0));
+    assert_is_close_float_f64(angle.to_degrees(), 135.0);
+    assert_is_close_float_f64(angle.to_radians(), 3.0 * std::f64::consts::FRAC_PI_4);
+    let angle: Angle<f64> = degrees.new(180.0);
+    assert_is_close_float_f64(angle.to_radians(), std::f64::consts::PI);
+}
@@ -0,0 +1,98 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use crate::example_system::dimensions::{Length, Power};
+            use crate::example_system::units;
+            use crate::make_annotated_unit_constructor;
+
+            make_annotated_unit_constructor!(meters, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(watts, Power<$float_name>, $float_name);
+
+            // Logarithms and exponentials are not exact, so we
+            // compare with a relative tolerance here.
+            fn assert_is_close_float(x: $float_name, y: $float_name) {
+                assert!((x - y).abs() <= 1e-5 * y.abs().max(1.0), "{} {}", x, y)
+            }
+
+            #[test]
+            fn new_level() {
+                let x = units::decibel_milliwatts.new(10.0 as $float_name);
+                assert_eq!(x.value(), 10.0);
+                assert_eq!(*x.value_ref(), 10.0);
+            }
+
+            #[test]
+            fn level_into_quantity() {
+                let x = units::decibel_milliwatts.new(10.0 as $float_name);
+                assert_is_close_float(x.into_quantity().value_in(units::watts), 0.01);
+                let x = units::decibel_watts.new(-10.0 as $float_name);
+                assert_is_close_float(x.into_quantity().value_in(units::watts), 0.1);
+                let x = units::decibel_meters.new(20.0 as $float_name);
+                assert_is_close_float(x.into_quantity().value_in(units::meters), 10.0);
+            }
+
+            #[test]
+            fn level_of_quantity() {
+                let x = units::decibel_milliwatts.level_of(watts(1.0));
+                assert_is_close_float(x.value(), 30.0);
+                let x = units::decibel_watts.level_of(watts(1.0));
+                assert_is_close_float(x.value(), 0.0);
+                let x = units::decibel_meters.level_of(meters(100.0));
+                assert_is_close_float(x.value(), 40.0);
+                let x = units::decibel_watts.level_of(watts(1e-3));
+                assert_is_close_float(x.value(), -30.0);
+            }
+
+            #[test]
+            fn convert_between_logarithmic_units() {
+                let x = units::decibel_milliwatts.new(30.0 as $float_name);
+                let y = units::decibel_watts.level_of(x.into_quantity());
+                assert_is_close_float(y.value(), 0.0);
+            }
+
+            #[test]
+            fn add_power_levels() {
+                let x = units::decibel_milliwatts.new(10.0 as $float_name);
+                let y = units::decibel_milliwatts.new(10.0 as $float_name);
+                assert_is_close_float((x + y).value(), 13.010_3);
+                assert_is_close_float((x + y).into_quantity().value_in(units::watts), 0.02);
+                let z = units::decibel_milliwatts.new(0.0 as $float_name);
+                assert_is_close_float((x + z).into_quantity().value_in(units::watts), 0.011);
+            }
+
+            #[test]
+            fn add_root_power_levels() {
+                let x = units::decibel_meters.new(0.0 as $float_name);
+                let y = units::decibel_meters.new(0.0 as $float_name);
+                assert_is_close_float((x + y).value(), 3.010_3);
+                assert_is_close_float(
+                    (x + y).into_quantity().value_in(units::meters),
+                    (2.0 as $float_name).sqrt(),
+                );
+            }
+
+            #[test]
+            fn add_assign_levels() {
+                let mut x = units::decibel_watts.new(0.0 as $float_name);
+                x += units::decibel_watts.new(0.0);
+                x += units::decibel_watts.new(0.0);
+                assert_is_close_float(x.into_quantity().value_in(units::watts), 3.0);
+            }
+
+            #[test]
+            fn compare_levels() {
+                let x = units::decibel_milliwatts.new(10.0 as $float_name);
+                let y = units::decibel_milliwatts.new(20.0 as $float_name);
+                assert!(x < y);
+                assert!(x == x);
+                assert!(x != y);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64);
@@ -0,0 +1,44 @@
+use diman::si::units::{
+    cubic_meters, electronvolts, joules, kilograms, litres, milligrams, millilitres,
+};
+
+#[test]
+fn derived_unit_factors_are_exact() {
+    assert_eq!(litres.new(1.0).value_in(cubic_meters), 1e-3);
+    assert_eq!(millilitres.new(1.0).value_in(cubic_meters), 1e-6);
+    assert_eq!(milligrams.new(1.0).value_in(kilograms), 1e-6);
+    assert_eq!(cubic_meters.new(1.0).value_in(litres), 1e3);
+}
+
+#[test]
+fn decimal_literals_are_exact() {
+    assert_eq!(electronvolts.new(1.0).value_in(joules), 1.602_176_634e-19);
+    let expected: f32 = "1.602176634e-19".parse().unwrap();
+    assert_eq!(electronvolts.new(1.0f32).value_in(joules), expected);
+}
+
+#[test]
+fn composite_units() {
+    use diman::si::dimensions::{Length, Velocity};
+    use diman::si::units::{hours, kilometers, meters_per_second};
+    let velocity: Velocity<f64> = (kilometers / hours).new(3.6);
+    assert_eq!(velocity.value_in(meters_per_second), 1.0);
+    let velocity: Velocity<f64> = 36.0 * (kilometers / hours);
+    assert_eq!(velocity.value_in(kilometers / hours), 36.0);
+    let length: Length<f64> = (kilometers / hours * hours).new(2.0);
+    assert_eq!(length.value_in(kilometers), 2.0);
+}
+
+#[test]
+fn unit_powers() {
+    use diman::si::dimensions::{Area, Frequency};
+    use diman::si::units::{centimeters, hertz, meters, seconds, square_meters};
+    let area: Area<f64> = meters.powi::<2>().new(4.0);
+    assert_eq!(area.value_in(square_meters), 4.0);
+    let frequency: Frequency<f64> = seconds.powi::<-1>().new(2.0);
+    assert_eq!(frequency.value_in(hertz), 2.0);
+    assert_eq!(
+        centimeters.powi::<3>().new(1.0).value_in(cubic_meters),
+        1e-6
+    );
+}
@@ -0,0 +1,83 @@
+use diman::measurement::Measurement;
+use diman::si::dimensions::{Area, Dimensionless, Length, Time, Velocity};
+use diman::si::units::{kilometers, meters, meters_per_second, seconds, square_meters};
+
+use crate::utils::assert_is_close_float_f64;
+
+fn assert_is_close(x: Measurement<f64>, y: Measurement<f64>) {
+    assert_is_close_float_f64(x.value, y.value);
+    assert_is_close_float_f64(x.uncertainty, y.uncertainty);
+}
+
+crate::gen_new_and_value_in_test!(measurement_new_and_value_in, Measurement<f64>, |x| {
+    Measurement::new(x, x / 4.0)
+});
+
+#[test]
+fn measurement_new_from_nominal_and_uncertainty() {
+    let x = Length::<Measurement<f64>>::new(kilometers.new(1.0), meters.new(10.0));
+    assert_eq!(x.nominal(), meters.new(1000.0));
+    assert_eq!(x.uncertainty(), meters.new(10.0));
+    assert_eq!(x.relative_uncertainty().value(), 0.01);
+    assert_eq!(x.value_in(kilometers), Measurement::new(1.0, 0.01));
+}
+
+#[test]
+fn measurement_sum_and_difference() {
+    let x = Length::<Measurement<f64>>::new(meters.new(3.0), meters.new(0.3));
+    let y = Length::<Measurement<f64>>::new(meters.new(4.0), meters.new(0.4));
+    assert_is_close((x + y).value_in(meters), Measurement::new(7.0, 0.5));
+    assert_is_close((x - y).value_in(meters), Measurement::new(-1.0, 0.5));
+    assert_is_close((-x).value_in(meters), Measurement::new(-3.0, 0.3));
+}
+
+#[test]
+fn measurement_product_and_quotient() {
+    let x = Length::<Measurement<f64>>::new(meters.new(10.0), meters.new(0.3));
+    let t = Time::<Measurement<f64>>::new(seconds.new(2.0), seconds.new(0.1));
+    let v: Velocity<Measurement<f64>> = x / t;
+    assert_is_close(
+        v.value_in(meters_per_second),
+        Measurement::new(5.0, 0.15f64.hypot(0.25)),
+    );
+    let y = Length::<Measurement<f64>>::new(meters.new(2.0), meters.new(0.1));
+    let area: Area<Measurement<f64>> = x * y;
+    assert_is_close(
+        area.value_in(square_meters),
+        Measurement::new(20.0, 0.6f64.hypot(1.0)),
+    );
+}
+
+#[test]
+fn measurement_mixed_with_exact_quantities() {
+    let x = Length::<Measurement<f64>>::new(meters.new(10.0), meters.new(0.3));
+    let t: Time<f64> = seconds.new(2.0);
+    assert_is_close(
+        (x / t).value_in(meters_per_second),
+        Measurement::new(5.0, 0.15),
+    );
+    assert_is_close((x * 2.0).value_in(meters), Measurement::new(20.0, 0.6));
+    assert_is_close((-2.0 * x).value_in(meters), Measurement::new(-20.0, 0.6));
+    let inverse = 100.0 / x;
+    assert_is_close(inverse.value_unchecked(), Measurement::new(10.0, 0.3));
+}
+
+#[test]
+fn measurement_powi_and_sqrt() {
+    let x = Length::<Measurement<f64>>::new(meters.new(3.0), meters.new(0.1));
+    let area: Area<Measurement<f64>> = x.powi::<2>();
+    assert_is_close(area.value_in(square_meters), Measurement::new(9.0, 0.6));
+    let area: Area<Measurement<f64>> = Measurement::new(16.0, 0.8) * square_meters;
+    let side: Length<Measurement<f64>> = area.sqrt();
+    assert_is_close(side.value_in(meters), Measurement::new(4.0, 0.1));
+}
+
+#[test]
+fn measurement_dimensionless_float_functions() {
+    let x: Dimensionless<Measurement<f64>> = Measurement::new(0.0, 0.1) * meters / meters;
+    assert_is_close(x.sin().value(), Measurement::new(0.0, 0.1));
+    assert_is_close(x.cos().value(), Measurement::new(1.0, 0.0));
+    assert_is_close(x.exp().value(), Measurement::new(1.0, 0.1));
+    let x: Dimensionless<Measurement<f64>> = Measurement::new(2.0, 0.2) * meters / meters;
+    assert_is_close(x.ln().value(), Measurement::new(2.0f64.ln(), 0.1));
+}
@@ -6,6 +6,10 @@ pub mod utils;
 
 mod float;
 
+mod point;
+
+mod logarithmic;
+
 mod type_aliases;
 
 pub mod unit_aliases;
@@ -20,9 +24,79 @@ mod dimension_defs;
 #[cfg(feature = "f64")]
 mod gas;
 
+#[cfg(feature = "si")]
+#[cfg(feature = "f64")]
+mod extension;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "f64")]
+mod conversion;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "f64")]
+mod kinds;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "f64")]
+#[cfg(feature = "f32")]
+mod magnitude;
+
+#[cfg(feature = "si")]
+mod integer;
+
+#[cfg(feature = "f64")]
+mod storage_type;
+
 #[cfg(feature = "glam")]
 mod glam;
 
+#[cfg(feature = "si")]
+#[cfg(feature = "num-complex")]
+#[cfg(feature = "f32")]
+#[cfg(feature = "f64")]
+mod complex;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "nalgebra")]
+#[cfg(feature = "f64")]
+mod nalgebra;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "ndarray")]
+#[cfg(feature = "f64")]
+mod ndarray;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "measurement")]
+#[cfg(feature = "f64")]
+mod measurement;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "interval")]
+#[cfg(feature = "f64")]
+mod interval;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "dual")]
+#[cfg(feature = "f64")]
+mod dual;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "half")]
+mod half;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "f64")]
+mod ordered;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+
+#[cfg(feature = "si")]
+#[cfg(feature = "num-rational")]
+mod rational;
+
 #[cfg(feature = "mpi")]
 mod mpi;
 
@@ -32,5 +106,10 @@ mod serde;
 #[cfg(feature = "rand")]
 mod rand;
 
+#[cfg(feature = "si")]
+#[cfg(feature = "hdf5")]
+#[cfg(feature = "f64")]
+mod hdf5;
+
 #[cfg(feature = "rational-dimensions")]
 pub mod rational_dimensions;
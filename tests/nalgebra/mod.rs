@@ -0,0 +1,80 @@
+use diman::si::dimensions::{Area, Force, Length, Time, Velocity};
+use diman::si::units::{kilometers, meters, meters_per_second, newtons, seconds, square_meters};
+use nalgebra::{DVector, SVector, Vector3};
+
+crate::gen_new_and_value_in_test!(nalgebra_static_vector_new_and_value_in, Vector3<f64>, |x| {
+    Vector3::new(x, 2.0 * x, -x)
+});
+
+#[test]
+fn nalgebra_static_vector_from_quantities() {
+    let x: Length<SVector<f64, 2>> = [meters.new(1.0), kilometers.new(2.0)].into();
+    assert_eq!(x.value_in(meters), SVector::from([1.0, 2000.0]));
+}
+
+#[test]
+fn nalgebra_static_vector_element_access() {
+    let mut x: Length<SVector<f64, 3>> = Vector3::new(1.0, 2.0, 3.0) * meters;
+    assert_eq!(x.get(1), Some(meters.new(2.0)));
+    assert_eq!(x.get(3), None);
+    x.set(2, kilometers.new(1.0));
+    assert_eq!(
+        x.iter().collect::<Vec<_>>(),
+        [meters.new(1.0), meters.new(2.0), meters.new(1000.0)]
+    );
+}
+
+#[test]
+fn nalgebra_static_vector_norm_dot_cross() {
+    let x: Length<Vector3<f64>> = Vector3::new(3.0, 0.0, 4.0) * meters;
+    let y: Length<Vector3<f64>> = Vector3::new(0.0, 2.0, 0.0) * meters;
+    assert_eq!(x.norm(), meters.new(5.0));
+    assert_eq!(x.length(), meters.new(5.0));
+    let area: Area<f64> = x.dot(y);
+    assert_eq!(area, square_meters.new(0.0));
+    let cross: Area<Vector3<f64>> = x.cross(&y);
+    assert_eq!(cross.value_in(square_meters), Vector3::new(-8.0, 0.0, 6.0));
+    assert_eq!(x.normalize().value(), Vector3::new(0.6, 0.0, 0.8));
+}
+
+#[test]
+fn nalgebra_static_vector_arithmetic() {
+    let x: Length<Vector3<f64>> = Vector3::new(1.0, 2.0, 3.0) * meters;
+    let t: Time<f64> = seconds.new(2.0);
+    let v: Velocity<Vector3<f64>> = x / t;
+    assert_eq!(v.value_in(meters_per_second), Vector3::new(0.5, 1.0, 1.5));
+    assert_eq!((x + x).value_in(meters), Vector3::new(2.0, 4.0, 6.0));
+    assert_eq!((x * 2.0).value_in(meters), Vector3::new(2.0, 4.0, 6.0));
+    assert_eq!((x / 2.0).value_in(meters), Vector3::new(0.5, 1.0, 1.5));
+    assert_eq!((-x).value_in(meters), Vector3::new(-1.0, -2.0, -3.0));
+    let force: Force<f64> = newtons.new(2.0);
+    assert_eq!(
+        (force * Vector3::new(1.0, 0.0, 0.0)).value_in(newtons),
+        Vector3::new(2.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn nalgebra_dynamic_vector() {
+    let x: Length<DVector<f64>> = DVector::from_vec(vec![3.0, 4.0]) * kilometers;
+    assert_eq!(
+        x.clone().value_in(meters),
+        DVector::from_vec(vec![3000.0, 4000.0])
+    );
+    assert_eq!(x.get(1), Some(kilometers.new(4.0)));
+    assert_eq!(x.norm(), kilometers.new(5.0));
+    let y = Length::<DVector<f64>>::from_slice(&[meters.new(1.0), meters.new(2.0)]);
+    let area: Area<f64> = x.clone().dot(y.clone());
+    assert_eq!(area, square_meters.new(11000.0));
+    let sum = x + y * 2.0;
+    assert_eq!(
+        sum.value_in(meters),
+        DVector::from_vec(vec![3002.0, 4004.0])
+    );
+    let z: Length<DVector<f64>> = [meters.new(1.0), meters.new(0.0), meters.new(0.0)].into();
+    let w: Length<DVector<f64>> = [meters.new(0.0), meters.new(1.0), meters.new(0.0)].into();
+    assert_eq!(
+        z.cross(&w).value_in(square_meters),
+        DVector::from_vec(vec![0.0, 0.0, 1.0])
+    );
+}
@@ -0,0 +1,72 @@
+use diman::si::dimensions::{Area, Dimensionless, Length, Time, Velocity};
+use diman::si::units::{kilometers, meters, meters_per_second, seconds, square_meters};
+use ndarray::{array, Array1, Array2, Array3};
+
+crate::gen_new_and_value_in_test!(ndarray_new_and_value_in, Array1<f64>, |x| {
+    array![x, 2.0 * x]
+});
+
+#[test]
+fn ndarray_from_elem() {
+    let x = Length::<Array3<f64>>::from_elem((2, 3, 4), meters.new(2.0));
+    assert_eq!(x.shape(), &[2, 3, 4]);
+    assert_eq!(x.value_in(kilometers), Array3::from_elem((2, 3, 4), 0.002));
+}
+
+#[test]
+fn ndarray_element_access() {
+    let mut x: Length<Array2<f64>> = array![[1.0, 2.0], [3.0, 4.0]] * meters;
+    assert_eq!(x.get([1, 0]), Some(meters.new(3.0)));
+    assert_eq!(x.get([2, 0]), None);
+    x.set([0, 1], kilometers.new(1.0));
+    assert_eq!(
+        x.iter().collect::<Vec<_>>(),
+        [
+            meters.new(1.0),
+            meters.new(1000.0),
+            meters.new(3.0),
+            meters.new(4.0)
+        ]
+    );
+}
+
+#[test]
+fn ndarray_reductions() {
+    let x: Length<Array2<f64>> = array![[1.0, 2.0], [3.0, 6.0]] * meters;
+    assert_eq!(x.sum(), meters.new(12.0));
+    assert_eq!(x.mean(), Some(meters.new(3.0)));
+    let empty: Length<Array1<f64>> = Array1::<f64>::zeros(0) * meters;
+    assert_eq!(empty.sum(), meters.new(0.0));
+    assert_eq!(empty.mean(), None);
+}
+
+#[test]
+fn ndarray_elementwise_arithmetic() {
+    let x: Length<Array1<f64>> = array![1.0, 2.0, 3.0] * meters;
+    let y: Length<Array1<f64>> = array![2.0, 2.0, 2.0] * meters;
+    let area: Area<Array1<f64>> = x.clone() * y.clone();
+    assert_eq!(area.value_in(square_meters), array![2.0, 4.0, 6.0]);
+    let ratio: Dimensionless<Array1<f64>> = x.clone() / y.clone();
+    assert_eq!(ratio.value(), array![0.5, 1.0, 1.5]);
+    assert_eq!(
+        (x.clone() + y.clone()).value_in(meters),
+        array![3.0, 4.0, 5.0]
+    );
+    assert_eq!((x.clone() - y).value_in(meters), array![-1.0, 0.0, 1.0]);
+    assert_eq!((-x).value_in(meters), array![-1.0, -2.0, -3.0]);
+}
+
+#[test]
+fn ndarray_broadcast_scalars() {
+    let x: Length<Array1<f64>> = array![1.0, 2.0, 4.0] * meters;
+    let t: Time<f64> = seconds.new(2.0);
+    let v: Velocity<Array1<f64>> = x.clone() / t;
+    assert_eq!(v.value_in(meters_per_second), array![0.5, 1.0, 2.0]);
+    let area: Area<Array1<f64>> = meters.new(2.0) * x.clone();
+    assert_eq!(area.value_in(square_meters), array![2.0, 4.0, 8.0]);
+    assert_eq!((x.clone() * 2.0).value_in(meters), array![2.0, 4.0, 8.0]);
+    assert_eq!((x.clone() / 2.0).value_in(meters), array![0.5, 1.0, 2.0]);
+    assert_eq!((2.0 * x.clone()).value_in(meters), array![2.0, 4.0, 8.0]);
+    let inverse = 4.0 / x;
+    assert_eq!(inverse.value_unchecked(), array![4.0, 2.0, 1.0]);
+}
@@ -0,0 +1,66 @@
+use std::collections::{BTreeMap, HashSet};
+
+use diman::ordered::Ordered;
+use diman::si::dimensions::{Length, Time};
+use diman::si::units::{kilometers, meters, milliseconds, seconds};
+
+#[test]
+fn sort_by_time() {
+    let mut events = [
+        (seconds.new(2.0), "second"),
+        (milliseconds.new(500.0), "first"),
+        (seconds.new(10.0), "third"),
+    ];
+    events.sort_by_key(|(time, _)| Ordered(*time));
+    assert_eq!(events.map(|(_, name)| name), ["first", "second", "third"]);
+}
+
+#[test]
+fn nan_is_sorted_last() {
+    let mut times: Vec<Ordered<Time<f64>>> =
+        [seconds.new(1.0), Time::<f64>::NAN, seconds.new(-1.0)]
+            .into_iter()
+            .map(Ordered)
+            .collect();
+    times.sort();
+    assert_eq!(times[0], Ordered(seconds.new(-1.0)));
+    assert_eq!(times[1], Ordered(seconds.new(1.0)));
+    assert!(times[2].0.is_nan());
+}
+
+#[test]
+fn bucket_by_length() {
+    let mut buckets: BTreeMap<Ordered<Length<f64>>, Vec<&str>> = BTreeMap::new();
+    for (length, name) in [
+        (meters.new(1000.0), "a"),
+        (meters.new(5.0), "b"),
+        (kilometers.new(1.0), "c"),
+    ] {
+        buckets.entry(Ordered(length)).or_default().push(name);
+    }
+    let buckets: Vec<_> = buckets.into_values().collect();
+    assert_eq!(buckets, [vec!["b"], vec!["a", "c"]]);
+}
+
+#[test]
+fn hash_set_of_lengths() {
+    let lengths: HashSet<_> = [meters.new(1.0), meters.new(2.0), meters.new(1.0)]
+        .into_iter()
+        .map(Ordered)
+        .collect();
+    assert_eq!(lengths.len(), 2);
+    assert!(lengths.contains(&Ordered(meters.new(2.0))));
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn integer_quantities_are_totally_ordered() {
+    use std::collections::BTreeSet;
+    let lengths: Vec<Length<i64>> = vec![meters.new(3), meters.new(1), meters.new(2)];
+    let lengths: BTreeSet<_> = lengths.into_iter().collect();
+    let lengths: Vec<_> = lengths.into_iter().map(|l| l.value_in(meters)).collect();
+    assert_eq!(lengths, [1, 2, 3]);
+    let mut set = HashSet::new();
+    assert!(set.insert(meters.new(1i64)));
+    assert!(!set.insert(meters.new(1i64)));
+}
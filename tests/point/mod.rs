@@ -0,0 +1,94 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident, $assert_is_close: path, $assert_is_close_float: path) => {
+        mod $float_name {
+            use crate::example_system::dimensions::{Length, Temperature};
+            use crate::example_system::units;
+            use crate::example_system::Point;
+            use crate::make_annotated_unit_constructor;
+            use $assert_is_close as assert_is_close;
+            use $assert_is_close_float as assert_is_close_float;
+
+            make_annotated_unit_constructor!(meters, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(kelvins, Temperature<$float_name>, $float_name);
+
+            #[test]
+            fn sub_point_point() {
+                let x = units::kelvins.new_point(300.0 as $float_name);
+                let y = units::kelvins.new_point(280.0);
+                assert_is_close(x - y, kelvins(20.0));
+                assert_is_close(&x - y, kelvins(20.0));
+                assert_is_close(x - &y, kelvins(20.0));
+                assert_is_close(&x - &y, kelvins(20.0));
+            }
+
+            #[test]
+            fn add_point_quantity() {
+                let x = units::meters.new_point(1.0 as $float_name);
+                let y = meters(2.0);
+                assert_eq!(x + y, units::meters.new_point(3.0));
+                assert_eq!(&x + y, units::meters.new_point(3.0));
+                assert_eq!(x + &y, units::meters.new_point(3.0));
+                assert_eq!(&x + &y, units::meters.new_point(3.0));
+                assert_eq!(y + x, units::meters.new_point(3.0));
+                assert_eq!(&y + &x, units::meters.new_point(3.0));
+            }
+
+            #[test]
+            fn sub_point_quantity() {
+                let x = units::meters.new_point(5.0 as $float_name);
+                let y = meters(2.0);
+                assert_eq!(x - y, units::meters.new_point(3.0));
+                assert_eq!(&x - &y, units::meters.new_point(3.0));
+            }
+
+            #[test]
+            fn add_assign_sub_assign_point_quantity() {
+                let mut x = units::meters.new_point(5.0 as $float_name);
+                x += meters(2.0);
+                assert_eq!(x, units::meters.new_point(7.0));
+                x -= &meters(4.0);
+                assert_eq!(x, units::meters.new_point(3.0));
+            }
+
+            #[test]
+            fn partial_ord_point_point() {
+                let x = units::meters.new_point(5.0 as $float_name);
+                let y = units::meters.new_point(3.0);
+                assert!(x > y);
+                assert!(&x > y);
+                assert!(x >= &y);
+                assert!(x != y);
+            }
+
+            #[test]
+            fn point_with_offset() {
+                let x = units::celsius.new_point(20.0 as $float_name);
+                let y = units::celsius.new_point(30.0);
+                assert_is_close_float(x.value_in(units::celsius), 20.0);
+                assert_is_close(y - x, kelvins(10.0));
+                assert_is_close_float((y - x).value_in(units::kelvins), 10.0);
+            }
+
+            #[test]
+            fn point_from_origin() {
+                let x = Point::from_origin(meters(5.0));
+                assert_eq!(x, units::meters.new_point(5.0));
+                assert_is_close(x.displacement_from_origin(), meters(5.0));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(
+    f32,
+    crate::utils::assert_is_close_f32,
+    crate::utils::assert_is_close_float_f32
+);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(
+    f64,
+    crate::utils::assert_is_close_f64,
+    crate::utils::assert_is_close_float_f64
+);
@@ -28,3 +28,26 @@ gen_tests_for_float!(f32);
 
 #[cfg(feature = "f64")]
 gen_tests_for_float!(f64);
+
+#[cfg(feature = "half")]
+mod half_precision {
+    use half::f16;
+    use rand::Rng;
+
+    use crate::example_system::dimensions::Length;
+    use crate::example_system::units::{kilometers, meters};
+
+    #[test]
+    fn test_random_half_quantity_generation() {
+        let mut rng = rand::thread_rng();
+        let low: Length<f16> = meters.new(f16::from_f32(0.0));
+        let high: Length<f16> = kilometers.new(f16::from_f32(1.0));
+        for _ in 0..100 {
+            let x = rng.gen_range(low..high);
+            assert!(low <= x);
+            assert!(x < high);
+            let x = rng.gen_range(low..=high);
+            assert!(low <= x && x <= high);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use diman::scaling::InexactConversion;
+use diman::si::dimensions::{Time, Velocity};
+use diman::si::units::{hours, meters, minutes, seconds};
+use num_rational::Ratio;
+
+crate::gen_new_and_value_in_test!(rational_new_and_value_in, Ratio<i64>, |x| {
+    Ratio::from_integer(x as i64)
+});
+
+#[test]
+fn rational_conversions_are_exact() {
+    let v: Velocity<Ratio<i64>> = (meters / minutes).new(Ratio::from_integer(1));
+    assert_eq!(v.value_in(meters / seconds), Ratio::new(1, 60));
+    assert_eq!(v.value_in(meters / minutes), Ratio::from_integer(1));
+    let t: Time<Ratio<i64>> = hours.new(Ratio::new(1, 3));
+    assert_eq!(t.value_in(minutes), Ratio::from_integer(20));
+    assert_eq!(t.value_in_exact(seconds), Ok(Ratio::from_integer(1200)));
+}
+
+#[test]
+fn rational_conversions_out_of_range_are_errors() {
+    assert_eq!(
+        hours.new_exact(Ratio::from_integer(i64::MAX)),
+        Err(InexactConversion)
+    );
+    let t: Time<Ratio<i64>> = seconds.new(Ratio::new(1, i64::MAX));
+    assert_eq!(t.value_in_exact(hours), Err(InexactConversion));
+}
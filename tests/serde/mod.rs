@@ -2,7 +2,7 @@
 macro_rules! gen_tests_for_float {
     ($float_name: ident, $assert_is_close: path) => {
         mod $float_name {
-            use crate::example_system::dimensions::{Energy, Length, Time, Velocity};
+            use crate::example_system::dimensions::{Energy, Length, Temperature, Time, Velocity};
             use crate::example_system::units;
             use crate::make_annotated_unit_constructor;
             use $assert_is_close as assert_is_close;
@@ -19,6 +19,28 @@ macro_rules! gen_tests_for_float {
                 assert_is_close(q, kilometers(5.0) / seconds(1.0));
             }
 
+            #[test]
+            fn deserialize_float_string_symbol() {
+                let q: Length<$float_name> = serde_yaml::from_str("5.0 Å").unwrap();
+                assert_is_close(q, units::angstroms.new(5.0));
+                let q: Velocity<$float_name> = serde_yaml::from_str("5.0 Å s^-1").unwrap();
+                assert_is_close(q, units::angstroms.new(5.0) / seconds(1.0));
+            }
+
+            #[test]
+            fn deserialize_float_unit_with_offset() {
+                let q: Temperature<$float_name> = serde_yaml::from_str("0.0 degC").unwrap();
+                assert_is_close(q, units::kelvins.new(273.15));
+                let q: Temperature<$float_name> = serde_yaml::from_str("-273.15 degC").unwrap();
+                assert_is_close(q, units::kelvins.new(0.0));
+            }
+
+            #[test]
+            #[should_panic(expected = "cannot be combined with other units")]
+            fn deserialize_float_unit_with_offset_combined() {
+                let _: Temperature<$float_name> = serde_yaml::from_str("1.0 degC m m^-1").unwrap();
+            }
+
             #[test]
             #[should_panic(expected = "mismatch in dimensions")]
             fn deserialize_float_dimension_mismatch() {
@@ -146,6 +168,42 @@ macro_rules! gen_tests_for_vector_3 {
     };
 }
 
+#[cfg(any(feature = "glam-vec4", feature = "glam-dvec4"))]
+macro_rules! gen_tests_for_vector_4 {
+    ($float_name: ident, $mod_name: ident, $vec_name: ty, $assert_is_close: path) => {
+        mod $mod_name {
+            use crate::example_system::dimensions::Length;
+            use crate::example_system::units;
+            use crate::example_system::units::meters;
+            use $assert_is_close as assert_is_close;
+            use $vec_name as Vec4;
+
+            use crate::make_annotated_unit_constructor;
+            make_annotated_unit_constructor!(kilometers, Length<$float_name>, $float_name);
+
+            #[test]
+            fn deserialize_vector() {
+                let q: Length<Vec4> = serde_yaml::from_str("(5.0 3.0 7.0 9.0) km").unwrap();
+                assert_is_close(q.x(), kilometers(5.0));
+                assert_is_close(q.w(), kilometers(9.0));
+            }
+
+            #[test]
+            #[should_panic]
+            fn deserialize_vector_fails_with_fewer_than_4_components() {
+                let _: Length<Vec4> = serde_yaml::from_str("(5.0 3.0 7.0) km").unwrap();
+            }
+
+            #[test]
+            fn serialize_vector() {
+                let x = meters * Vec4::new(5.3, 1.1, 2.2, 0.5);
+                let result: String = serde_yaml::to_string(&x).unwrap();
+                assert_eq!(result, "(5.3 1.1 2.2 0.5) m\n");
+            }
+        }
+    };
+}
+
 #[cfg(feature = "f32")]
 gen_tests_for_float!(f32, crate::utils::assert_is_close_f32);
 
@@ -163,3 +221,108 @@ gen_tests_for_vector_3!(f32, vec3, glam::Vec3, crate::utils::assert_is_close_f32
 
 #[cfg(all(feature = "f64", feature = "glam-dvec3"))]
 gen_tests_for_vector_3!(f64, dvec3, glam::DVec3, crate::utils::assert_is_close_f64);
+
+#[cfg(all(feature = "f32", feature = "glam-vec4"))]
+gen_tests_for_vector_4!(f32, vec4, glam::Vec4, crate::utils::assert_is_close_f32);
+
+#[cfg(all(feature = "f64", feature = "glam-dvec4"))]
+gen_tests_for_vector_4!(f64, dvec4, glam::DVec4, crate::utils::assert_is_close_f64);
+
+#[cfg(all(feature = "f64", feature = "measurement"))]
+mod measurement {
+    use crate::example_system::dimensions::{Length, Temperature, Velocity};
+    use crate::example_system::units::{kilometers, meters, meters_per_second};
+    use diman::measurement::Measurement;
+
+    #[test]
+    fn deserialize_measurement_concise_notation() {
+        let q: Velocity<Measurement<f64>> = serde_yaml::from_str("9.81(2) m s^-1").unwrap();
+        assert_eq!(q.value_in(meters_per_second), Measurement::new(9.81, 0.02));
+        let q: Length<Measurement<f64>> = serde_yaml::from_str("1.5(1) km").unwrap();
+        assert_eq!(q.nominal(), kilometers.new(1.5));
+        assert_eq!(q.uncertainty(), meters.new(100.0));
+    }
+
+    #[test]
+    fn deserialize_measurement_plus_minus() {
+        let q: Velocity<Measurement<f64>> = serde_yaml::from_str("9.81 ± 0.02 m s^-1").unwrap();
+        assert_eq!(q.value_in(meters_per_second), Measurement::new(9.81, 0.02));
+        let q: Length<Measurement<f64>> = serde_yaml::from_str("9.81±0.02 m").unwrap();
+        assert_eq!(q.value_in(meters), Measurement::new(9.81, 0.02));
+    }
+
+    #[test]
+    #[should_panic(expected = "mismatch in dimensions")]
+    fn deserialize_measurement_dimension_mismatch() {
+        let _: Length<Measurement<f64>> = serde_yaml::from_str("9.81(2) s").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "units with an offset are not supported")]
+    fn deserialize_measurement_unit_with_offset() {
+        let _: Temperature<Measurement<f64>> = serde_yaml::from_str("20.0(1) degC").unwrap();
+    }
+
+    #[test]
+    fn serialize_measurement() {
+        let q = Length::<Measurement<f64>>::new(meters.new(9.5), meters.new(0.25));
+        assert_eq!(serde_yaml::to_string(&q).unwrap().trim(), "9.5 ± 0.25 m");
+        let round_trip: Length<Measurement<f64>> =
+            serde_yaml::from_str(&serde_yaml::to_string(&q).unwrap()).unwrap();
+        assert_eq!(round_trip, q);
+    }
+}
+
+#[cfg(feature = "half")]
+mod half_precision {
+    use crate::example_system::dimensions::{Length, Temperature};
+    use crate::example_system::units::{kelvins, kilometers, meters};
+    use half::{bf16, f16};
+
+    #[test]
+    fn deserialize_half() {
+        let q: Length<f16> = serde_yaml::from_str("1.5 km").unwrap();
+        assert_eq!(q, kilometers.new(f16::from_f32(1.5)));
+        let q: Length<bf16> = serde_yaml::from_str("2.0 km").unwrap();
+        assert_eq!(q, meters.new(bf16::from_f32(2000.0)));
+    }
+
+    #[test]
+    fn deserialize_half_unit_with_offset() {
+        let q: Temperature<f16> = serde_yaml::from_str("20.0 degC").unwrap();
+        assert_eq!(q, kelvins.new(f16::from_f32(293.25)));
+    }
+
+    #[test]
+    #[should_panic(expected = "mismatch in dimensions")]
+    fn deserialize_half_dimension_mismatch() {
+        let _: Length<f16> = serde_yaml::from_str("5.0 kg").unwrap();
+    }
+
+    #[test]
+    fn serialize_half() {
+        let q = kilometers.new(f16::from_f32(1.5));
+        assert_eq!(serde_yaml::to_string(&q).unwrap().trim(), "1500 m");
+        let round_trip: Length<f16> =
+            serde_yaml::from_str(&serde_yaml::to_string(&q).unwrap()).unwrap();
+        assert_eq!(round_trip, q);
+    }
+}
+
+#[cfg(all(feature = "si", feature = "f64"))]
+mod si {
+    use diman::si::dimensions::{Angle, Temperature};
+    use diman::si::units::{degrees, degrees_celsius};
+
+    use crate::utils::assert_is_close_float_f64;
+
+    #[test]
+    fn deserialize_alternative_symbols() {
+        let q: Angle<f64> = serde_yaml::from_str("90.0 deg").unwrap();
+        assert_is_close_float_f64(q.value_in(degrees), 90.0);
+        let q: Angle<f64> = serde_yaml::from_str("90.0 °").unwrap();
+        assert_is_close_float_f64(q.value_in(degrees), 90.0);
+        let q: Temperature<f64> = serde_yaml::from_str("20.0 degC").unwrap();
+        assert_is_close_float_f64(q.value_in(degrees_celsius), 20.0);
+    }
+}
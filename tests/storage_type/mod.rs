@@ -0,0 +1,338 @@
+//! Example of storage types which are owned by the user and
+//! declared in the unit system.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use diman::internal::magnitude::Magnitude;
+use diman::storage::{FloatStorage, QuantityStorage, RealStorage, VectorStorage};
+
+/// A float which is stored as an `f64`, standing in for types such
+/// as half-precision floats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Float(pub f64);
+
+impl Float {
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl QuantityStorage for Float {}
+
+impl RealStorage for Float {
+    fn zero() -> Self {
+        Self(0.0)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+}
+
+macro_rules! forward_methods {
+    ($($method: ident),*) => {
+        $(
+            fn $method(self) -> Self {
+                Self(self.0.$method())
+            }
+        )*
+    };
+}
+
+impl FloatStorage for Float {
+    fn powi(self, n: i32) -> Self {
+        Self(self.0.powi(n))
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Self(self.0.clamp(min.0, max.0))
+    }
+
+    fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    forward_methods!(
+        sqrt, cbrt, log2, ln, log10, exp, exp2, ceil, floor, sin, cos, tan, asin, acos, atan, sinh,
+        cosh, tanh, asinh, acosh, atanh, exp_m1, ln_1p
+    );
+}
+
+impl core::fmt::Display for Float {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! forward_operator {
+    ($trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident) => {
+        impl $trait for Float {
+            type Output = Float;
+
+            fn $method(self, rhs: Float) -> Float {
+                Float($trait::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $assign_trait for Float {
+            fn $assign_method(&mut self, rhs: Float) {
+                $assign_trait::$assign_method(&mut self.0, rhs.0)
+            }
+        }
+    };
+}
+
+forward_operator!(Add, add, AddAssign, add_assign);
+forward_operator!(Sub, sub, SubAssign, sub_assign);
+forward_operator!(Mul, mul, MulAssign, mul_assign);
+forward_operator!(Div, div, DivAssign, div_assign);
+
+impl Neg for Float {
+    type Output = Float;
+
+    fn neg(self) -> Float {
+        Float(-self.0)
+    }
+}
+
+impl Mul<Magnitude> for Float {
+    type Output = Float;
+
+    fn mul(self, rhs: Magnitude) -> Float {
+        Float(self.0 * rhs)
+    }
+}
+
+impl Div<Magnitude> for Float {
+    type Output = Float;
+
+    fn div(self, rhs: Magnitude) -> Float {
+        Float(self.0 / rhs)
+    }
+}
+
+/// A two-dimensional vector with the same interface as the `glam` vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vector {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vector {
+    pub const X: Self = Self::new(1.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0);
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+}
+
+impl QuantityStorage for Vector {}
+
+impl RealStorage for Vector {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl VectorStorage for Vector {
+    type Scalar = f64;
+
+    fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    fn distance_squared(&self, other: &Self) -> f64 {
+        (*self - *other).dot(&(*self - *other))
+    }
+
+    fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl core::fmt::Display for Vector {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+macro_rules! forward_vector_operator {
+    ($trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident) => {
+        impl $trait for Vector {
+            type Output = Vector;
+
+            fn $method(self, rhs: Vector) -> Vector {
+                Vector::new(
+                    $trait::$method(self.x, rhs.x),
+                    $trait::$method(self.y, rhs.y),
+                )
+            }
+        }
+
+        impl $assign_trait for Vector {
+            fn $assign_method(&mut self, rhs: Vector) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+forward_vector_operator!(Add, add, AddAssign, add_assign);
+forward_vector_operator!(Sub, sub, SubAssign, sub_assign);
+forward_vector_operator!(Mul, mul, MulAssign, mul_assign);
+forward_vector_operator!(Div, div, DivAssign, div_assign);
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: f64) -> Vector {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Mul<Vector> for f64 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Vector {
+        rhs * self
+    }
+}
+
+impl Div<f64> for Vector {
+    type Output = Vector;
+
+    fn div(self, rhs: f64) -> Vector {
+        Vector::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl Div<Vector> for f64 {
+    type Output = Vector;
+
+    fn div(self, rhs: Vector) -> Vector {
+        Vector::new(self / rhs.x, self / rhs.y)
+    }
+}
+
+impl Mul<Magnitude> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: Magnitude) -> Vector {
+        self * rhs.into_f64()
+    }
+}
+
+impl Div<Magnitude> for Vector {
+    type Output = Vector;
+
+    fn div(self, rhs: Magnitude) -> Vector {
+        self * (1.0 / rhs.into_f64())
+    }
+}
+
+mod system {
+    diman::unit_system!(
+        quantity_type Quantity;
+        dimension_type Dimension;
+
+        storage_type super::Float;
+        storage_type super::Vector: vector(f64, 2);
+
+        dimension Length;
+        dimension Time;
+        dimension Area = Length^2;
+        dimension Velocity = Length / Time;
+        dimension Dimensionless = 1;
+
+        #[base(Length)]
+        #[symbol(m)]
+        unit meters;
+
+        #[base(Time)]
+        #[symbol(s)]
+        unit seconds;
+
+        unit kilometers = 1000 meters;
+        unit hours = 3600 seconds;
+        unit square_meters = meters^2;
+    );
+}
+
+use system::{
+    dimensions::{Area, Dimensionless, Length, Velocity},
+    units::{hours, kilometers, meters, seconds, square_meters},
+};
+
+#[test]
+fn custom_scalar_storage_type() {
+    let x: Length<Float> = kilometers.new(Float(1.5));
+    assert_eq!(x.value_in(meters), Float(1500.0));
+    let y: Length<Float> = Float(500.0) * meters;
+    assert_eq!((x + y).value_in(kilometers), Float(2.0));
+    let area: Area<Float> = x * y;
+    assert_eq!(area.value_in(square_meters), Float(750000.0));
+    assert_eq!(area.sqrt().value_in(meters), Float(750000.0f64.sqrt()));
+    let velocity: Velocity<Float> = x / seconds.new(Float(3.0));
+    assert_eq!(velocity.value_in(meters / seconds), Float(500.0));
+    assert_eq!((-x).abs(), x);
+    assert!(x.is_positive());
+    assert_eq!(Length::<Float>::zero(), meters.new(Float(0.0)));
+    assert_eq!(
+        (x * Float(2.0)).value_in(kilometers / hours * hours),
+        Float(3.0)
+    );
+}
+
+#[test]
+fn custom_scalar_storage_type_float_methods() {
+    let x: Dimensionless<Float> = (meters / meters).new(Float(0.5));
+    assert_eq!(x.sin().value(), Float(0.5f64.sin()));
+    assert_eq!(x.exp().value(), Float(0.5f64.exp()));
+}
+
+#[test]
+fn custom_vector_storage_type() {
+    let x: Length<Vector> = kilometers.new(Vector::new(1.0, 2.0));
+    assert_eq!(x.value_in(meters), Vector::new(1000.0, 2000.0));
+    assert_eq!(x.x().value_in(meters), 1000.0);
+    assert_eq!(x.y(), kilometers.new(2.0));
+    let y = Length::<Vector>::new(meters.new(3.0), meters.new(4.0));
+    assert_eq!(y.length(), meters.new(5.0));
+    assert_eq!(y.dot(y), square_meters.new(25.0));
+    let z = Length::<Vector>::new_x(meters.new(2.0));
+    assert_eq!(z.value_in(meters), Vector::new(2.0, 0.0));
+}
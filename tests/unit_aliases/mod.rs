@@ -12,12 +12,26 @@ unit_system!(
 
     #[prefix(kilo)]
     unit foo: Length = 0.25 * meters;
+
+    dimension Information;
+    #[base(Information)]
+    #[binary_prefixes(skip: exbi)]
+    #[prefix(kilo)]
+    #[symbol(B)]
+    unit bytes: Information;
+
+    #[prefix(kibi)]
+    unit bits: Information = 0.125 * bytes;
+
+    prefix myria = 1e4, symbol "my";
+    #[prefix(myria, quetta, quecto)]
+    unit bar: Length = 2 meters;
 );
 
 macro_rules! gen_tests_for_float {
     ($float_name: ident, $mod_name: ident, $assert_is_close: path, $assert_is_close_float: path) => {
         mod $mod_name {
-            use super::dimensions::Length;
+            use super::dimensions::{Information, Length};
             use super::units;
             use crate::make_annotated_unit_constructor;
             make_annotated_unit_constructor!(meters, Length<$float_name>, $float_name);
@@ -27,6 +41,16 @@ macro_rules! gen_tests_for_float {
             make_annotated_unit_constructor!(kilometers, Length<$float_name>, $float_name);
             make_annotated_unit_constructor!(foo, Length<$float_name>, $float_name);
             make_annotated_unit_constructor!(kilofoo, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(bytes, Information<$float_name>, $float_name);
+            make_annotated_unit_constructor!(kilobytes, Information<$float_name>, $float_name);
+            make_annotated_unit_constructor!(kibibytes, Information<$float_name>, $float_name);
+            make_annotated_unit_constructor!(mebibytes, Information<$float_name>, $float_name);
+            make_annotated_unit_constructor!(pebibytes, Information<$float_name>, $float_name);
+            make_annotated_unit_constructor!(kibibits, Information<$float_name>, $float_name);
+            make_annotated_unit_constructor!(bar, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(myriabar, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(quettabar, Length<$float_name>, $float_name);
+            make_annotated_unit_constructor!(quectobar, Length<$float_name>, $float_name);
 
             #[test]
             fn unit_aliases() {
@@ -51,6 +75,28 @@ macro_rules! gen_tests_for_float {
                 assert_eq!(foo(100.0), meters(25.0));
                 assert_eq!(kilofoo(100.0), kilometers(25.0));
             }
+
+            #[test]
+            fn binary_prefixes() {
+                assert_eq!(kibibytes(1.0), bytes(1024.0));
+                assert_eq!(mebibytes(1.0), kibibytes(1024.0));
+                assert_eq!(pebibytes(1.0), bytes(1125899906842624.0));
+                assert_eq!(kilobytes(1.0), bytes(1000.0));
+                assert_eq!(kibibits(8.0), kibibytes(1.0));
+            }
+
+            #[test]
+            fn user_defined_prefix() {
+                assert_eq!(myriabar(1.0), meters(2e4));
+                assert_eq!(myriabar(1.0), bar(1e4));
+                assert_eq!(myriabar(1.0).value_in(units::bar), 1e4);
+            }
+
+            #[test]
+            fn si_2022_prefixes() {
+                assert_eq!(quettabar(1.0).value_in(units::bar), 1e30);
+                assert_eq!(quectobar(1e30).value_in(units::bar), 1.0);
+            }
         }
     };
 }
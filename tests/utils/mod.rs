@@ -46,3 +46,27 @@ macro_rules! make_annotated_unit_constructor {
         }
     };
 }
+
+// Generates a test that creates a length of the given storage
+// type in one unit and reads it in another. `$new` turns an f64
+// into a value of the storage type.
+
+#[macro_export]
+macro_rules! gen_new_and_value_in_test {
+    ($test_name: ident, $storage_type: ty, $new: expr) => {
+        #[test]
+        fn $test_name() {
+            use diman::si::dimensions::Length;
+            use diman::si::units::{kilometers, meters};
+            let new: fn(f64) -> $storage_type = $new;
+            let x: Length<$storage_type> = kilometers.new(new(2.0));
+            assert_eq!(x.value_in(meters), new(2000.0));
+            let x: Length<$storage_type> = kilometers.new(new(2.0));
+            assert_eq!(x.value_in(kilometers), new(2.0));
+            let x: Length<$storage_type> = new(3.0) * kilometers;
+            assert_eq!(x.value_in(meters), new(3000.0));
+            let x: Length<$storage_type> = meters.new(new(4000.0));
+            assert_eq!(x.value_in(kilometers), new(4.0));
+        }
+    };
+}